@@ -0,0 +1,299 @@
+//! Binary container params for a drift-competitions randomness request.
+//!
+//! Written by the program when it triggers the switchboard function request and read by
+//! the function inside the enclave. No dependencies so both sides can build it against
+//! their own solana version.
+//!
+//! Layout (version 1), integers little endian:
+//!
+//! | offset | size | field         |
+//! |--------|------|---------------|
+//! | 0      | 1    | version       |
+//! | 1      | 32   | program_id    |
+//! | 33     | 32   | competition   |
+//! | 65     | 32   | spot_market   |
+//! | 97     | 32   | if_vault      |
+//! | 129    | 16   | winner_min    |
+//! | 145    | 16   | winner_max    |
+//! | 161    | 16   | prize_min     |
+//! | 177    | 16   | prize_max     |
+
+use std::fmt;
+
+pub const CONTAINER_PARAMS_VERSION: u8 = 1;
+pub const CONTAINER_PARAMS_SIZE: usize = 1 + 4 * 32 + 4 * 16;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ContainerParams {
+    pub program_id: [u8; 32],
+    pub competition: [u8; 32],
+    pub spot_market: [u8; 32],
+    pub if_vault: [u8; 32],
+    // inclusive bounds of the winner / prize draws
+    pub winner_min: u128,
+    pub winner_max: u128,
+    pub prize_min: u128,
+    pub prize_max: u128,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContainerParamsError {
+    Empty,
+    UnsupportedVersion(u8),
+    InvalidLength {
+        expected: usize,
+        actual: usize,
+    },
+    MissingAccount(&'static str),
+    InvalidBounds {
+        name: &'static str,
+        min: u128,
+        max: u128,
+    },
+}
+
+impl fmt::Display for ContainerParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContainerParamsError::Empty => write!(f, "container params are empty"),
+            ContainerParamsError::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported container params version {} (expected {})",
+                version, CONTAINER_PARAMS_VERSION
+            ),
+            ContainerParamsError::InvalidLength { expected, actual } => write!(
+                f,
+                "container params are {} bytes (expected {})",
+                actual, expected
+            ),
+            ContainerParamsError::MissingAccount(name) => write!(f, "{} cannot be undefined", name),
+            ContainerParamsError::InvalidBounds { name, min, max } => {
+                write!(f, "invalid {} bounds [{}, {}]", name, min, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContainerParamsError {}
+
+impl ContainerParams {
+    pub fn validate(&self) -> Result<(), ContainerParamsError> {
+        for (name, key) in [
+            ("PID", &self.program_id),
+            ("COMPETITION", &self.competition),
+            ("SPOT_MARKET", &self.spot_market),
+            ("IF_VAULT", &self.if_vault),
+        ] {
+            if *key == [0; 32] {
+                return Err(ContainerParamsError::MissingAccount(name));
+            }
+        }
+
+        for (name, min, max) in [
+            ("WINNER", self.winner_min, self.winner_max),
+            ("PRIZE", self.prize_min, self.prize_max),
+        ] {
+            if max == 0 || min > max {
+                return Err(ContainerParamsError::InvalidBounds { name, min, max });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, ContainerParamsError> {
+        self.validate()?;
+
+        let mut bytes = Vec::with_capacity(CONTAINER_PARAMS_SIZE);
+        bytes.push(CONTAINER_PARAMS_VERSION);
+        for key in [
+            &self.program_id,
+            &self.competition,
+            &self.spot_market,
+            &self.if_vault,
+        ] {
+            bytes.extend_from_slice(key);
+        }
+        for value in [
+            self.winner_min,
+            self.winner_max,
+            self.prize_min,
+            self.prize_max,
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        Ok(bytes)
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, ContainerParamsError> {
+        let version = *bytes.first().ok_or(ContainerParamsError::Empty)?;
+        if version != CONTAINER_PARAMS_VERSION {
+            return Err(ContainerParamsError::UnsupportedVersion(version));
+        }
+
+        if bytes.len() != CONTAINER_PARAMS_SIZE {
+            return Err(ContainerParamsError::InvalidLength {
+                expected: CONTAINER_PARAMS_SIZE,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut reader = Reader { bytes, offset: 1 };
+        let params = ContainerParams {
+            program_id: reader.read(),
+            competition: reader.read(),
+            spot_market: reader.read(),
+            if_vault: reader.read(),
+            winner_min: u128::from_le_bytes(reader.read()),
+            winner_max: u128::from_le_bytes(reader.read()),
+            prize_min: u128::from_le_bytes(reader.read()),
+            prize_max: u128::from_le_bytes(reader.read()),
+        };
+
+        params.validate()?;
+
+        Ok(params)
+    }
+}
+
+// length is checked up front, so reads can't run past the end
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn read<const N: usize>(&mut self) -> [u8; N] {
+        let mut value = [0u8; N];
+        value.copy_from_slice(&self.bytes[self.offset..self.offset + N]);
+        self.offset += N;
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_params() -> ContainerParams {
+        ContainerParams {
+            program_id: [1; 32],
+            competition: [2; 32],
+            spot_market: [3; 32],
+            if_vault: [4; 32],
+            winner_min: 1,
+            winner_max: 6,
+            prize_min: 0,
+            prize_max: 31,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for params in [
+            sample_params(),
+            ContainerParams {
+                winner_max: u128::MAX,
+                prize_max: u128::MAX,
+                ..sample_params()
+            },
+        ] {
+            let bytes = params.encode().unwrap();
+            assert_eq!(bytes.len(), CONTAINER_PARAMS_SIZE);
+            assert_eq!(bytes[0], CONTAINER_PARAMS_VERSION);
+            assert_eq!(ContainerParams::decode(&bytes).unwrap(), params);
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        let bytes = sample_params().encode().unwrap();
+        assert_eq!(&bytes[1..33], &[1; 32]);
+        assert_eq!(&bytes[97..129], &[4; 32]);
+        assert_eq!(&bytes[145..161], &6_u128.to_le_bytes());
+        assert_eq!(&bytes[177..193], &31_u128.to_le_bytes());
+    }
+
+    #[test]
+    fn test_decode_malformed() {
+        let bytes = sample_params().encode().unwrap();
+
+        assert_eq!(
+            ContainerParams::decode(&[]),
+            Err(ContainerParamsError::Empty)
+        );
+
+        // the old KEY=VALUE string format
+        assert_eq!(
+            ContainerParams::decode(b"PID=11111111111111111111111111111111,WINNER_MIN=1"),
+            Err(ContainerParamsError::UnsupportedVersion(b'P'))
+        );
+
+        let mut next_version = bytes.clone();
+        next_version[0] = CONTAINER_PARAMS_VERSION + 1;
+        assert_eq!(
+            ContainerParams::decode(&next_version),
+            Err(ContainerParamsError::UnsupportedVersion(
+                CONTAINER_PARAMS_VERSION + 1
+            ))
+        );
+
+        assert_eq!(
+            ContainerParams::decode(&bytes[..CONTAINER_PARAMS_SIZE - 1]),
+            Err(ContainerParamsError::InvalidLength {
+                expected: CONTAINER_PARAMS_SIZE,
+                actual: CONTAINER_PARAMS_SIZE - 1
+            })
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            ContainerParams::decode(&trailing),
+            Err(ContainerParamsError::InvalidLength { .. })
+        ));
+
+        let mut missing_competition = bytes.clone();
+        missing_competition[33..65].copy_from_slice(&[0; 32]);
+        assert_eq!(
+            ContainerParams::decode(&missing_competition),
+            Err(ContainerParamsError::MissingAccount("COMPETITION"))
+        );
+
+        let mut flipped_prize = bytes;
+        flipped_prize[161..177].copy_from_slice(&40_u128.to_le_bytes());
+        assert_eq!(
+            ContainerParams::decode(&flipped_prize),
+            Err(ContainerParamsError::InvalidBounds {
+                name: "PRIZE",
+                min: 40,
+                max: 31
+            })
+        );
+    }
+
+    #[test]
+    fn test_encode_validates() {
+        assert_eq!(
+            ContainerParams {
+                program_id: [0; 32],
+                ..sample_params()
+            }
+            .encode(),
+            Err(ContainerParamsError::MissingAccount("PID"))
+        );
+        assert_eq!(
+            ContainerParams {
+                winner_max: 0,
+                ..sample_params()
+            }
+            .encode(),
+            Err(ContainerParamsError::InvalidBounds {
+                name: "WINNER",
+                min: 1,
+                max: 0
+            })
+        );
+    }
+}
@@ -49,6 +49,68 @@ pub enum ErrorCode {
     CompetitionInvariantIssue,
     #[msg("CompetitorHasPendingInsuranceWithdraw")]
     CompetitorHasPendingInsuranceWithdraw,
+    #[msg("WinnerBadgeAccountsMissing")]
+    WinnerBadgeAccountsMissing,
+    #[msg("CompetitionClaimWindowElapsed")]
+    CompetitionClaimWindowElapsed,
+    #[msg("CompetitionPaused")]
+    CompetitionPaused,
+    #[msg("CompetitorHasUnclaimedWinnings")]
+    CompetitorHasUnclaimedWinnings,
+    #[msg("CompetitionNotClosable")]
+    CompetitionNotClosable,
+    #[msg("InvalidSponsorUpdate")]
+    InvalidSponsorUpdate,
+    #[msg("KeeperNotAllowed")]
+    KeeperNotAllowed,
+    #[msg("InvalidMaxSponsorFraction")]
+    InvalidMaxSponsorFraction,
+    #[msg("InvalidNumberOfWinners")]
+    InvalidNumberOfWinners,
+    #[msg("InvalidRoundDuration")]
+    InvalidRoundDuration,
+    #[msg("InvalidCompetitionExpiryTs")]
+    InvalidCompetitionExpiryTs,
+    #[msg("InvalidRoundSchedule")]
+    InvalidRoundSchedule,
+    #[msg("InvalidCompetitionName")]
+    InvalidCompetitionName,
+    #[msg("CompetitionRegistryUpdateInvalid")]
+    CompetitionRegistryUpdateInvalid,
+    #[msg("InvalidBatchSettlementAccounts")]
+    InvalidBatchSettlementAccounts,
+    #[msg("TicketIndexPageMissing")]
+    TicketIndexPageMissing,
+    #[msg("TicketIndexPageInvalid")]
+    TicketIndexPageInvalid,
+    #[msg("TicketIndexWinnerNotFound")]
+    TicketIndexWinnerNotFound,
+    #[msg("WinnerNotSkippable")]
+    WinnerNotSkippable,
+    #[msg("RandomnessRequestNotTimedOut")]
+    RandomnessRequestNotTimedOut,
+    #[msg("RandomnessRequestRetriesExhausted")]
+    RandomnessRequestRetriesExhausted,
+    #[msg("CompetitorNotSettleable")]
+    CompetitorNotSettleable,
+    #[msg("InvalidRandomnessProvider")]
+    InvalidRandomnessProvider,
+    #[msg("InvalidRandomnessCommitment")]
+    InvalidRandomnessCommitment,
+    #[msg("InvalidRandomnessReveal")]
+    InvalidRandomnessReveal,
+    #[msg("SlotHashNotFound")]
+    SlotHashNotFound,
+    #[msg("RandomnessRevealNotTimedOut")]
+    RandomnessRevealNotTimedOut,
+    #[msg("InvalidRandomnessRequestParams")]
+    InvalidRandomnessRequestParams,
+    #[msg("InvalidWinnerBadgeAuthority")]
+    InvalidWinnerBadgeAuthority,
+    #[msg("InvalidCompetitionMigration")]
+    InvalidCompetitionMigration,
+    #[msg("InvalidRandomnessDelivered")]
+    InvalidRandomnessDelivered,
 }
 
 impl From<DriftErrorCode> for ErrorCode {
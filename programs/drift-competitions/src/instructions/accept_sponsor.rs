@@ -0,0 +1,33 @@
+use super::constraints::is_pending_sponsor_for_competition;
+use crate::state::events::SponsorAcceptedRecord;
+use crate::state::Competition;
+use anchor_lang::prelude::*;
+
+pub fn accept_sponsor<'info>(ctx: Context<'_, '_, '_, 'info, AcceptSponsor<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    let previous_sponsor = competition.sponsor_info.sponsor;
+    competition.accept_sponsor(ctx.accounts.pending_sponsor.key())?;
+
+    emit!(SponsorAcceptedRecord {
+        competition: ctx.accounts.competition.key(),
+        previous_sponsor,
+        sponsor: competition.sponsor_info.sponsor,
+        ts: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptSponsor<'info> {
+    #[account(
+        mut,
+        constraint = is_pending_sponsor_for_competition(&competition, &pending_sponsor)?,
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    pub pending_sponsor: Signer<'info>,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use super::constraints::is_operator_for_competition;
+use crate::state::{Competition, CompetitionKeeper, Size};
+
+pub fn add_competition_keeper<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddCompetitionKeeper<'info>>,
+) -> Result<()> {
+    let mut competition_keeper = ctx.accounts.competition_keeper.load_init()?;
+
+    competition_keeper.authority = ctx.accounts.keeper_authority.key();
+    competition_keeper.competition = ctx.accounts.competition.key();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddCompetitionKeeper<'info> {
+    #[account(
+        init,
+        seeds = [b"competition_keeper", competition.key().as_ref(), keeper_authority.key().as_ref()],
+        space = CompetitionKeeper::SIZE,
+        bump,
+        payer = operator
+    )]
+    pub competition_keeper: AccountLoader<'info, CompetitionKeeper>,
+    #[account(
+        constraint = is_operator_for_competition(&competition, &operator)?,
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    /// CHECK: keeper signer to allow
+    pub keeper_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub operator: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
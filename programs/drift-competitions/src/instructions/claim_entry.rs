@@ -8,7 +8,21 @@ use drift::state::user::UserStats;
 use std::str::FromStr;
 
 pub fn claim_entry<'info>(ctx: Context<'_, '_, '_, 'info, ClaimEntry<'info>>) -> Result<()> {
-    let ixs = ctx.accounts.instructions.as_ref();
+    validate_claim_entry_ix(ctx.accounts.instructions.as_ref())?;
+
+    let competition = ctx.accounts.competition.load()?;
+    competition.validate_not_paused()?;
+
+    let mut competitor = ctx.accounts.competitor.load_mut()?;
+
+    competitor.catch_up_round(&competition)?;
+    competitor.claim_entry()?;
+
+    Ok(())
+}
+
+// free entries must be claimed by the last ix, preceded only by compute budget ixs
+pub fn validate_claim_entry_ix(ixs: &AccountInfo) -> Result<()> {
     let mut current_index = instructions::load_current_index_checked(ixs)? as usize;
 
     if instructions::load_instruction_at_checked(current_index + 1, ixs).is_ok() {
@@ -33,10 +47,6 @@ pub fn claim_entry<'info>(ctx: Context<'_, '_, '_, 'info, ClaimEntry<'info>>) ->
         }
     }
 
-    let mut competitor = ctx.accounts.competitor.load_mut()?;
-
-    competitor.claim_entry()?;
-
     Ok(())
 }
 
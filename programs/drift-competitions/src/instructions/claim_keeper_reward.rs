@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use super::constraints::*;
+use crate::signer_seeds::get_competition_authority_seeds;
+use crate::state::{Competition, CompetitionKeeper};
+
+pub fn claim_keeper_reward<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimKeeperReward<'info>>,
+) -> Result<()> {
+    let competition_key = ctx.accounts.competition.key();
+    let bump = ctx.accounts.competition.load()?.competition_authority_bump;
+    let competition_authority_seeds = get_competition_authority_seeds(&competition_key, &bump);
+
+    let mut competition_keeper = ctx.accounts.competition_keeper.load_mut()?;
+
+    let reward = pay_keeper_reward(
+        &mut competition_keeper,
+        &ctx.accounts.competition_authority,
+        &ctx.accounts.keeper.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[&competition_authority_seeds[..]],
+    )?;
+
+    msg!(
+        "keeper reward claimed: {} ({} remaining)",
+        reward,
+        competition_keeper.unclaimed_reward
+    );
+
+    Ok(())
+}
+
+pub fn pay_keeper_reward<'info>(
+    competition_keeper: &mut CompetitionKeeper,
+    competition_authority: &AccountInfo<'info>,
+    keeper_authority: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    // competition_authority holds the reward lamports, keep it rent exempt
+    let available = competition_authority
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    let reward = competition_keeper.claim_reward(available)?;
+
+    if reward > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Transfer {
+                    from: competition_authority.clone(),
+                    to: keeper_authority.clone(),
+                },
+                signer_seeds,
+            ),
+            reward,
+        )?;
+    }
+
+    Ok(reward)
+}
+
+#[derive(Accounts)]
+pub struct ClaimKeeperReward<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_keeper_for_competition(&competition_keeper, &competition, &keeper)?
+    )]
+    pub competition_keeper: AccountLoader<'info, CompetitionKeeper>,
+    pub competition: AccountLoader<'info, Competition>,
+    /// CHECK
+    #[account(
+        mut,
+        constraint = competition.load()?.competition_authority == competition_authority.key()
+    )]
+    pub competition_authority: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
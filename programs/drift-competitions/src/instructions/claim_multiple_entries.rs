@@ -21,11 +21,16 @@ pub fn claim_multiple_entries<'info>(
         "Max is 5M per single claim"
     )?;
 
+    let competition = ctx.accounts.competition.load()?;
+    competition.validate_not_paused()?;
+
     let mut competitor = ctx.accounts.competitor.load_mut()?;
 
+    competitor.catch_up_round(&competition)?;
     competitor.claim_multiple_entries(entries)?;
 
     drop(competitor);
+    drop(competition);
 
     let spot_market = ctx.accounts.spot_market.load()?;
     let deposit = calculate_revenue_pool_deposit_tokens_from_entries(entries, &spot_market)?;
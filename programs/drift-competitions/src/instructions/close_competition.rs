@@ -0,0 +1,148 @@
+use super::constraints::is_sponsor_for_competition;
+use crate::error::ErrorCode;
+use crate::signer_seeds::get_competition_authority_seeds;
+use crate::state::{Competition, CompetitionRegistryPage};
+use anchor_lang::prelude::*;
+use switchboard_solana::prelude::*;
+
+pub fn close_competition<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseCompetition<'info>>,
+) -> Result<()> {
+    let competition_key = ctx.accounts.competition.key();
+
+    let competition = ctx.accounts.competition.load()?;
+    competition.validate_closable()?;
+
+    // a listed competition can't be closed out from under the registry
+    if competition.is_registered() {
+        let competition_registry_page = ctx
+            .accounts
+            .competition_registry_page
+            .as_ref()
+            .ok_or(ErrorCode::CompetitionRegistryUpdateInvalid)?;
+        let mut competition_registry_page = competition_registry_page.load_mut()?;
+
+        competition.validate_registry_page(competition_registry_page.page_number)?;
+        competition_registry_page.remove(&competition_key)?;
+    }
+
+    let bump = competition.competition_authority_bump;
+    let has_switchboard_request = competition.switchboard_function_request != Pubkey::default();
+    drop(competition);
+
+    if has_switchboard_request {
+        let competition_authority_seeds = get_competition_authority_seeds(&competition_key, &bump);
+        close_switchboard_request(ctx.accounts, &[&competition_authority_seeds[..]])?;
+    }
+
+    Ok(())
+}
+
+fn close_switchboard_request<'info>(
+    accounts: &CloseCompetition<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let switchboard = accounts
+        .switchboard
+        .as_ref()
+        .ok_or(ErrorCode::CompetitionNotClosable)?;
+    let switchboard_state = accounts
+        .switchboard_state
+        .as_ref()
+        .ok_or(ErrorCode::CompetitionNotClosable)?;
+    let switchboard_function = accounts
+        .switchboard_function
+        .as_ref()
+        .ok_or(ErrorCode::CompetitionNotClosable)?;
+    let switchboard_request = accounts
+        .switchboard_request
+        .as_ref()
+        .ok_or(ErrorCode::CompetitionNotClosable)?;
+    let switchboard_request_escrow = accounts
+        .switchboard_request_escrow
+        .as_ref()
+        .ok_or(ErrorCode::CompetitionNotClosable)?;
+    let sponsor_token_account = accounts
+        .sponsor_token_account
+        .as_ref()
+        .ok_or(ErrorCode::CompetitionNotClosable)?;
+    let token_program = accounts
+        .token_program
+        .as_ref()
+        .ok_or(ErrorCode::CompetitionNotClosable)?;
+    let system_program = accounts
+        .system_program
+        .as_ref()
+        .ok_or(ErrorCode::CompetitionNotClosable)?;
+
+    // closes the request and drains its escrow back to the sponsor
+    let request_close_ctx = FunctionRequestClose {
+        request: switchboard_request.clone(),
+        authority: accounts.competition_authority.clone(),
+        escrow: switchboard_request_escrow.clone(),
+        function: switchboard_function.clone(),
+        sol_dest: accounts.sponsor.to_account_info(),
+        escrow_dest: sponsor_token_account.clone(),
+        state: switchboard_state.clone(),
+        token_program: token_program.to_account_info(),
+        system_program: system_program.to_account_info(),
+    };
+
+    request_close_ctx.invoke_signed(switchboard.clone(), signer_seeds)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseCompetition<'info> {
+    // COMPETITION ACCOUNTS
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    #[account(
+        mut,
+        close = sponsor,
+        constraint = is_sponsor_for_competition(&competition, &sponsor)?,
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    /// CHECK
+    #[account(
+        constraint = competition.load()?.competition_authority == competition_authority.key()
+    )]
+    pub competition_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub competition_registry_page: Option<AccountLoader<'info, CompetitionRegistryPage>>,
+
+    // SWITCHBOARD ACCOUNTS (required when the competition has a function request)
+    /// CHECK: program ID checked.
+    #[account(executable, address = SWITCHBOARD_ATTESTATION_PROGRAM_ID)]
+    pub switchboard: Option<AccountInfo<'info>>,
+    /// CHECK: checked in cpi
+    pub switchboard_state: Option<AccountInfo<'info>>,
+    /// CHECK: checked in cpi
+    #[account(
+        mut,
+        constraint = competition.load()?.switchboard_function == switchboard_function.key()
+    )]
+    pub switchboard_function: Option<AccountInfo<'info>>,
+    /// CHECK: cpi checks
+    #[account(
+        mut,
+        constraint = competition.load()?.switchboard_function_request == switchboard_request.key()
+    )]
+    pub switchboard_request: Option<AccountInfo<'info>>,
+    /// CHECK: cpi checks
+    #[account(
+        mut,
+        constraint = competition.load()?.switchboard_function_request_escrow == switchboard_request_escrow.key()
+    )]
+    pub switchboard_request_escrow: Option<AccountInfo<'info>>,
+    /// CHECK: sponsor's wrapped SOL token account, checked in cpi
+    #[account(mut)]
+    pub sponsor_token_account: Option<AccountInfo<'info>>,
+
+    // TOKEN ACCOUNTS
+    pub token_program: Option<Program<'info, Token>>,
+
+    // SYSTEM ACCOUNTS
+    pub system_program: Option<Program<'info, System>>,
+}
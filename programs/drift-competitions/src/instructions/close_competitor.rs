@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use super::constraints::*;
+use crate::state::{Competition, Competitor};
+
+pub fn close_competitor<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseCompetitor<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let competitor = ctx.accounts.competitor.load()?;
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    competitor.close(&mut competition, now)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseCompetitor<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        close = authority,
+        constraint = can_sign_for_competitor(&competitor, &authority)?,
+    )]
+    pub competitor: AccountLoader<'info, Competitor>,
+    #[account(
+        mut,
+        constraint = is_competition_for_competitor(&competitor, &competition)?
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+}
@@ -0,0 +1,33 @@
+use super::constraints::is_sponsor_for_competition;
+use crate::state::Competition;
+use anchor_lang::prelude::*;
+
+pub fn commit_randomness<'info>(
+    ctx: Context<'_, '_, '_, 'info, CommitRandomness<'info>>,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    competition.commit_randomness(commitment, now)?;
+
+    msg!(
+        "randomness commitment for round_number={}: {:?}",
+        competition.round_number,
+        commitment
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitRandomness<'info> {
+    #[account(
+        mut,
+        constraint = is_sponsor_for_competition(&competition, &sponsor)?,
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    pub sponsor: Signer<'info>,
+}
@@ -1,4 +1,4 @@
-use crate::state::{Competition, Competitor};
+use crate::state::{Competition, CompetitionKeeper, Competitor, TicketIndexPage};
 
 use anchor_lang::prelude::*;
 use drift::state::user::UserStats;
@@ -17,6 +17,16 @@ pub fn is_user_stats_for_competitor(
     Ok(competitor.load()?.user_stats.eq(&user_stats.key()))
 }
 
+pub fn is_ticket_index_page_for_competition(
+    ticket_index_page: &Option<AccountLoader<TicketIndexPage>>,
+    competition: &AccountLoader<Competition>,
+) -> Result<bool> {
+    match ticket_index_page {
+        Some(ticket_index_page) => Ok(ticket_index_page.load()?.competition.eq(&competition.key())),
+        None => Ok(true),
+    }
+}
+
 pub fn is_competition_for_competitor(
     competitor: &AccountLoader<Competitor>,
     competition: &AccountLoader<Competition>,
@@ -30,3 +40,37 @@ pub fn is_sponsor_for_competition<'info>(
 ) -> Result<bool> {
     Ok(competition.load()?.sponsor_info.sponsor.eq(&sponsor.key()))
 }
+
+pub fn is_operator_for_competition<'info>(
+    competition: &AccountLoader<'info, Competition>,
+    operator: &Signer<'info>,
+) -> Result<bool> {
+    Ok(competition.load()?.get_operator().eq(&operator.key()))
+}
+
+pub fn is_moderator_for_competition<'info>(
+    competition: &AccountLoader<'info, Competition>,
+    moderator: &Signer<'info>,
+) -> Result<bool> {
+    Ok(competition.load()?.get_moderator().eq(&moderator.key()))
+}
+
+pub fn is_pending_sponsor_for_competition<'info>(
+    competition: &AccountLoader<'info, Competition>,
+    pending_sponsor: &Signer<'info>,
+) -> Result<bool> {
+    Ok(competition
+        .load()?
+        .pending_sponsor
+        .eq(&pending_sponsor.key()))
+}
+
+pub fn is_keeper_for_competition<'info>(
+    competition_keeper: &AccountLoader<'info, CompetitionKeeper>,
+    competition: &AccountLoader<'info, Competition>,
+    keeper: &Signer<'info>,
+) -> Result<bool> {
+    let competition_keeper = competition_keeper.load()?;
+    Ok(competition_keeper.authority.eq(&keeper.key())
+        && competition_keeper.competition.eq(&competition.key()))
+}
@@ -0,0 +1,30 @@
+use crate::state::{Competition, CompetitionRegistry, CompetitionRegistryPage};
+use anchor_lang::prelude::*;
+
+// frees the entry, e.g. for a competition that was abandoned without being closed
+pub fn deregister_competition<'info>(
+    ctx: Context<'_, '_, '_, 'info, DeregisterCompetition<'info>>,
+) -> Result<()> {
+    let mut competition = ctx.accounts.competition.load_mut()?;
+    let mut competition_registry_page = ctx.accounts.competition_registry_page.load_mut()?;
+
+    competition.deregister(competition_registry_page.page_number)?;
+    competition_registry_page.remove(&ctx.accounts.competition.key())?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeregisterCompetition<'info> {
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+    #[account(
+        seeds = [b"competition_registry"],
+        bump,
+        has_one = admin,
+    )]
+    pub competition_registry: AccountLoader<'info, CompetitionRegistry>,
+    #[account(mut)]
+    pub competition_registry_page: AccountLoader<'info, CompetitionRegistryPage>,
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::events::CompetitionExpiredRecord;
+use crate::state::{Competition, CompetitionRoundStatus};
+
+pub fn expire_competition<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExpireCompetition<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    // first call expires the competition, calls after the claim window forfeit unclaimed winnings
+    let (round_abandoned, unclaimed_winnings_forfeited) =
+        if competition.status != CompetitionRoundStatus::Expired {
+            let round_abandoned = competition.is_round_in_progress();
+            competition.expire(now)?;
+
+            (round_abandoned, 0)
+        } else {
+            (false, competition.forfeit_unclaimed_winnings(now)?)
+        };
+
+    emit!(CompetitionExpiredRecord {
+        competition: ctx.accounts.competition.key(),
+        round_number: competition.round_number,
+        round_abandoned,
+        number_of_competitors: competition.number_of_competitors,
+        outstanding_unclaimed_winnings: competition.outstanding_unclaimed_winnings,
+        unclaimed_winnings_forfeited,
+        competition_expiry_ts: competition.competition_expiry_ts,
+        claim_window_end_ts: competition.calculate_claim_window_end_ts()?,
+        ts: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExpireCompetition<'info> {
+    #[account(mut)]
+    keeper: Signer<'info>,
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+}
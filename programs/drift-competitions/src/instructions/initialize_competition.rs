@@ -1,24 +1,27 @@
-use crate::error::ErrorCode;
 use crate::state::Size;
 use crate::state::{Competition, CompetitionRoundStatus};
+use crate::validation::{validate_competition_name, validate_competition_params};
 use anchor_lang::prelude::*;
 
-const SWEEPSTAKES_NAME: [u8; 32] = [
-    115, 119, 101, 101, 112, 115, 116, 97, 107, 101, 115, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32,
-    32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32,
-];
-
 pub fn initialize_competition<'info>(
     ctx: Context<'_, '_, '_, 'info, InitializeCompetition<'info>>,
     params: CompetitionParams,
 ) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
     let competition_key = ctx.accounts.competition.key();
     let mut competition = ctx.accounts.competition.load_init()?;
 
-    if params.name != SWEEPSTAKES_NAME {
-        msg!("Invalid competition name");
-        return Err(ErrorCode::Default.into());
-    }
+    validate_competition_name(&params.name)?;
+
+    validate_competition_params(
+        params.next_round_expiry_ts,
+        params.competition_expiry_ts,
+        params.round_duration,
+        params.max_sponsor_fraction,
+        params.number_of_winners,
+    )?;
 
     competition.name = params.name;
     competition.sponsor_info.sponsor = ctx.accounts.sponsor.key();
@@ -36,6 +39,7 @@ pub fn initialize_competition<'info>(
 
     competition.status = CompetitionRoundStatus::Active;
 
+    competition.round_start_ts = now;
     competition.next_round_expiry_ts = params.next_round_expiry_ts;
     competition.competition_expiry_ts = params.competition_expiry_ts;
     competition.round_duration = params.round_duration;
@@ -49,7 +53,7 @@ pub fn initialize_competition<'info>(
 
 #[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub struct CompetitionParams {
-    pub name: [u8; 32],
+    pub name: [u8; 32], // utf-8, right padded with spaces
 
     //scheduling variables
     pub next_round_expiry_ts: i64,
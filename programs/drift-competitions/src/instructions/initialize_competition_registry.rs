@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::program::DriftCompetitions;
+use crate::state::{CompetitionRegistry, Size};
+
+pub fn initialize_competition_registry<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeCompetitionRegistry<'info>>,
+) -> Result<()> {
+    let mut competition_registry = ctx.accounts.competition_registry.load_init()?;
+
+    competition_registry.admin = ctx.accounts.admin.key();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeCompetitionRegistry<'info> {
+    #[account(
+        init,
+        seeds = [b"competition_registry"],
+        space = CompetitionRegistry::SIZE,
+        bump,
+        payer = admin
+    )]
+    pub competition_registry: AccountLoader<'info, CompetitionRegistry>,
+    // the registry is a single pda, only the upgrade authority can claim it
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()),
+    )]
+    pub program: Program<'info, DriftCompetitions>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()),
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
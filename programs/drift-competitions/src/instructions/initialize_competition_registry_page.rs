@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CompetitionRegistry, CompetitionRegistryPage, Size};
+
+pub fn initialize_competition_registry_page<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeCompetitionRegistryPage<'info>>,
+    page_number: u32,
+) -> Result<()> {
+    ctx.accounts
+        .competition_registry
+        .load_mut()?
+        .add_page(page_number)?;
+
+    let mut competition_registry_page = ctx.accounts.competition_registry_page.load_init()?;
+    competition_registry_page.page_number = page_number;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(page_number: u32)]
+pub struct InitializeCompetitionRegistryPage<'info> {
+    #[account(
+        init,
+        seeds = [b"competition_registry_page", page_number.to_le_bytes().as_ref()],
+        space = CompetitionRegistryPage::SIZE,
+        bump,
+        payer = admin
+    )]
+    pub competition_registry_page: AccountLoader<'info, CompetitionRegistryPage>,
+    #[account(
+        mut,
+        seeds = [b"competition_registry"],
+        bump,
+        has_one = admin,
+    )]
+    pub competition_registry: AccountLoader<'info, CompetitionRegistry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
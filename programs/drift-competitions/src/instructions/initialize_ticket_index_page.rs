@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Competition, Size, TicketIndexPage};
+
+pub fn initialize_ticket_index_page<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeTicketIndexPage<'info>>,
+    page_number: u32,
+) -> Result<()> {
+    let mut ticket_index_page = ctx.accounts.ticket_index_page.load_init()?;
+
+    ticket_index_page.competition = ctx.accounts.competition.key();
+    ticket_index_page.page_number = page_number;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(page_number: u32)]
+pub struct InitializeTicketIndexPage<'info> {
+    #[account(
+        init,
+        seeds = [b"ticket_index_page", competition.key().as_ref(), page_number.to_le_bytes().as_ref()],
+        space = TicketIndexPage::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub ticket_index_page: AccountLoader<'info, TicketIndexPage>,
+    pub competition: AccountLoader<'info, Competition>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::program::DriftCompetitions;
+use crate::state::{validate_competition_migration, Competition, Size};
+
+// grows a competition created before the current layout, the new fields are zero-filled
+pub fn migrate_competition<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateCompetition<'info>>,
+) -> Result<()> {
+    let competition = ctx.accounts.competition.to_account_info();
+
+    validate_competition_migration(&competition.try_borrow_data()?)?;
+
+    let rent_top_up = Rent::get()?
+        .minimum_balance(Competition::SIZE)
+        .saturating_sub(competition.lamports());
+
+    if rent_top_up > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: competition.clone(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
+
+    competition.realloc(Competition::SIZE, true)?;
+
+    msg!(
+        "migrated competition {} to {} bytes",
+        competition.key(),
+        Competition::SIZE
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateCompetition<'info> {
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()),
+    )]
+    pub program: Program<'info, DriftCompetitions>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()),
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,26 +1,82 @@
+pub use accept_sponsor::*;
+pub use add_competition_keeper::*;
 pub use claim_entry::*;
+pub use claim_keeper_reward::*;
 pub use claim_multiple_entries::*;
 pub use claim_winnings::*;
+pub use close_competition::*;
+pub use close_competitor::*;
+pub use commit_randomness::*;
+pub use deregister_competition::*;
+pub use draw_slot_hash_randomness::*;
+pub use expire_competition::*;
 pub use initialize_competition::*;
+pub use initialize_competition_registry::*;
+pub use initialize_competition_registry_page::*;
 pub use initialize_competitor::*;
+pub use initialize_ticket_index_page::*;
+pub use migrate_competition::*;
+pub use pause_competition::*;
+pub use pin_randomness_slot_hash::*;
+pub use propose_sponsor::*;
 pub use receive_randomness::*;
+pub use register_competition::*;
+pub use remove_competition_keeper::*;
+pub use request_commit_reveal_randomness::*;
 pub use request_randomness::*;
+pub use resolve_missed_reveal::*;
+pub use resume_competition::*;
+pub use reveal_randomness::*;
+pub use self_settle_competitor::*;
 pub use settle_competitor::*;
+pub use settle_competitors_batch::*;
 pub use settle_winner::*;
+pub use settle_winner_by_index::*;
+pub use skip_winner::*;
 pub use update_competition::*;
+pub use update_competition_schedule::*;
 pub use update_competitor_status::*;
+pub use update_round_schedule::*;
 pub use update_switchboard_function::*;
 
+mod accept_sponsor;
+mod add_competition_keeper;
 mod claim_entry;
+mod claim_keeper_reward;
 mod claim_multiple_entries;
 mod claim_winnings;
+mod close_competition;
+mod close_competitor;
+mod commit_randomness;
 mod constraints;
+mod deregister_competition;
+mod draw_slot_hash_randomness;
+mod expire_competition;
 mod initialize_competition;
+mod initialize_competition_registry;
+mod initialize_competition_registry_page;
 mod initialize_competitor;
+mod initialize_ticket_index_page;
+mod migrate_competition;
+mod pause_competition;
+mod pin_randomness_slot_hash;
+mod propose_sponsor;
 mod receive_randomness;
+mod register_competition;
+mod remove_competition_keeper;
+mod request_commit_reveal_randomness;
 mod request_randomness;
+mod resolve_missed_reveal;
+mod resume_competition;
+mod reveal_randomness;
+mod self_settle_competitor;
 mod settle_competitor;
+mod settle_competitors_batch;
 mod settle_winner;
+mod settle_winner_by_index;
+mod skip_winner;
 mod update_competition;
+mod update_competition_schedule;
 mod update_competitor_status;
+mod update_round_schedule;
 mod update_switchboard_function;
@@ -0,0 +1,26 @@
+use super::constraints::is_operator_for_competition;
+use crate::state::Competition;
+use anchor_lang::prelude::*;
+
+pub fn pause_competition<'info>(
+    ctx: Context<'_, '_, '_, 'info, PauseCompetition<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    competition.pause(now)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PauseCompetition<'info> {
+    #[account(
+        mut,
+        constraint = is_operator_for_competition(&competition, &operator)?,
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    pub operator: Signer<'info>,
+}
@@ -0,0 +1,44 @@
+use crate::state::Competition;
+use crate::utils::get_first_slot_hash_after;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+
+// keepers pin the slot hash as soon as it lands, reveal_randomness pins it itself otherwise
+pub fn pin_randomness_slot_hash<'info>(
+    ctx: Context<'_, '_, '_, 'info, PinRandomnessSlotHash<'info>>,
+) -> Result<()> {
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    pin_randomness_slot_hash_from_sysvar(&mut competition, &ctx.accounts.slot_hashes)
+}
+
+// shared with reveal_randomness and resolve_missed_reveal
+pub fn pin_randomness_slot_hash_from_sysvar(
+    competition: &mut Competition,
+    slot_hashes: &AccountInfo,
+) -> Result<()> {
+    let (slot, slot_hash) = get_first_slot_hash_after(
+        &slot_hashes.try_borrow_data()?,
+        competition.randomness_requested_slot,
+    )?;
+
+    msg!(
+        "pinning slot hash of slot {} (requested at slot {}) for round_number={}",
+        slot,
+        competition.randomness_requested_slot,
+        competition.round_number
+    );
+    competition.pin_randomness_slot_hash(slot, slot_hash, Clock::get()?.slot)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PinRandomnessSlotHash<'info> {
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+    pub keeper: Signer<'info>,
+    /// CHECK: slot hashes sysvar, read in place
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
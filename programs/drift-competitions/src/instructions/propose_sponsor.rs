@@ -0,0 +1,35 @@
+use super::constraints::is_sponsor_for_competition;
+use crate::state::events::SponsorProposedRecord;
+use crate::state::Competition;
+use anchor_lang::prelude::*;
+
+pub fn propose_sponsor<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProposeSponsor<'info>>,
+    pending_sponsor: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    competition.propose_sponsor(pending_sponsor)?;
+
+    emit!(SponsorProposedRecord {
+        competition: ctx.accounts.competition.key(),
+        sponsor: competition.sponsor_info.sponsor,
+        pending_sponsor,
+        ts: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeSponsor<'info> {
+    #[account(
+        mut,
+        constraint = is_sponsor_for_competition(&competition, &sponsor)?,
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    pub sponsor: Signer<'info>,
+}
@@ -1,4 +1,4 @@
-use crate::state::Competition;
+use crate::state::{Competition, RandomnessProvider};
 use anchor_lang::prelude::*;
 use switchboard_solana::prelude::*;
 
@@ -7,27 +7,66 @@ use drift::math::constants::QUOTE_SPOT_MARKET_INDEX;
 use drift::math::insurance::if_shares_to_vault_amount;
 use drift::state::spot_market::SpotMarket;
 
-use drift::math::casting::Cast;
 use drift::math::safe_math::SafeMath;
 
+// the function sends the raw draws it accepted, ranged here rather than trusted pre-ranged
 pub fn receive_randomness(
     ctx: Context<ReceiveRandomness>,
-    winner_randomness: u128,
-    prize_randomness: u128,
+    winner_randomness_raw: u128,
+    prize_randomness_raw: u128,
 ) -> Result<()> {
     let clock = Clock::get()?;
     let now = clock.unix_timestamp;
 
-    msg!("winner_randomness: {}", winner_randomness);
-    msg!("prize_randomness: {}", prize_randomness);
-
     let mut competition = ctx.accounts.competition.load_mut()?;
+    competition.validate_randomness_provider(RandomnessProvider::Switchboard)?;
 
     let spot_market = ctx.accounts.spot_market.load()?;
     let vault_balance = ctx.accounts.insurance_fund_vault.amount;
 
+    let (winner_randomness, prize_randomness) =
+        competition.range_switchboard_randomness(winner_randomness_raw, prize_randomness_raw)?;
+
+    let mut randomness_hash = [0_u8; 32];
+    randomness_hash[..16].copy_from_slice(&winner_randomness_raw.to_le_bytes());
+    randomness_hash[16..].copy_from_slice(&prize_randomness_raw.to_le_bytes());
+
+    resolve_randomness(
+        ctx.accounts.competition.key(),
+        &mut competition,
+        &spot_market,
+        vault_balance,
+        winner_randomness,
+        prize_randomness,
+        randomness_hash,
+        now,
+    )
+}
+
+// shared by every randomness provider. randomness_hash holds the raw winner / prize values
+// (first / last 16 bytes, little endian) that winner_randomness / prize_randomness were ranged
+// from: the sha256 for on-chain providers, the function's draws for switchboard
+pub fn resolve_randomness(
+    competition_key: Pubkey,
+    competition: &mut Competition,
+    spot_market: &SpotMarket,
+    vault_balance: u64,
+    winner_randomness: u128,
+    prize_randomness: u128,
+    randomness_hash: [u8; 32],
+    now: i64,
+) -> Result<()> {
+    msg!("winner_randomness: {}", winner_randomness);
+    msg!("prize_randomness: {}", prize_randomness);
+
     competition.winner_randomness = winner_randomness;
     competition.prize_randomness = prize_randomness;
+    let mut winner_randomness_raw = [0_u8; 16];
+    winner_randomness_raw.copy_from_slice(&randomness_hash[..16]);
+    let mut prize_randomness_raw = [0_u8; 16];
+    prize_randomness_raw.copy_from_slice(&randomness_hash[16..]);
+    competition.winner_randomness_delivered = u128::from_le_bytes(winner_randomness_raw);
+    competition.prize_randomness_delivered = u128::from_le_bytes(prize_randomness_raw);
 
     msg!("insurance_fund vault_balance: {}", vault_balance);
     msg!(
@@ -39,9 +78,10 @@ pub fn receive_randomness(
         spot_market.insurance_fund.total_shares
     );
     let (_, prize_odds_numerator, prize_placement) =
-        competition.calculate_prize_amount(&spot_market, vault_balance)?;
+        competition.calculate_prize_amount(spot_market, vault_balance)?;
 
-    competition.resolve_winner_and_prize_randomness(&spot_market, vault_balance)?;
+    competition.resolve_winner_and_prize_randomness(spot_market, vault_balance)?;
+    competition.winner_draw_ts = now;
 
     let prize_value = if_shares_to_vault_amount(
         competition.prize_amount,
@@ -50,16 +90,12 @@ pub fn receive_randomness(
     )?;
 
     let max_prize_bucket_value =
-        competition.calculate_sponsor_max_prize(&spot_market, vault_balance)?;
-
-    let competition_key = ctx.accounts.competition.key();
+        competition.calculate_sponsor_max_prize(spot_market, vault_balance)?;
 
     emit!(CompetitionRoundSummaryRecord {
         competition: competition_key,
         round_number: competition.round_number,
-        round_start_ts: competition
-            .next_round_expiry_ts
-            .safe_sub(competition.round_duration.cast()?)?,
+        round_start_ts: competition.get_round_start_ts()?,
         round_end_ts: competition.next_round_expiry_ts,
         total_score_settled: competition.total_score_settled,
 
@@ -76,6 +112,7 @@ pub fn receive_randomness(
         prize_randomness_max: competition.prize_randomness_max,
 
         max_prize_bucket_value,
+
         insurance_vault_balance: vault_balance,
         protocol_if_shares: spot_market
             .insurance_fund
@@ -84,8 +121,16 @@ pub fn receive_randomness(
         total_if_shares: spot_market.insurance_fund.total_shares,
 
         ts: now,
+
+        prize_draw: competition.prize_draw,
+        winner_randomness_delivered: competition.winner_randomness_delivered,
+        prize_randomness_delivered: competition.prize_randomness_delivered,
+        winner_randomness_max: competition.winner_randomness_max,
+        randomness_hash,
     });
 
+    emit!(competition.get_winner_draw_record(competition_key, now));
+
     Ok(())
 }
 
@@ -0,0 +1,35 @@
+use crate::state::{Competition, CompetitionRegistry, CompetitionRegistryPage};
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+pub fn register_competition<'info>(
+    ctx: Context<'_, '_, '_, 'info, RegisterCompetition<'info>>,
+) -> Result<()> {
+    let mut competition = ctx.accounts.competition.load_mut()?;
+    let mut competition_registry = ctx.accounts.competition_registry.load_mut()?;
+    let mut competition_registry_page = ctx.accounts.competition_registry_page.load_mut()?;
+
+    competition.register(competition_registry_page.page_number)?;
+    competition_registry_page.add(ctx.accounts.competition.key())?;
+    competition_registry.total_competitions_registered = competition_registry
+        .total_competitions_registered
+        .safe_add(1)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterCompetition<'info> {
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+    #[account(
+        mut,
+        seeds = [b"competition_registry"],
+        bump,
+        has_one = admin,
+    )]
+    pub competition_registry: AccountLoader<'info, CompetitionRegistry>,
+    #[account(mut)]
+    pub competition_registry_page: AccountLoader<'info, CompetitionRegistryPage>,
+    pub admin: Signer<'info>,
+}
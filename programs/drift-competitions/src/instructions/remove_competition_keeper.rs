@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use super::claim_keeper_reward::pay_keeper_reward;
+use super::constraints::is_operator_for_competition;
+use crate::signer_seeds::get_competition_authority_seeds;
+use crate::state::{Competition, CompetitionKeeper};
+
+pub fn remove_competition_keeper<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveCompetitionKeeper<'info>>,
+) -> Result<()> {
+    let competition_key = ctx.accounts.competition.key();
+    let bump = ctx.accounts.competition.load()?.competition_authority_bump;
+    let competition_authority_seeds = get_competition_authority_seeds(&competition_key, &bump);
+
+    let mut competition_keeper = ctx.accounts.competition_keeper.load_mut()?;
+
+    // pay out what was earned before removal, anything the vault can't cover is forfeited
+    let reward = pay_keeper_reward(
+        &mut competition_keeper,
+        &ctx.accounts.competition_authority,
+        &ctx.accounts.keeper_authority,
+        &ctx.accounts.system_program.to_account_info(),
+        &[&competition_authority_seeds[..]],
+    )?;
+
+    msg!(
+        "removing keeper {} (paid {}, forfeited {})",
+        competition_keeper.authority,
+        reward,
+        competition_keeper.unclaimed_reward
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveCompetitionKeeper<'info> {
+    #[account(
+        mut,
+        close = operator,
+        constraint = competition_keeper.load()?.competition == competition.key()
+    )]
+    pub competition_keeper: AccountLoader<'info, CompetitionKeeper>,
+    #[account(
+        constraint = is_operator_for_competition(&competition, &operator)?,
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    /// CHECK: checked against competition_keeper
+    #[account(
+        mut,
+        constraint = competition_keeper.load()?.authority == keeper_authority.key()
+    )]
+    pub keeper_authority: AccountInfo<'info>,
+    /// CHECK
+    #[account(
+        mut,
+        constraint = competition.load()?.competition_authority == competition_authority.key()
+    )]
+    pub competition_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub operator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
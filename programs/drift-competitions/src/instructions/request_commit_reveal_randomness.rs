@@ -0,0 +1,73 @@
+use super::constraints::is_keeper_for_competition;
+use crate::state::events::RandomnessRequestedRecord;
+use crate::state::{Competition, CompetitionKeeper, RandomnessProvider};
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use drift::math::constants::QUOTE_SPOT_MARKET_INDEX;
+use drift::state::spot_market::SpotMarket;
+
+pub fn request_commit_reveal_randomness<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestCommitRevealRandomness<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let competition_key = ctx.accounts.competition.key();
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+    let spot_market = ctx.accounts.spot_market.load()?;
+    let vault_balance = ctx.accounts.insurance_fund_vault.amount;
+
+    competition.validate_not_paused()?;
+    competition.validate_randomness_provider(RandomnessProvider::CommitReveal)?;
+    competition.validate_randomness_commitment()?;
+
+    let mut competition_keeper = match &ctx.accounts.competition_keeper {
+        Some(competition_keeper) => Some(competition_keeper.load_mut()?),
+        None => None,
+    };
+    competition.validate_keeper_is_allowed(competition_keeper.is_some())?;
+
+    competition.forfeit_unsettled_competitors(now)?;
+    if competition.roll_over_round_without_winner(now)? {
+        return Ok(());
+    }
+    competition.request_winner_and_prize_randomness(&spot_market, vault_balance)?;
+    competition.reward_keeper(competition_keeper.as_deref_mut(), now)?;
+
+    // the reveal mixes in the hash of the first slot after this one, see pin_randomness_slot_hash
+    competition.record_randomness_request(now, clock.slot);
+
+    emit!(RandomnessRequestedRecord {
+        competition: competition_key,
+        round_number: competition.round_number,
+        retry: 0,
+        max_retries: 0,
+        winner_max: competition.total_score_settled,
+        prize_max: competition.prize_randomness_max,
+        slot: clock.slot,
+        ts: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestCommitRevealRandomness<'info> {
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_keeper_for_competition(&competition_keeper, &competition, &keeper)?
+    )]
+    pub competition_keeper: Option<AccountLoader<'info, CompetitionKeeper>>,
+    #[account(
+        constraint = spot_market.load()?.market_index == QUOTE_SPOT_MARKET_INDEX,
+    )]
+    pub spot_market: AccountLoader<'info, SpotMarket>,
+    #[account(
+        constraint = spot_market.load()?.insurance_fund.vault == insurance_fund_vault.key(),
+    )]
+    pub insurance_fund_vault: Account<'info, TokenAccount>,
+}
@@ -1,8 +1,11 @@
+use super::constraints::is_keeper_for_competition;
 use crate::error::ErrorCode;
 use crate::signer_seeds::get_competition_authority_seeds;
-use crate::state::{Competition, CompetitionRoundStatus};
+use crate::state::events::RandomnessRequestedRecord;
+use crate::state::{Competition, CompetitionKeeper, CompetitionRoundStatus, RandomnessProvider};
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
+use container_params::ContainerParams;
 use drift::math::constants::QUOTE_SPOT_MARKET_INDEX;
 use drift::state::spot_market::SpotMarket;
 use drift::validate;
@@ -12,6 +15,9 @@ pub fn request_randomness<'info>(
     ctx: Context<'_, '_, '_, 'info, RequestRandomness<'info>>,
     bounty: Option<u64>,
 ) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
     let competition_key = ctx.accounts.competition.key();
     let spot_market_key = ctx.accounts.spot_market.key();
     let insurance_fund_vault_key = ctx.accounts.insurance_fund_vault.key();
@@ -24,9 +30,27 @@ pub fn request_randomness<'info>(
     let spot_market = ctx.accounts.spot_market.load()?;
     let vault_balance = ctx.accounts.insurance_fund_vault.amount;
 
+    competition.validate_not_paused()?;
+    competition.validate_randomness_provider(RandomnessProvider::Switchboard)?;
+
+    let mut competition_keeper = match &ctx.accounts.competition_keeper {
+        Some(competition_keeper) => Some(competition_keeper.load_mut()?),
+        None => None,
+    };
+    competition.validate_keeper_is_allowed(competition_keeper.is_some())?;
+
+    // re-triggering an outstanding request (after it times out) isn't rewarded
     if competition.status == CompetitionRoundStatus::Active {
+        competition.forfeit_unsettled_competitors(now)?;
+        if competition.roll_over_round_without_winner(now)? {
+            return Ok(());
+        }
         competition.request_winner_and_prize_randomness(&spot_market, vault_balance)?;
+        competition.reward_keeper(competition_keeper.as_deref_mut(), now)?;
+    } else {
+        competition.retry_randomness_request(now)?;
     }
+    competition.record_randomness_request(now, clock.slot);
 
     validate!(
         competition.status == CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested,
@@ -36,14 +60,20 @@ pub fn request_randomness<'info>(
     let winner_min = 1;
     let winner_max = competition.total_score_settled;
 
-    validate!(
-        winner_min <= winner_max,
-        ErrorCode::InvalidStatusUpdateDetected
-    )?;
-
     let prize_min = 0;
     let prize_max = competition.prize_randomness_max;
 
+    emit!(RandomnessRequestedRecord {
+        competition: competition_key,
+        round_number: competition.round_number,
+        retry: competition.randomness_request_retries,
+        max_retries: competition.get_max_randomness_request_retries(),
+        winner_max,
+        prize_max,
+        slot: clock.slot,
+        ts: now,
+    });
+
     let request_params = get_request_params(
         winner_min,
         winner_max,
@@ -52,7 +82,7 @@ pub fn request_randomness<'info>(
         competition_key,
         spot_market_key,
         insurance_fund_vault_key,
-    );
+    )?;
 
     let update_request_params = FunctionRequestSetConfig {
         request: ctx.accounts.switchboard_request.clone(),
@@ -61,7 +91,7 @@ pub fn request_randomness<'info>(
 
     update_request_params.invoke_signed(
         ctx.accounts.switchboard.clone(),
-        request_params.into_bytes(),
+        request_params,
         false,
         &[&function_authority_seeds[..]],
     )?;
@@ -93,25 +123,29 @@ pub fn request_randomness<'info>(
 }
 
 fn get_request_params(
-    prize_min: u128,
-    prize_max: u128,
     winner_min: u128,
     winner_max: u128,
+    prize_min: u128,
+    prize_max: u128,
     competition: Pubkey,
     spot_market: Pubkey,
     if_vault: Pubkey,
-) -> String {
-    format!(
-        "PID={},WINNER_MIN={},WINNER_MAX={},PRIZE_MIN={},PRIZE_MAX={},COMPETITION={},SPOT_MARKET={},IF_VAULT={}",
-        crate::id(),
-        prize_min,
-        prize_max,
+) -> Result<Vec<u8>> {
+    ContainerParams {
+        program_id: crate::id().to_bytes(),
+        competition: competition.to_bytes(),
+        spot_market: spot_market.to_bytes(),
+        if_vault: if_vault.to_bytes(),
         winner_min,
         winner_max,
-        competition,
-        spot_market,
-        if_vault,
-    )
+        prize_min,
+        prize_max,
+    }
+    .encode()
+    .map_err(|e| {
+        msg!("invalid randomness request params: {}", e);
+        ErrorCode::InvalidRandomnessRequestParams.into()
+    })
 }
 
 #[derive(Accounts)]
@@ -126,6 +160,11 @@ pub struct RequestRandomness<'info> {
         constraint = competition.load()?.competition_authority == competition_authority.key()
     )]
     pub competition_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = is_keeper_for_competition(&competition_keeper, &competition, &keeper)?
+    )]
+    pub competition_keeper: Option<AccountLoader<'info, CompetitionKeeper>>,
 
     // DRIFT ACCOUNTS
     #[account(
@@ -178,19 +217,54 @@ pub struct RequestRandomness<'info> {
 mod test {
     use crate::MAX_REQUEST_PARAM_SIZE;
     use anchor_spl::token_interface::spl_token_2022::solana_program::pubkey::Pubkey;
+    use container_params::ContainerParams;
 
     #[test]
     fn max_result_params_size() {
         let params = super::get_request_params(
-            0,
-            u128::MAX,
             1,
             u128::MAX,
+            0,
+            u128::MAX,
             Pubkey::new_unique(),
             Pubkey::new_unique(),
             Pubkey::new_unique(),
-        );
+        )
+        .unwrap();
 
         assert!(params.len() as u32 <= MAX_REQUEST_PARAM_SIZE);
     }
+
+    #[test]
+    fn request_params_round_trip() {
+        let competition = Pubkey::new_unique();
+        let params = super::get_request_params(
+            1,
+            100,
+            0,
+            31,
+            competition,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+
+        let decoded = ContainerParams::decode(&params).unwrap();
+        assert_eq!(decoded.program_id, crate::id().to_bytes());
+        assert_eq!(decoded.competition, competition.to_bytes());
+        assert_eq!((decoded.winner_min, decoded.winner_max), (1, 100));
+        assert_eq!((decoded.prize_min, decoded.prize_max), (0, 31));
+
+        // nothing settled, nothing to draw
+        assert!(super::get_request_params(
+            1,
+            0,
+            0,
+            31,
+            competition,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        )
+        .is_err());
+    }
 }
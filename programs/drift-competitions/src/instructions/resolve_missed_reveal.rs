@@ -0,0 +1,69 @@
+use super::pin_randomness_slot_hash::pin_randomness_slot_hash_from_sysvar;
+use crate::state::events::RandomnessRevealedRecord;
+use crate::state::Competition;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+
+// nothing is drawn here: the competition moves to switchboard and keepers re-request the
+// outstanding draw with request_randomness, see penalize_missed_randomness_reveal and
+// resolve_missed_randomness_slot_hash_pin
+pub fn resolve_missed_reveal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResolveMissedReveal<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let competition_key = ctx.accounts.competition.key();
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    let slot_hash_pin_missed = competition.is_randomness_slot_hash_pin_missed(clock.slot)?;
+
+    if slot_hash_pin_missed {
+        competition.resolve_missed_randomness_slot_hash_pin(clock.slot)?;
+
+        msg!(
+            "slot hash pin missed for round_number={}, re-request with switchboard",
+            competition.round_number
+        );
+    } else {
+        // the sponsor can't dodge the deadline by leaving the slot hash unpinned
+        if !competition.is_randomness_slot_hash_pinned() {
+            pin_randomness_slot_hash_from_sysvar(&mut competition, &ctx.accounts.slot_hashes)?;
+        }
+
+        competition.penalize_missed_randomness_reveal(now)?;
+
+        msg!(
+            "reveal missed for round_number={}, re-request with switchboard",
+            competition.round_number
+        );
+    }
+
+    emit!(RandomnessRevealedRecord {
+        competition: competition_key,
+        round_number: competition.round_number,
+        commitment: competition.randomness_commitment,
+        secret: [0; 32],
+        slot_hash: competition.randomness_slot_hash,
+        slot: competition.randomness_slot_hash_slot,
+        reveal_missed: !slot_hash_pin_missed,
+        slot_hash_pin_missed,
+        reveals_missed: competition.randomness_reveals_missed,
+        winner_randomness: 0,
+        prize_randomness: 0,
+        ts: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveMissedReveal<'info> {
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+    pub keeper: Signer<'info>,
+    /// CHECK: slot hashes sysvar, read in place
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
@@ -0,0 +1,80 @@
+use super::pin_randomness_slot_hash::pin_randomness_slot_hash_from_sysvar;
+use super::receive_randomness::resolve_randomness;
+use crate::state::events::RandomnessRevealedRecord;
+use crate::state::Competition;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_spl::token::TokenAccount;
+use drift::math::constants::QUOTE_SPOT_MARKET_INDEX;
+use drift::state::spot_market::SpotMarket;
+
+pub fn reveal_randomness<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevealRandomness<'info>>,
+    secret: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let competition_key = ctx.accounts.competition.key();
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+    let spot_market = ctx.accounts.spot_market.load()?;
+    let vault_balance = ctx.accounts.insurance_fund_vault.amount;
+
+    competition.validate_randomness_reveal(&secret)?;
+
+    if !competition.is_randomness_slot_hash_pinned() {
+        pin_randomness_slot_hash_from_sysvar(&mut competition, &ctx.accounts.slot_hashes)?;
+    }
+
+    let slot_hash = competition.randomness_slot_hash;
+    let (randomness_hash, winner_randomness, prize_randomness) =
+        competition.derive_randomness(&[competition_key.as_ref(), &secret, &slot_hash])?;
+
+    resolve_randomness(
+        competition_key,
+        &mut competition,
+        &spot_market,
+        vault_balance,
+        winner_randomness,
+        prize_randomness,
+        randomness_hash,
+        now,
+    )?;
+
+    emit!(RandomnessRevealedRecord {
+        competition: competition_key,
+        round_number: competition.round_number,
+        commitment: competition.randomness_commitment,
+        secret,
+        slot_hash,
+        slot: competition.randomness_slot_hash_slot,
+        reveal_missed: false,
+        slot_hash_pin_missed: false,
+        reveals_missed: competition.randomness_reveals_missed,
+        winner_randomness,
+        prize_randomness,
+        ts: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevealRandomness<'info> {
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+    // anyone holding the secret can reveal it
+    pub revealer: Signer<'info>,
+    #[account(
+        constraint = spot_market.load()?.market_index == QUOTE_SPOT_MARKET_INDEX,
+    )]
+    pub spot_market: AccountLoader<'info, SpotMarket>,
+    #[account(
+        constraint = spot_market.load()?.insurance_fund.vault == insurance_fund_vault.key(),
+    )]
+    pub insurance_fund_vault: Account<'info, TokenAccount>,
+    /// CHECK: slot hashes sysvar, read in place
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
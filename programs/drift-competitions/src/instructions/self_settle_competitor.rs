@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions;
+
+use super::claim_entry::validate_claim_entry_ix;
+use super::constraints::*;
+use crate::error::ErrorCode;
+use crate::state::{Competition, Competitor, TicketIndexPage};
+use drift::state::user::UserStats;
+
+pub fn self_settle_competitor<'info>(
+    ctx: Context<'_, '_, '_, 'info, SelfSettleCompetitor<'info>>,
+    claim_entry: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    // same restrictions as claim_entry
+    if claim_entry {
+        let instructions = ctx
+            .accounts
+            .instructions
+            .as_ref()
+            .ok_or(ErrorCode::CompetitorHasInvalidClaim)?;
+        validate_claim_entry_ix(instructions.as_ref())?;
+    }
+
+    let competitor_pubkey = ctx.accounts.competitor.key();
+    let competition_pubkey = ctx.accounts.competition.key();
+
+    let mut competitor = ctx.accounts.competitor.load_mut()?;
+    let mut competition = ctx.accounts.competition.load_mut()?;
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+
+    let mut ticket_index_page = match &ctx.accounts.ticket_index_page {
+        Some(ticket_index_page) => Some(ticket_index_page.load_mut()?),
+        None => None,
+    };
+
+    // the competitor signs for themselves, so the keeper allowlist doesn't apply and the
+    // entry counts towards this settlement
+    if claim_entry {
+        competitor.claim_entry()?;
+    }
+
+    competition.self_settle_competitor(
+        &mut competitor,
+        &user_stats,
+        ticket_index_page.as_deref_mut(),
+        now,
+        competitor_pubkey,
+        competition_pubkey,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SelfSettleCompetitor<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = can_sign_for_competitor(&competitor, &authority)?
+    )]
+    pub competitor: AccountLoader<'info, Competitor>,
+    #[account(
+        mut,
+        constraint = is_competition_for_competitor(&competitor, &competition)?
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    #[account(
+        constraint = is_user_stats_for_competitor(&competitor, &drift_user_stats)?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_ticket_index_page_for_competition(&ticket_index_page, &competition)?
+    )]
+    pub ticket_index_page: Option<AccountLoader<'info, TicketIndexPage>>,
+    /// CHECK: fixed instructions sysvar account (required to claim the free entry)
+    #[account(address = instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+}
@@ -1,10 +1,8 @@
 use anchor_lang::prelude::*;
 
 use super::constraints::*;
-use crate::error::ErrorCode;
-use crate::state::{Competition, Competitor};
+use crate::state::{Competition, CompetitionKeeper, Competitor, TicketIndexPage};
 use drift::state::user::UserStats;
-use drift::validate;
 
 pub fn settle_competitor<'info>(
     ctx: Context<'_, '_, '_, 'info, SettleCompetitor<'info>>,
@@ -19,20 +17,29 @@ pub fn settle_competitor<'info>(
     let mut competition = ctx.accounts.competition.load_mut()?;
     let user_stats = ctx.accounts.drift_user_stats.load()?;
 
-    competition.settle_competitor(
+    let mut competition_keeper = match &ctx.accounts.competition_keeper {
+        Some(competition_keeper) => Some(competition_keeper.load_mut()?),
+        None => None,
+    };
+    competition.validate_keeper_is_allowed(competition_keeper.is_some())?;
+
+    let mut ticket_index_page = match &ctx.accounts.ticket_index_page {
+        Some(ticket_index_page) => Some(ticket_index_page.load_mut()?),
+        None => None,
+    };
+
+    let settled = competition.settle_and_index_competitor(
         &mut competitor,
         &user_stats,
+        ticket_index_page.as_deref_mut(),
         now,
         competitor_pubkey,
         competition_pubkey,
     )?;
 
-    if competition.number_of_competitors == competition.number_of_competitors_settled {
-        validate!(
-            competition.total_score_settled != 0,
-            ErrorCode::InvalidRoundSettlementDetected,
-            "total_score_settled is 0 after settling all competitors, round cannot end until competitors have one entry"
-        )?;
+    // only reward settlements that made progress
+    if settled {
+        competition.reward_keeper(competition_keeper.as_deref_mut(), now)?;
     }
 
     Ok(())
@@ -53,4 +60,14 @@ pub struct SettleCompetitor<'info> {
         constraint = is_user_stats_for_competitor(&competitor, &drift_user_stats)?
     )]
     pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_keeper_for_competition(&competition_keeper, &competition, &keeper)?
+    )]
+    pub competition_keeper: Option<AccountLoader<'info, CompetitionKeeper>>,
+    #[account(
+        mut,
+        constraint = is_ticket_index_page_for_competition(&ticket_index_page, &competition)?
+    )]
+    pub ticket_index_page: Option<AccountLoader<'info, TicketIndexPage>>,
 }
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use super::constraints::*;
+use crate::error::ErrorCode;
+use crate::state::{Competition, CompetitionKeeper, Competitor, TicketIndexPage};
+use drift::state::user::UserStats;
+use drift::validate;
+
+// remaining_accounts: [competitor (mut), drift_user_stats] pairs, at most
+// MAX_SETTLE_COMPETITORS_BATCH_SIZE of them. with the ticket index enabled a batch stops at
+// the end of the given ticket_index_page
+pub const MAX_SETTLE_COMPETITORS_BATCH_SIZE: usize = 10;
+
+pub fn settle_competitors_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleCompetitorsBatch<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let competition_pubkey = ctx.accounts.competition.key();
+
+    validate!(
+        !ctx.remaining_accounts.is_empty()
+            && ctx.remaining_accounts.len() % 2 == 0
+            && ctx.remaining_accounts.len() <= MAX_SETTLE_COMPETITORS_BATCH_SIZE * 2,
+        ErrorCode::InvalidBatchSettlementAccounts,
+        "expected 1 to {} (competitor, drift_user_stats) pairs, got {} accounts",
+        MAX_SETTLE_COMPETITORS_BATCH_SIZE,
+        ctx.remaining_accounts.len()
+    )?;
+
+    let mut account_loaders = Vec::with_capacity(ctx.remaining_accounts.len() / 2);
+    for account_pair in ctx.remaining_accounts.chunks_exact(2) {
+        let competitor_account_info = &account_pair[0];
+        let drift_user_stats_account_info = &account_pair[1];
+
+        validate!(
+            competitor_account_info.is_writable,
+            ErrorCode::InvalidBatchSettlementAccounts,
+            "competitor {} must be writable",
+            competitor_account_info.key()
+        )?;
+
+        let competitor_loader: AccountLoader<Competitor> =
+            AccountLoader::try_from(competitor_account_info)?;
+        let drift_user_stats_loader: AccountLoader<UserStats> =
+            AccountLoader::try_from(drift_user_stats_account_info)?;
+
+        account_loaders.push((competitor_loader, drift_user_stats_loader));
+    }
+
+    // a competitor passed twice fails to borrow
+    let mut accounts = Vec::with_capacity(account_loaders.len());
+    for (competitor_loader, drift_user_stats_loader) in account_loaders.iter() {
+        accounts.push((
+            competitor_loader.load_mut()?,
+            competitor_loader.key(),
+            drift_user_stats_loader.load()?,
+            drift_user_stats_loader.key(),
+        ));
+    }
+    let mut competitors: Vec<(&mut Competitor, Pubkey, &UserStats, Pubkey)> = accounts
+        .iter_mut()
+        .map(
+            |(competitor, competitor_pubkey, user_stats, user_stats_pubkey)| {
+                (
+                    &mut **competitor,
+                    *competitor_pubkey,
+                    &**user_stats,
+                    *user_stats_pubkey,
+                )
+            },
+        )
+        .collect();
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    let mut competition_keeper = match &ctx.accounts.competition_keeper {
+        Some(competition_keeper) => Some(competition_keeper.load_mut()?),
+        None => None,
+    };
+    competition.validate_keeper_is_allowed(competition_keeper.is_some())?;
+
+    let mut ticket_index_page = match &ctx.accounts.ticket_index_page {
+        Some(ticket_index_page) => Some(ticket_index_page.load_mut()?),
+        None => None,
+    };
+
+    let number_of_competitors_settled = competition.settle_competitors_batch(
+        &mut competitors,
+        ticket_index_page.as_deref_mut(),
+        now,
+        competition_pubkey,
+    )?;
+
+    // only reward settlements that made progress
+    for _ in 0..number_of_competitors_settled {
+        competition.reward_keeper(competition_keeper.as_deref_mut(), now)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleCompetitorsBatch<'info> {
+    #[account(mut)]
+    keeper: Signer<'info>,
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+    #[account(
+        mut,
+        constraint = is_keeper_for_competition(&competition_keeper, &competition, &keeper)?
+    )]
+    pub competition_keeper: Option<AccountLoader<'info, CompetitionKeeper>>,
+    #[account(
+        mut,
+        constraint = is_ticket_index_page_for_competition(&ticket_index_page, &competition)?
+    )]
+    pub ticket_index_page: Option<AccountLoader<'info, TicketIndexPage>>,
+}
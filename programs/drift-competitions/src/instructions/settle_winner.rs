@@ -1,34 +1,177 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::associated_token::{self, AssociatedToken, Create};
+use anchor_spl::token::spl_token::state::Mint as SplMint;
+use anchor_spl::token::{initialize_mint2, mint_to, InitializeMint2, MintTo, Token, TokenAccount};
 
 use super::constraints::*;
-use crate::state::{Competition, Competitor};
+use crate::error::ErrorCode;
+use crate::signer_seeds::get_competition_authority_seeds;
+use crate::state::{Competition, CompetitionKeeper, Competitor};
 use drift::math::constants::QUOTE_SPOT_MARKET_INDEX;
 use drift::state::spot_market::SpotMarket;
 use drift::state::user::UserStats;
 
 pub fn settle_winner<'info>(ctx: Context<'_, '_, '_, 'info, SettleWinner<'info>>) -> Result<()> {
+    settle_winner_for_accounts(ctx.accounts)
+}
+
+// shared with settle_winner_by_index
+pub fn settle_winner_for_accounts<'info>(accounts: &SettleWinner<'info>) -> Result<()> {
     let clock = Clock::get()?;
     let now = clock.unix_timestamp;
 
-    let mut competitor = ctx.accounts.competitor.load_mut()?;
-    let mut competition = ctx.accounts.competition.load_mut()?;
-    let spot_market = ctx.accounts.spot_market.load()?;
-    let vault_balance = ctx.accounts.insurance_fund_vault.amount;
+    let competition_key = accounts.competition.key();
+    let bump = accounts.competition.load()?.competition_authority_bump;
+    let competition_authority_seeds = get_competition_authority_seeds(&competition_key, &bump);
+
+    let mut competitor = accounts.competitor.load_mut()?;
+    let mut competition = accounts.competition.load_mut()?;
+    let spot_market = accounts.spot_market.load()?;
+    let vault_balance = accounts.insurance_fund_vault.amount;
+
+    let winner_badge_mint = if competition.winner_badges_enabled() {
+        match &accounts.winner_badge_mint {
+            Some(winner_badge_mint) => Some(winner_badge_mint.key()),
+            None => return Err(ErrorCode::WinnerBadgeAccountsMissing.into()),
+        }
+    } else {
+        None
+    };
+
+    let mut competition_keeper = match &accounts.competition_keeper {
+        Some(competition_keeper) => Some(competition_keeper.load_mut()?),
+        None => None,
+    };
+    competition.validate_keeper_is_allowed(competition_keeper.is_some())?;
+
+    let number_of_winners_settled_before = competition.number_of_winners_settled;
 
     competition.settle_winner(
         &mut competitor,
         &spot_market,
         vault_balance,
         now,
-        ctx.accounts.competitor.key(),
-        ctx.accounts.competition.key(),
+        accounts.competitor.key(),
+        competition_key,
+        winner_badge_mint,
     )?;
 
+    let winner_settled = competition.number_of_winners_settled > number_of_winners_settled_before;
+
+    if winner_settled {
+        competition.reward_keeper(competition_keeper.as_deref_mut(), now)?;
+    }
+
     if competition.number_of_winners == competition.number_of_winners_settled {
         competition.reset_round(now)?;
     }
 
+    if winner_settled && winner_badge_mint.is_some() {
+        let winner_authority = accounts
+            .winner_authority
+            .as_ref()
+            .ok_or(ErrorCode::WinnerBadgeAccountsMissing)?;
+        competitor.validate_winner_badge_authority(winner_authority.key)?;
+    }
+
+    drop(competitor);
+    drop(competition);
+    drop(spot_market);
+    drop(competition_keeper);
+
+    if winner_settled && winner_badge_mint.is_some() {
+        mint_winner_badge(accounts, &[&competition_authority_seeds[..]])?;
+    }
+
+    Ok(())
+}
+
+fn mint_winner_badge<'info>(
+    accounts: &SettleWinner<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let winner_badge_mint = accounts
+        .winner_badge_mint
+        .as_ref()
+        .ok_or(ErrorCode::WinnerBadgeAccountsMissing)?;
+    let winner_badge_token_account = accounts
+        .winner_badge_token_account
+        .as_ref()
+        .ok_or(ErrorCode::WinnerBadgeAccountsMissing)?;
+    let winner_authority = accounts
+        .winner_authority
+        .as_ref()
+        .ok_or(ErrorCode::WinnerBadgeAccountsMissing)?;
+    let competition_authority = accounts
+        .competition_authority
+        .as_ref()
+        .ok_or(ErrorCode::WinnerBadgeAccountsMissing)?;
+    let token_program = accounts
+        .token_program
+        .as_ref()
+        .ok_or(ErrorCode::WinnerBadgeAccountsMissing)?;
+    let associated_token_program = accounts
+        .associated_token_program
+        .as_ref()
+        .ok_or(ErrorCode::WinnerBadgeAccountsMissing)?;
+    let system_program = accounts
+        .system_program
+        .as_ref()
+        .ok_or(ErrorCode::WinnerBadgeAccountsMissing)?;
+
+    // non-fungible: decimals 0 and exactly one token ever minted by competition_authority
+    create_account(
+        CpiContext::new(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: accounts.keeper.to_account_info(),
+                to: winner_badge_mint.to_account_info(),
+            },
+        ),
+        Rent::get()?.minimum_balance(SplMint::LEN),
+        SplMint::LEN as u64,
+        token_program.key,
+    )?;
+
+    initialize_mint2(
+        CpiContext::new(
+            token_program.to_account_info(),
+            InitializeMint2 {
+                mint: winner_badge_mint.to_account_info(),
+            },
+        ),
+        0,
+        competition_authority.key,
+        None,
+    )?;
+
+    associated_token::create(CpiContext::new(
+        associated_token_program.to_account_info(),
+        Create {
+            payer: accounts.keeper.to_account_info(),
+            associated_token: winner_badge_token_account.clone(),
+            authority: winner_authority.clone(),
+            mint: winner_badge_mint.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+    ))?;
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: winner_badge_mint.to_account_info(),
+                to: winner_badge_token_account.clone(),
+                authority: competition_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
     Ok(())
 }
 
@@ -56,4 +199,26 @@ pub struct SettleWinner<'info> {
         constraint = spot_market.load()?.insurance_fund.vault == insurance_fund_vault.key(),
     )]
     pub insurance_fund_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = is_keeper_for_competition(&competition_keeper, &competition, &keeper)?
+    )]
+    pub competition_keeper: Option<AccountLoader<'info, CompetitionKeeper>>,
+
+    // WINNER BADGE ACCOUNTS (required when competition.winner_badges_enabled)
+    #[account(mut)]
+    pub winner_badge_mint: Option<Signer<'info>>,
+    /// CHECK: checked in cpi
+    #[account(mut)]
+    pub winner_badge_token_account: Option<AccountInfo<'info>>,
+    /// CHECK: checked against competitor authority
+    pub winner_authority: Option<AccountInfo<'info>>,
+    /// CHECK
+    #[account(
+        constraint = competition.load()?.competition_authority == competition_authority.key()
+    )]
+    pub competition_authority: Option<AccountInfo<'info>>,
+    pub token_program: Option<Program<'info, Token>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Option<Program<'info, System>>,
 }
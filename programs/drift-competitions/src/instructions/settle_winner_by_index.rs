@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use super::settle_winner::{settle_winner_for_accounts, SettleWinner};
+use crate::error::ErrorCode;
+use crate::state::TicketIndexPage;
+use drift::validate;
+
+pub fn settle_winner_by_index<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleWinnerByIndex<'info>>,
+) -> Result<()> {
+    let competition = ctx.accounts.settle_winner.competition.load()?;
+    let ticket_index_page = ctx.accounts.ticket_index_page.load()?;
+
+    // the page resolves the winner, so keepers don't need an off-chain scan and only the page
+    // holding winner_randomness is loaded, however many pages the round has
+    let ticket_range =
+        ticket_index_page.find_winner(competition.round_number, competition.winner_randomness)?;
+
+    validate!(
+        ticket_range.competitor == ctx.accounts.settle_winner.competitor.key(),
+        ErrorCode::CompetitorNotWinner,
+        "ticket index page {} resolves winner_randomness={} to competitor {}",
+        ticket_index_page.page_number,
+        competition.winner_randomness,
+        ticket_range.competitor
+    )?;
+
+    drop(competition);
+    drop(ticket_index_page);
+
+    settle_winner_for_accounts(&ctx.accounts.settle_winner)
+}
+
+#[derive(Accounts)]
+pub struct SettleWinnerByIndex<'info> {
+    pub settle_winner: SettleWinner<'info>,
+    #[account(
+        constraint = ticket_index_page.load()?.competition == settle_winner.competition.key()
+    )]
+    pub ticket_index_page: AccountLoader<'info, TicketIndexPage>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use super::constraints::*;
+use crate::state::events::WinnerSkippedRecord;
+use crate::state::{Competition, CompetitionKeeper};
+
+pub fn skip_winner<'info>(ctx: Context<'_, '_, '_, 'info, SkipWinner<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    competition.validate_keeper_is_allowed(ctx.accounts.competition_keeper.is_some())?;
+
+    let winner_placement = competition.number_of_winners_settled;
+    let skipped_winner_randomness = competition.winner_randomness;
+
+    // no keeper reward: skipping doesn't settle a winner
+    competition.skip_winner(now)?;
+
+    emit!(WinnerSkippedRecord {
+        competition: ctx.accounts.competition.key(),
+        round_number: competition.round_number,
+        winner_placement,
+        skipped_winner_randomness,
+        winner_randomness: competition.winner_randomness,
+        winner_redraws: competition.winner_redraws,
+        ts: now,
+    });
+
+    emit!(competition.get_winner_draw_record(ctx.accounts.competition.key(), now));
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SkipWinner<'info> {
+    keeper: Signer<'info>,
+    #[account(mut)]
+    pub competition: AccountLoader<'info, Competition>,
+    #[account(
+        constraint = is_keeper_for_competition(&competition_keeper, &competition, &keeper)?
+    )]
+    pub competition_keeper: Option<AccountLoader<'info, CompetitionKeeper>>,
+}
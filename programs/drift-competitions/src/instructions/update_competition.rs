@@ -1,69 +1,158 @@
 use super::constraints::is_sponsor_for_competition;
-use crate::state::{Competition, CompetitionRoundStatus};
+use crate::state::{Competition, RandomnessProvider, RoundParamsScheduledRecord};
+use crate::validation::{
+    validate_max_sponsor_fraction, validate_number_of_winners, validate_randomness_provider,
+};
 use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
 
 pub fn update_competition<'info>(
     ctx: Context<'_, '_, '_, 'info, UpdateCompetition<'info>>,
     params: UpdateCompetitionParams,
 ) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let competition_key = ctx.accounts.competition.key();
     let mut competition = ctx.accounts.competition.load_mut()?;
 
-    if let Some(next_round_expiry_ts) = params.next_round_expiry_ts {
-        competition.next_round_expiry_ts = next_round_expiry_ts;
+    // round rules are only changed at the round boundary (see reset_round)
+    let mut round_params_scheduled = false;
+
+    if let Some(max_entries_per_competitor) = params.max_entries_per_competitor {
+        competition
+            .pending_round_params
+            .set_max_entries_per_competitor(max_entries_per_competitor);
+        round_params_scheduled = true;
     }
 
-    if let Some(competition_expiry_ts) = params.competition_expiry_ts {
-        competition.competition_expiry_ts = competition_expiry_ts;
+    if let Some(min_sponsor_amount) = params.min_sponsor_amount {
+        competition
+            .pending_round_params
+            .set_min_sponsor_amount(min_sponsor_amount);
+        round_params_scheduled = true;
     }
 
-    if let Some(round_duration) = params.round_duration {
-        competition.round_duration = round_duration;
+    if let Some(max_sponsor_fraction) = params.max_sponsor_fraction {
+        validate_max_sponsor_fraction(max_sponsor_fraction)?;
+        competition
+            .pending_round_params
+            .set_max_sponsor_fraction(max_sponsor_fraction);
+        round_params_scheduled = true;
     }
 
-    if let Some(max_entries_per_competitor) = params.max_entries_per_competitor {
-        competition.max_entries_per_competitor = max_entries_per_competitor;
+    if let Some(number_of_winners) = params.number_of_winners {
+        validate_number_of_winners(number_of_winners)?;
+        competition
+            .pending_round_params
+            .set_number_of_winners(number_of_winners);
+        round_params_scheduled = true;
     }
 
-    if let Some(min_sponsor_amount) = params.min_sponsor_amount {
-        competition.sponsor_info.min_sponsor_amount = min_sponsor_amount;
+    if round_params_scheduled {
+        emit!(get_round_params_scheduled_record(
+            competition_key,
+            &competition,
+            now
+        )?);
     }
 
-    if let Some(max_sponsor_fraction) = params.max_sponsor_fraction {
-        competition.sponsor_info.max_sponsor_fraction = max_sponsor_fraction;
+    if let Some(winner_badges_enabled) = params.winner_badges_enabled {
+        competition.winner_badges_enabled = winner_badges_enabled as u8;
     }
 
-    if let Some(number_of_winners) = params.number_of_winners {
-        competition.number_of_winners = number_of_winners;
+    if let Some(keeper_allowlist_enabled) = params.keeper_allowlist_enabled {
+        competition.keeper_allowlist_enabled = keeper_allowlist_enabled as u8;
+    }
+
+    if let Some(ticket_index_enabled) = params.ticket_index_enabled {
+        // the index has to cover every competitor settled in a round
+        competition.validate_round_settlement_hasnt_started(now)?;
+        competition.ticket_index_enabled = ticket_index_enabled as u8;
+    }
+
+    if let Some(randomness_provider) = params.randomness_provider {
+        // can't swap the source of a draw that is already underway
+        competition.validate_round_settlement_hasnt_started(now)?;
+        validate_randomness_provider(randomness_provider)?;
+        msg!(
+            "updating randomness provider: {:?} -> {:?}",
+            competition.randomness_provider,
+            randomness_provider
+        );
+        competition.update_randomness_provider(randomness_provider)?;
+    }
+
+    if let Some(keeper_reward) = params.keeper_reward {
+        competition.keeper_reward = keeper_reward;
     }
 
-    if let Some(reset_round_state) = params.reset_round_state {
-        if reset_round_state
-            && competition.status == CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested
-        {
-            competition.status = CompetitionRoundStatus::Active;
-        }
+    if let Some(operator) = params.operator {
+        msg!(
+            "updating operator: {} -> {}",
+            competition.operator,
+            operator
+        );
+        competition.operator = operator;
+    }
+
+    if let Some(moderator) = params.moderator {
+        msg!(
+            "updating moderator: {} -> {}",
+            competition.moderator,
+            moderator
+        );
+        competition.moderator = moderator;
     }
 
     Ok(())
 }
 
+pub fn get_round_params_scheduled_record(
+    competition_key: Pubkey,
+    competition: &Competition,
+    now: i64,
+) -> Result<RoundParamsScheduledRecord> {
+    let pending_round_params = &competition.pending_round_params;
+
+    Ok(RoundParamsScheduledRecord {
+        competition: competition_key,
+        effective_round_number: competition.round_number.safe_add(1)?,
+        max_entries_per_competitor: pending_round_params.get_max_entries_per_competitor(),
+        round_duration: pending_round_params.get_round_duration(),
+        min_sponsor_amount: pending_round_params.get_min_sponsor_amount(),
+        max_sponsor_fraction: pending_round_params.get_max_sponsor_fraction(),
+        number_of_winners: pending_round_params.get_number_of_winners(),
+        ts: now,
+    })
+}
+
 #[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub struct UpdateCompetitionParams {
-    // scheduling variables
-    pub next_round_expiry_ts: Option<i64>,
-    pub competition_expiry_ts: Option<i64>,
-    pub round_duration: Option<u64>,
-
-    // sponsor details
+    // sponsor details (applied at the next round boundary)
     pub max_entries_per_competitor: Option<u128>,
     pub min_sponsor_amount: Option<u64>,
     pub max_sponsor_fraction: Option<u64>,
 
-    // number of winners
+    // number of winners (applied at the next round boundary)
     pub number_of_winners: Option<u32>,
 
-    // attempt to reset round state
-    pub reset_round_state: Option<bool>,
+    // mint a badge token to winners
+    pub winner_badges_enabled: Option<bool>,
+
+    // keepers
+    pub keeper_allowlist_enabled: Option<bool>,
+    pub keeper_reward: Option<u64>,
+
+    // on-chain ticket range index for settle_winner_by_index
+    pub ticket_index_enabled: Option<bool>,
+
+    // where winner / prize randomness comes from
+    pub randomness_provider: Option<RandomnessProvider>,
+
+    // roles (default pubkey falls back to sponsor)
+    pub operator: Option<Pubkey>,
+    pub moderator: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
@@ -0,0 +1,100 @@
+use super::constraints::is_operator_for_competition;
+use super::update_competition::get_round_params_scheduled_record;
+use crate::state::{Competition, CompetitionRoundStatus};
+use crate::validation::{validate_competition_expiry_ts, validate_round_duration};
+use anchor_lang::prelude::*;
+
+pub fn update_competition_schedule<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateCompetitionSchedule<'info>>,
+    params: UpdateCompetitionScheduleParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let competition_key = ctx.accounts.competition.key();
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    if let Some(next_round_expiry_ts) = params.next_round_expiry_ts {
+        competition.next_round_expiry_ts = next_round_expiry_ts;
+    }
+
+    if let Some(competition_expiry_ts) = params.competition_expiry_ts {
+        competition.competition_expiry_ts = competition_expiry_ts;
+    }
+
+    if params.next_round_expiry_ts.is_some() || params.competition_expiry_ts.is_some() {
+        validate_competition_expiry_ts(
+            competition.next_round_expiry_ts,
+            competition.competition_expiry_ts,
+        )?;
+    }
+
+    if let Some(round_duration) = params.round_duration {
+        validate_round_duration(round_duration)?;
+        competition
+            .pending_round_params
+            .set_round_duration(round_duration);
+        emit!(get_round_params_scheduled_record(
+            competition_key,
+            &competition,
+            now
+        )?);
+    }
+
+    if let Some(settlement_grace_period) = params.settlement_grace_period {
+        competition.settlement_grace_period = settlement_grace_period;
+    }
+
+    if let Some(winner_settlement_timeout) = params.winner_settlement_timeout {
+        competition.winner_settlement_timeout = winner_settlement_timeout;
+    }
+
+    if let Some(randomness_request_timeout) = params.randomness_request_timeout {
+        competition.randomness_request_timeout = randomness_request_timeout;
+    }
+
+    if let Some(max_randomness_request_retries) = params.max_randomness_request_retries {
+        competition.max_randomness_request_retries = max_randomness_request_retries;
+    }
+
+    if let Some(reset_round_state) = params.reset_round_state {
+        if reset_round_state
+            && competition.status == CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested
+        {
+            competition.status = CompetitionRoundStatus::Active;
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct UpdateCompetitionScheduleParams {
+    // scheduling variables
+    pub next_round_expiry_ts: Option<i64>,
+    pub competition_expiry_ts: Option<i64>,
+    pub round_duration: Option<u64>, // applied at the next round boundary
+
+    // unsettled competitors are forfeited this long after the round end (0 = default)
+    pub settlement_grace_period: Option<u32>,
+
+    // keepers can skip a drawn winner left unsettled this long (0 = default)
+    pub winner_settlement_timeout: Option<u32>,
+
+    // keepers re-trigger an unanswered randomness request after the timeout (0 = default)
+    pub randomness_request_timeout: Option<u32>,
+    pub max_randomness_request_retries: Option<u8>,
+
+    // attempt to reset round state
+    pub reset_round_state: Option<bool>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCompetitionSchedule<'info> {
+    #[account(
+        mut,
+        constraint = is_operator_for_competition(&competition, &operator)?,
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    pub operator: Signer<'info>,
+}
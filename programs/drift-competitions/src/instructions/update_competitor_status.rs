@@ -31,10 +31,10 @@ pub fn update_competitor_status<'info>(
 pub struct UpdateCompetitorStatus<'info> {
     #[account(
         mut,
-        constraint = is_sponsor_for_competition(&competition, &sponsor)?,
+        constraint = is_moderator_for_competition(&competition, &moderator)?,
     )]
     pub competition: AccountLoader<'info, Competition>,
-    pub sponsor: Signer<'info>,
+    pub moderator: Signer<'info>,
     #[account(mut)]
     pub competitor: AccountLoader<'info, Competitor>,
     #[account(
@@ -0,0 +1,83 @@
+use super::constraints::is_operator_for_competition;
+use crate::error::ErrorCode;
+use crate::state::{Competition, RoundScheduleMode};
+use anchor_lang::prelude::*;
+use drift::validate;
+
+pub fn update_round_schedule<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateRoundSchedule<'info>>,
+    params: UpdateRoundScheduleParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let mut competition = ctx.accounts.competition.load_mut()?;
+
+    // takes effect when the current round resets, next_round_expiry_ts is left as is
+    let round_schedule = &mut competition.round_schedule;
+
+    if let Some(mode) = params.mode {
+        msg!(
+            "updating round schedule mode: {:?} -> {:?}",
+            round_schedule.mode,
+            mode
+        );
+        round_schedule.mode = mode;
+    }
+
+    if let Some(weekday_mask) = params.weekday_mask {
+        round_schedule.weekday_mask = weekday_mask;
+    }
+
+    if let Some(hour) = params.hour {
+        round_schedule.hour = hour;
+    }
+
+    if let Some(minute) = params.minute {
+        round_schedule.minute = minute;
+    }
+
+    if params.clear_expiries == Some(true) {
+        round_schedule.clear();
+    }
+
+    for expiry_ts in params.add_expiries {
+        validate!(
+            expiry_ts > now,
+            ErrorCode::InvalidRoundSchedule,
+            "round schedule expiry_ts={} is in the past",
+            expiry_ts
+        )?;
+        round_schedule.push(expiry_ts)?;
+    }
+
+    if round_schedule.mode == RoundScheduleMode::Weekly {
+        round_schedule.validate_weekly_rule()?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct UpdateRoundScheduleParams {
+    pub mode: Option<RoundScheduleMode>,
+
+    // weekly rule (utc)
+    pub weekday_mask: Option<u8>,
+    pub hour: Option<u8>,
+    pub minute: Option<u8>,
+
+    // explicit round ends (Explicit) or skipped round ends (Weekly), ascending
+    pub clear_expiries: Option<bool>,
+    pub add_expiries: Vec<i64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRoundSchedule<'info> {
+    #[account(
+        mut,
+        constraint = is_operator_for_competition(&competition, &operator)?,
+    )]
+    pub competition: AccountLoader<'info, Competition>,
+    pub operator: Signer<'info>,
+}
@@ -8,6 +8,7 @@ mod instructions;
 mod signer_seeds;
 pub mod state;
 mod utils;
+mod validation;
 
 #[cfg(test)]
 mod tests;
@@ -19,7 +20,39 @@ pub mod drift_competitions {
 
     use super::*;
 
-    // sponsor ix
+    // admin ix
+    pub fn initialize_competition_registry<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeCompetitionRegistry<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_competition_registry(ctx)
+    }
+
+    pub fn initialize_competition_registry_page<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeCompetitionRegistryPage<'info>>,
+        page_number: u32,
+    ) -> Result<()> {
+        instructions::initialize_competition_registry_page(ctx, page_number)
+    }
+
+    pub fn register_competition<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterCompetition<'info>>,
+    ) -> Result<()> {
+        instructions::register_competition(ctx)
+    }
+
+    pub fn deregister_competition<'info>(
+        ctx: Context<'_, '_, '_, 'info, DeregisterCompetition<'info>>,
+    ) -> Result<()> {
+        instructions::deregister_competition(ctx)
+    }
+
+    pub fn migrate_competition<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateCompetition<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_competition(ctx)
+    }
+
+    // sponsor / operator / moderator ix
     pub fn initialize_competition<'info>(
         ctx: Context<'_, '_, '_, 'info, InitializeCompetition<'info>>,
         params: CompetitionParams,
@@ -34,6 +67,20 @@ pub mod drift_competitions {
         instructions::update_competition(ctx, params)
     }
 
+    pub fn update_competition_schedule<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateCompetitionSchedule<'info>>,
+        params: UpdateCompetitionScheduleParams,
+    ) -> Result<()> {
+        instructions::update_competition_schedule(ctx, params)
+    }
+
+    pub fn update_round_schedule<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateRoundSchedule<'info>>,
+        params: UpdateRoundScheduleParams,
+    ) -> Result<()> {
+        instructions::update_round_schedule(ctx, params)
+    }
+
     pub fn update_switchboard_function<'info>(
         ctx: Context<'_, '_, '_, 'info, UpdateSwitchboardFunction<'info>>,
     ) -> Result<()> {
@@ -47,6 +94,56 @@ pub mod drift_competitions {
         instructions::update_competitor_status(ctx, new_status)
     }
 
+    pub fn pause_competition<'info>(
+        ctx: Context<'_, '_, '_, 'info, PauseCompetition<'info>>,
+    ) -> Result<()> {
+        instructions::pause_competition(ctx)
+    }
+
+    pub fn resume_competition<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResumeCompetition<'info>>,
+    ) -> Result<()> {
+        instructions::resume_competition(ctx)
+    }
+
+    pub fn close_competition<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseCompetition<'info>>,
+    ) -> Result<()> {
+        instructions::close_competition(ctx)
+    }
+
+    pub fn propose_sponsor<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProposeSponsor<'info>>,
+        pending_sponsor: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_sponsor(ctx, pending_sponsor)
+    }
+
+    pub fn accept_sponsor<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptSponsor<'info>>,
+    ) -> Result<()> {
+        instructions::accept_sponsor(ctx)
+    }
+
+    pub fn add_competition_keeper<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddCompetitionKeeper<'info>>,
+    ) -> Result<()> {
+        instructions::add_competition_keeper(ctx)
+    }
+
+    pub fn remove_competition_keeper<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveCompetitionKeeper<'info>>,
+    ) -> Result<()> {
+        instructions::remove_competition_keeper(ctx)
+    }
+
+    pub fn commit_randomness<'info>(
+        ctx: Context<'_, '_, '_, 'info, CommitRandomness<'info>>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_randomness(ctx, commitment)
+    }
+
     // competitor ix
     pub fn initialize_competitor<'info>(
         ctx: Context<'_, '_, '_, 'info, InitializeCompetitor<'info>>,
@@ -72,14 +169,40 @@ pub mod drift_competitions {
         instructions::claim_winnings(ctx, n_shares)
     }
 
+    pub fn close_competitor<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseCompetitor<'info>>,
+    ) -> Result<()> {
+        instructions::close_competitor(ctx)
+    }
+
+    pub fn self_settle_competitor<'info>(
+        ctx: Context<'_, '_, '_, 'info, SelfSettleCompetitor<'info>>,
+        claim_entry: bool,
+    ) -> Result<()> {
+        instructions::self_settle_competitor(ctx, claim_entry)
+    }
+
     // keeper ix
 
+    pub fn initialize_ticket_index_page<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeTicketIndexPage<'info>>,
+        page_number: u32,
+    ) -> Result<()> {
+        instructions::initialize_ticket_index_page(ctx, page_number)
+    }
+
     pub fn settle_competitor<'info>(
         ctx: Context<'_, '_, '_, 'info, SettleCompetitor<'info>>,
     ) -> Result<()> {
         instructions::settle_competitor(ctx)
     }
 
+    pub fn settle_competitors_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleCompetitorsBatch<'info>>,
+    ) -> Result<()> {
+        instructions::settle_competitors_batch(ctx)
+    }
+
     pub fn request_randomness<'info>(
         ctx: Context<'_, '_, '_, 'info, RequestRandomness<'info>>,
         bounty: Option<u64>,
@@ -89,10 +212,41 @@ pub mod drift_competitions {
 
     pub fn receive_randomness<'info>(
         ctx: Context<'_, '_, '_, 'info, ReceiveRandomness<'info>>,
-        winner_randomness: u128,
-        prize_randomness: u128,
+        winner_randomness_raw: u128,
+        prize_randomness_raw: u128,
+    ) -> Result<()> {
+        instructions::receive_randomness(ctx, winner_randomness_raw, prize_randomness_raw)
+    }
+
+    pub fn request_commit_reveal_randomness<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestCommitRevealRandomness<'info>>,
+    ) -> Result<()> {
+        instructions::request_commit_reveal_randomness(ctx)
+    }
+
+    pub fn pin_randomness_slot_hash<'info>(
+        ctx: Context<'_, '_, '_, 'info, PinRandomnessSlotHash<'info>>,
     ) -> Result<()> {
-        instructions::receive_randomness(ctx, winner_randomness, prize_randomness)
+        instructions::pin_randomness_slot_hash(ctx)
+    }
+
+    pub fn reveal_randomness<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealRandomness<'info>>,
+        secret: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_randomness(ctx, secret)
+    }
+
+    pub fn resolve_missed_reveal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResolveMissedReveal<'info>>,
+    ) -> Result<()> {
+        instructions::resolve_missed_reveal(ctx)
+    }
+
+    pub fn draw_slot_hash_randomness<'info>(
+        ctx: Context<'_, '_, '_, 'info, DrawSlotHashRandomness<'info>>,
+    ) -> Result<()> {
+        instructions::draw_slot_hash_randomness(ctx)
     }
 
     pub fn settle_winner<'info>(
@@ -100,4 +254,26 @@ pub mod drift_competitions {
     ) -> Result<()> {
         instructions::settle_winner(ctx)
     }
+
+    pub fn settle_winner_by_index<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleWinnerByIndex<'info>>,
+    ) -> Result<()> {
+        instructions::settle_winner_by_index(ctx)
+    }
+
+    pub fn skip_winner<'info>(ctx: Context<'_, '_, '_, 'info, SkipWinner<'info>>) -> Result<()> {
+        instructions::skip_winner(ctx)
+    }
+
+    pub fn expire_competition<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExpireCompetition<'info>>,
+    ) -> Result<()> {
+        instructions::expire_competition(ctx)
+    }
+
+    pub fn claim_keeper_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimKeeperReward<'info>>,
+    ) -> Result<()> {
+        instructions::claim_keeper_reward(ctx)
+    }
 }
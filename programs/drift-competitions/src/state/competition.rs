@@ -1,7 +1,10 @@
-use crate::state::events::CompetitionRoundWinnerRecord;
-use crate::state::{CompetitorSettledRecord, Size};
+use crate::state::events::{CompetitionRoundWinnerRecord, WinnerDrawRecord};
+use crate::state::{
+    CompetitorSettledRecord, RoundSchedule, RoundScheduleMode, Size, TicketIndexPage,
+};
 use crate::utils::{
     apply_rebase_to_competition_prize, apply_rebase_to_competitor_unclaimed_winnings,
+    range_unbiased_randomness,
 };
 use drift::{
     error::DriftResult,
@@ -16,16 +19,35 @@ use anchor_lang::prelude::*;
 use drift::state::user::UserStats;
 use static_assertions::const_assert_eq;
 
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::slot_hashes::MAX_ENTRIES;
 use drift::math::casting::Cast;
 use drift::math::safe_math::SafeMath;
 
-use super::Competitor;
+use super::{CompetitionKeeper, Competitor};
 use borsh::{BorshDeserialize, BorshSerialize};
 
 use drift_macros::assert_no_slop;
 
 use drift::math::insurance::{if_shares_to_vault_amount, vault_amount_to_if_shares};
 
+// winners can still claim for this long after the competition expires, then it is forfeited
+pub const EXPIRED_COMPETITION_CLAIM_WINDOW: i64 = 60 * 60 * 24 * 30; // 30 days
+
+// used while winner_settlement_timeout is unset
+pub const DEFAULT_WINNER_SETTLEMENT_TIMEOUT: u32 = 60 * 60; // 1 hour
+
+// used while randomness_request_timeout / max_randomness_request_retries are unset
+pub const DEFAULT_RANDOMNESS_REQUEST_TIMEOUT: u32 = 60 * 10; // 10 minutes
+pub const DEFAULT_MAX_RANDOMNESS_REQUEST_RETRIES: u8 = 5;
+
+// the request slot is in the slot hashes sysvar for at least this many slots (~3.4 minutes),
+// so the slot hash after it has to be pinned within them
+pub const RANDOMNESS_SLOT_HASH_PIN_WINDOW: u64 = MAX_ENTRIES as u64;
+
+// used while settlement_grace_period is unset
+pub const DEFAULT_SETTLEMENT_GRACE_PERIOD: u32 = 60 * 60 * 24; // 1 day
+
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialOrd, Ord, PartialEq, Eq, Debug)]
 pub enum CompetitionRoundStatus {
     Active = 0,
@@ -33,6 +55,7 @@ pub enum CompetitionRoundStatus {
     WinnerAndPrizeRandomnessComplete = 2,
     WinnerSettlementComplete = 3,
     Expired = 4,
+    Paused = 5,
 }
 
 impl Default for CompetitionRoundStatus {
@@ -41,6 +64,22 @@ impl Default for CompetitionRoundStatus {
     }
 }
 
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug)]
+pub enum RandomnessProvider {
+    // request_randomness / receive_randomness via the switchboard function
+    Switchboard = 0,
+    // sponsor commits sha256(secret) before round end, reveal_randomness mixes it with a slot hash
+    CommitReveal = 1,
+    // draw_slot_hash_randomness uses the most recent slot hash (devnet / test builds only)
+    SlotHash = 2,
+}
+
+impl Default for RandomnessProvider {
+    fn default() -> Self {
+        RandomnessProvider::Switchboard
+    }
+}
+
 #[zero_copy(unsafe)]
 #[derive(Default, Eq, PartialEq, Debug)]
 pub struct SponsorInfo {
@@ -49,6 +88,88 @@ pub struct SponsorInfo {
     pub max_sponsor_fraction: u64, // only take this percent of gain above the min amount
 }
 
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum PendingRoundParam {
+    MaxEntriesPerCompetitor = 0b00000001,
+    RoundDuration = 0b00000010,
+    MinSponsorAmount = 0b00000100,
+    MaxSponsorFraction = 0b00001000,
+    NumberOfWinners = 0b00010000,
+}
+
+// parameter changes held until the current round resets
+#[zero_copy(unsafe)]
+#[derive(Default, Eq, PartialEq, Debug)]
+pub struct PendingRoundParams {
+    pub max_entries_per_competitor: u128,
+    pub round_duration: u64,
+    pub min_sponsor_amount: u64,
+    pub max_sponsor_fraction: u64,
+    pub number_of_winners: u32,
+    pub flags: u8, // PendingRoundParam bitmask of which params are set
+    pub padding: [u8; 3],
+}
+
+impl PendingRoundParams {
+    pub fn is_set(&self, param: PendingRoundParam) -> bool {
+        self.flags & param as u8 != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.flags == 0
+    }
+
+    pub fn set_max_entries_per_competitor(&mut self, max_entries_per_competitor: u128) {
+        self.max_entries_per_competitor = max_entries_per_competitor;
+        self.flags |= PendingRoundParam::MaxEntriesPerCompetitor as u8;
+    }
+
+    pub fn set_round_duration(&mut self, round_duration: u64) {
+        self.round_duration = round_duration;
+        self.flags |= PendingRoundParam::RoundDuration as u8;
+    }
+
+    pub fn set_min_sponsor_amount(&mut self, min_sponsor_amount: u64) {
+        self.min_sponsor_amount = min_sponsor_amount;
+        self.flags |= PendingRoundParam::MinSponsorAmount as u8;
+    }
+
+    pub fn set_max_sponsor_fraction(&mut self, max_sponsor_fraction: u64) {
+        self.max_sponsor_fraction = max_sponsor_fraction;
+        self.flags |= PendingRoundParam::MaxSponsorFraction as u8;
+    }
+
+    pub fn set_number_of_winners(&mut self, number_of_winners: u32) {
+        self.number_of_winners = number_of_winners;
+        self.flags |= PendingRoundParam::NumberOfWinners as u8;
+    }
+
+    pub fn get_max_entries_per_competitor(&self) -> Option<u128> {
+        self.is_set(PendingRoundParam::MaxEntriesPerCompetitor)
+            .then_some(self.max_entries_per_competitor)
+    }
+
+    pub fn get_round_duration(&self) -> Option<u64> {
+        self.is_set(PendingRoundParam::RoundDuration)
+            .then_some(self.round_duration)
+    }
+
+    pub fn get_min_sponsor_amount(&self) -> Option<u64> {
+        self.is_set(PendingRoundParam::MinSponsorAmount)
+            .then_some(self.min_sponsor_amount)
+    }
+
+    pub fn get_max_sponsor_fraction(&self) -> Option<u64> {
+        self.is_set(PendingRoundParam::MaxSponsorFraction)
+            .then_some(self.max_sponsor_fraction)
+    }
+
+    pub fn get_number_of_winners(&self) -> Option<u32> {
+        self.is_set(PendingRoundParam::NumberOfWinners)
+            .then_some(self.number_of_winners)
+    }
+}
+
 #[assert_no_slop]
 #[account(zero_copy(unsafe))]
 #[derive(Default, Eq, PartialEq, Debug)]
@@ -94,18 +215,182 @@ pub struct Competition {
 
     pub status: CompetitionRoundStatus,
     pub competition_authority_bump: u8,
-
-    pub padding: [u8; 30],
+    // mint a non-fungible badge token to each winner in settle_winner
+    pub winner_badges_enabled: u8,
+
+    // status to restore when a paused competition resumes
+    pub status_before_pause: CompetitionRoundStatus,
+    // only registered keepers can crank when enabled
+    pub keeper_allowlist_enabled: u8,
+    // settle_competitor records ticket ranges to TicketIndexPage accounts when enabled
+    pub ticket_index_enabled: u8,
+    pub padding1: [u8; 2],
+    pub paused_ts: i64,
+
+    // proposed sponsor that must accept before sponsor_info.sponsor changes
+    pub pending_sponsor: Pubkey,
+
+    // roles (fall back to sponsor when unset)
+    pub operator: Pubkey,  // scheduling, pause/resume and reset_round_state
+    pub moderator: Pubkey, // competitor disqualification
+
+    // lamports rewarded to a registered keeper per settlement step
+    pub keeper_reward: u64,
+
+    // applied by reset_round so rules don't change mid-round
+    pub pending_round_params: PendingRoundParams,
+
+    // how the next round end is picked at reset_round
+    pub round_schedule: RoundSchedule,
+
+    // sampling winners without replacement
+    pub winning_score_settled: u128, // tickets held by this round's settled winners
+    pub winner_redraws: u32,         // draws redone this round (repeat winners and skip_winner)
+    pub padding2: [u8; 4],
+    // when the current winner_randomness became settleable (for skip_winner timeouts)
+    pub winner_draw_ts: i64,
+
+    // outstanding randomness request, keepers can re-trigger it once it times out
+    pub randomness_requested_ts: i64,
+    pub randomness_requested_slot: u64,
+    pub randomness_request_timeout: u32, // seconds (falls back to default when unset)
+    pub randomness_request_retries: u8,  // re-triggers of the outstanding request
+    pub max_randomness_request_retries: u8, // falls back to default when unset
+    pub padding3: [u8; 2],
+
+    // competitors still unsettled at the settlement deadline, zero-entry for the round
+    pub number_of_competitors_forfeited: u128,
+    // seconds after the round end before unsettled competitors are forfeited (0 = default)
+    pub settlement_grace_period: u32,
+    // seconds a drawn winner can go unsettled before skip_winner passes it over (0 = default)
+    pub winner_settlement_timeout: u32,
+
+    // where winner_randomness / prize_randomness come from
+    pub randomness_provider: RandomnessProvider,
+    // CommitReveal: reveals the sponsor let time out, commit-reveal is revoked after one
+    pub randomness_reveals_missed: u8,
+    pub padding5: [u8; 6],
+    // CommitReveal: sponsor's sha256(secret) for randomness_commitment_round_number
+    pub randomness_commitment: [u8; 32],
+    pub randomness_commitment_round_number: u64,
+    // CommitReveal: hash of the first slot after randomness_requested_slot, mixed with the secret
+    pub randomness_slot_hash: [u8; 32],
+    pub randomness_slot_hash_slot: u64, // slot randomness_slot_hash was pinned from
+
+    // audit trail for the round's draw, see WinnerDrawRecord
+    pub winner_randomness_delivered: u128, // raw winner value delivered to the program, ranged to [1, WINNER_MAX] on-chain
+    pub prize_randomness_delivered: u128, // raw prize value delivered to the program, ranged to [0, PRIZE_MAX] on-chain
+    pub winner_randomness_max: u128,      // WINNER_MAX requested (total_score_settled at request)
+    pub prize_draw: u128, // prize_randomness re-ranged to the prize buckets at resolution
+
+    // when the current round started (initialize_competition / reset_round)
+    pub round_start_ts: i64,
+
+    // listed on CompetitionRegistryPage registry_page_number, closing has to delist it
+    pub registry_page_number: u32,
+    pub registered: u8,
+    pub padding: [u8; 3],
 }
 
 impl Size for Competition {
-    const SIZE: usize = 456 + 8;
+    const SIZE: usize = 976 + 8;
 }
 
 const_assert_eq!(Competition::SIZE, std::mem::size_of::<Competition>() + 8);
 
+// competitions created before the fields after competition_authority_bump, which were laid over
+// the old trailing padding, see migrate_competition
+pub const LEGACY_COMPETITION_SIZE: usize = 456 + 8;
+const LEGACY_COMPETITION_PADDING_START: usize = 426 + 8;
+
+// zero-filled new fields read as unset, which only holds if the old padding was never written
+pub fn validate_competition_migration(data: &[u8]) -> CompetitionResult {
+    validate!(
+        data.len() == LEGACY_COMPETITION_SIZE,
+        ErrorCode::InvalidCompetitionMigration,
+        "competition account is {} bytes, expected {}",
+        data.len(),
+        LEGACY_COMPETITION_SIZE
+    )?;
+
+    validate!(
+        data[LEGACY_COMPETITION_PADDING_START..]
+            .iter()
+            .all(|byte| *byte == 0),
+        ErrorCode::InvalidCompetitionMigration,
+        "competition account padding is not zeroed"
+    )?;
+
+    Ok(())
+}
+
 impl Competition {
+    pub fn winner_badges_enabled(&self) -> bool {
+        self.winner_badges_enabled != 0
+    }
+
+    pub fn keeper_allowlist_enabled(&self) -> bool {
+        self.keeper_allowlist_enabled != 0
+    }
+
+    pub fn ticket_index_enabled(&self) -> bool {
+        self.ticket_index_enabled != 0
+    }
+
+    pub fn index_ticket_range(
+        &self,
+        ticket_index_page: Option<&mut TicketIndexPage>,
+        position: u128,
+        competitor_pubkey: Pubkey,
+        competitor: &Competitor,
+    ) -> CompetitionResult {
+        if !self.ticket_index_enabled() {
+            return Ok(());
+        }
+
+        let ticket_index_page = ticket_index_page.ok_or_else(|| {
+            msg!(
+                "ticket index page required for settlement position {}",
+                position
+            );
+            ErrorCode::TicketIndexPageMissing
+        })?;
+
+        ticket_index_page.record(
+            self.round_number,
+            position,
+            competitor_pubkey,
+            competitor.min_draw,
+            competitor.max_draw,
+        )
+    }
+
+    pub fn validate_keeper_is_allowed(&self, is_registered_keeper: bool) -> CompetitionResult {
+        validate!(
+            !self.keeper_allowlist_enabled() || is_registered_keeper,
+            ErrorCode::KeeperNotAllowed,
+            "keeper allowlist is enabled"
+        )?;
+
+        Ok(())
+    }
+
+    pub fn reward_keeper(
+        &self,
+        competition_keeper: Option<&mut CompetitionKeeper>,
+        now: i64,
+    ) -> CompetitionResult {
+        // unregistered keepers can still crank when the allowlist is disabled, but earn nothing
+        if let Some(competition_keeper) = competition_keeper {
+            competition_keeper.record_call(self.keeper_reward, now)?;
+        }
+
+        Ok(())
+    }
+
     pub fn update_status(&mut self, new_status: CompetitionRoundStatus) -> CompetitionResult {
+        self.validate_not_paused()?;
+
         if new_status != CompetitionRoundStatus::Expired {
             let status_delta = (new_status as i32 + 1) - ((self.status as i32 + 1) % 4);
             validate!(
@@ -135,6 +420,35 @@ impl Competition {
 
     // calculate current round_number N
     pub fn calculate_next_round_expiry_ts(&self, now: i64) -> CompetitionResult<i64> {
+        if now < self.next_round_expiry_ts {
+            return Ok(self.next_round_expiry_ts);
+        }
+
+        match self.round_schedule.mode {
+            RoundScheduleMode::Interval => self.calculate_next_interval_round_expiry_ts(now),
+            RoundScheduleMode::Explicit => {
+                match self.round_schedule.next_explicit_expiry_ts(now) {
+                    Some(next_round_expiry_ts) => Ok(next_round_expiry_ts),
+                    // keep rounds going if the calendar runs dry
+                    None => self.calculate_next_interval_round_expiry_ts(now),
+                }
+            }
+            RoundScheduleMode::Weekly => self.round_schedule.next_weekly_expiry_ts(now),
+        }
+    }
+
+    // competitions migrated mid-round have no recorded start, fall back to a full interval
+    pub fn get_round_start_ts(&self) -> CompetitionResult<i64> {
+        if self.round_start_ts != 0 {
+            return Ok(self.round_start_ts);
+        }
+
+        Ok(self
+            .next_round_expiry_ts
+            .safe_sub(self.round_duration.cast()?)?)
+    }
+
+    pub fn calculate_next_interval_round_expiry_ts(&self, now: i64) -> CompetitionResult<i64> {
         let next_round_expiry_ts = if now >= self.next_round_expiry_ts {
             self.next_round_expiry_ts.safe_add(
                 self.round_duration
@@ -160,9 +474,323 @@ impl Competition {
             return Err(ErrorCode::CompetitionRoundOngoing);
         }
 
+        // an in-flight round is abandoned: no further winners are drawn after expiry,
+        // but winners already settled keep their unclaimed winnings
+        self.roll_forward_prize_amount_settled()?;
+
+        self.number_of_winners_settled = 0;
+        self.total_score_settled = 0;
+        self.number_of_competitors_settled = 0;
+        self.number_of_competitors_forfeited = 0;
+        self.winner_randomness = 0;
+        self.prize_randomness = 0;
+        self.prize_randomness_max = 0;
+        self.prize_amount = 0;
+        self.prize_amount_settled = 0;
+        self.winning_score_settled = 0;
+        self.winner_redraws = 0;
+        self.winner_draw_ts = 0;
+        self.randomness_requested_ts = 0;
+        self.randomness_requested_slot = 0;
+        self.randomness_request_retries = 0;
+        self.randomness_slot_hash = [0; 32];
+        self.randomness_slot_hash_slot = 0;
+        self.winner_randomness_delivered = 0;
+        self.prize_randomness_delivered = 0;
+        self.winner_randomness_max = 0;
+        self.prize_draw = 0;
+
+        Ok(())
+    }
+
+    pub fn pause(&mut self, now: i64) -> CompetitionResult {
+        self.validate_not_paused()?;
+
+        validate!(
+            self.status != CompetitionRoundStatus::Expired,
+            ErrorCode::CompetitionExpired,
+            "Competition status = {:?}",
+            self.status
+        )?;
+
+        msg!(
+            "updating Competition status: {:?} -> {:?}",
+            self.status,
+            CompetitionRoundStatus::Paused
+        );
+
+        self.status_before_pause = self.status;
+        self.status = CompetitionRoundStatus::Paused;
+        self.paused_ts = now;
+
+        Ok(())
+    }
+
+    pub fn resume(&mut self, now: i64) -> CompetitionResult {
+        validate!(
+            self.status == CompetitionRoundStatus::Paused,
+            ErrorCode::InvalidStatusUpdateDetected,
+            "Competition status = {:?} (should be Paused)",
+            self.status
+        )?;
+
+        // only extend a round that was still running, an ended round keeps settling where it left off
+        if self.paused_ts < self.next_round_expiry_ts {
+            if self.round_schedule.mode == RoundScheduleMode::Interval {
+                let paused_duration = now.safe_sub(self.paused_ts)?.max(0);
+                self.next_round_expiry_ts = self.next_round_expiry_ts.safe_add(paused_duration)?;
+
+                msg!(
+                    "paused for {} seconds, next_round_expiry_ts -> {}",
+                    paused_duration,
+                    self.next_round_expiry_ts
+                );
+            } else {
+                // calendar rounds keep ending on the calendar, a pause past the round end runs
+                // the round to the next scheduled end instead
+                self.next_round_expiry_ts = self.calculate_next_round_expiry_ts(now)?;
+                self.round_schedule.prune(self.next_round_expiry_ts);
+
+                msg!(
+                    "resumed on the {:?} schedule, next_round_expiry_ts -> {}",
+                    self.round_schedule.mode,
+                    self.next_round_expiry_ts
+                );
+            }
+        }
+
+        msg!(
+            "updating Competition status: {:?} -> {:?}",
+            self.status,
+            self.status_before_pause
+        );
+
+        self.status = self.status_before_pause;
+        self.status_before_pause = CompetitionRoundStatus::Active;
+        self.paused_ts = 0;
+
+        Ok(())
+    }
+
+    pub fn get_operator(&self) -> Pubkey {
+        if self.operator == Pubkey::default() {
+            self.sponsor_info.sponsor
+        } else {
+            self.operator
+        }
+    }
+
+    pub fn get_randomness_request_timeout(&self) -> u32 {
+        if self.randomness_request_timeout == 0 {
+            DEFAULT_RANDOMNESS_REQUEST_TIMEOUT
+        } else {
+            self.randomness_request_timeout
+        }
+    }
+
+    pub fn get_max_randomness_request_retries(&self) -> u8 {
+        if self.max_randomness_request_retries == 0 {
+            DEFAULT_MAX_RANDOMNESS_REQUEST_RETRIES
+        } else {
+            self.max_randomness_request_retries
+        }
+    }
+
+    pub fn get_winner_settlement_timeout(&self) -> u32 {
+        if self.winner_settlement_timeout == 0 {
+            DEFAULT_WINNER_SETTLEMENT_TIMEOUT
+        } else {
+            self.winner_settlement_timeout
+        }
+    }
+
+    pub fn get_settlement_grace_period(&self) -> u32 {
+        if self.settlement_grace_period == 0 {
+            DEFAULT_SETTLEMENT_GRACE_PERIOD
+        } else {
+            self.settlement_grace_period
+        }
+    }
+
+    pub fn get_moderator(&self) -> Pubkey {
+        if self.moderator == Pubkey::default() {
+            self.sponsor_info.sponsor
+        } else {
+            self.moderator
+        }
+    }
+
+    pub fn propose_sponsor(&mut self, pending_sponsor: Pubkey) -> CompetitionResult {
+        validate!(
+            pending_sponsor != self.sponsor_info.sponsor,
+            ErrorCode::InvalidSponsorUpdate,
+            "{} is already the sponsor",
+            pending_sponsor
+        )?;
+
+        // proposing the default pubkey cancels a pending transfer
+        self.pending_sponsor = pending_sponsor;
+
+        Ok(())
+    }
+
+    pub fn accept_sponsor(&mut self, new_sponsor: Pubkey) -> CompetitionResult {
+        validate!(
+            self.pending_sponsor != Pubkey::default() && self.pending_sponsor == new_sponsor,
+            ErrorCode::InvalidSponsorUpdate,
+            "pending_sponsor = {} (signer = {})",
+            self.pending_sponsor,
+            new_sponsor
+        )?;
+
+        msg!(
+            "updating Competition sponsor: {} -> {}",
+            self.sponsor_info.sponsor,
+            new_sponsor
+        );
+
+        self.sponsor_info.sponsor = new_sponsor;
+        self.pending_sponsor = Pubkey::default();
+
+        Ok(())
+    }
+
+    pub fn validate_closable(&self) -> CompetitionResult {
+        validate!(
+            self.status == CompetitionRoundStatus::Expired,
+            ErrorCode::CompetitionNotClosable,
+            "Competition status = {:?} (should be Expired)",
+            self.status
+        )?;
+
+        validate!(
+            self.outstanding_unclaimed_winnings == 0,
+            ErrorCode::CompetitionNotClosable,
+            "outstanding_unclaimed_winnings = {} (should be 0)",
+            self.outstanding_unclaimed_winnings
+        )?;
+
+        validate!(
+            self.number_of_competitors == 0,
+            ErrorCode::CompetitionNotClosable,
+            "number_of_competitors = {} (should be 0)",
+            self.number_of_competitors
+        )?;
+
+        Ok(())
+    }
+
+    pub fn is_registered(&self) -> bool {
+        self.registered == 1
+    }
+
+    pub fn register(&mut self, registry_page_number: u32) -> CompetitionResult {
+        validate!(
+            !self.is_registered(),
+            ErrorCode::CompetitionRegistryUpdateInvalid,
+            "competition already registered on page {}",
+            self.registry_page_number
+        )?;
+
+        self.registered = 1;
+        self.registry_page_number = registry_page_number;
+
+        Ok(())
+    }
+
+    pub fn deregister(&mut self, registry_page_number: u32) -> CompetitionResult {
+        self.validate_registry_page(registry_page_number)?;
+
+        self.registered = 0;
+        self.registry_page_number = 0;
+
+        Ok(())
+    }
+
+    pub fn validate_registry_page(&self, registry_page_number: u32) -> CompetitionResult {
+        validate!(
+            self.is_registered() && self.registry_page_number == registry_page_number,
+            ErrorCode::CompetitionRegistryUpdateInvalid,
+            "competition registered = {} on page {} (not page {})",
+            self.is_registered(),
+            self.registry_page_number,
+            registry_page_number
+        )?;
+
+        Ok(())
+    }
+
+    pub fn validate_not_paused(&self) -> CompetitionResult {
+        validate!(
+            self.status != CompetitionRoundStatus::Paused,
+            ErrorCode::CompetitionPaused,
+            "Competition paused at unix_timestamp = {}",
+            self.paused_ts
+        )?;
+
+        Ok(())
+    }
+
+    pub fn is_round_in_progress(&self) -> bool {
+        self.status != CompetitionRoundStatus::Active || self.number_of_competitors_settled > 0
+    }
+
+    pub fn calculate_claim_window_end_ts(&self) -> CompetitionResult<i64> {
+        Ok(self
+            .competition_expiry_ts
+            .safe_add(EXPIRED_COMPETITION_CLAIM_WINDOW)?)
+    }
+
+    pub fn validate_winnings_claimable(&self, now: i64) -> CompetitionResult {
+        self.validate_not_paused()?;
+
+        if self.status == CompetitionRoundStatus::Expired {
+            let claim_window_end_ts = self.calculate_claim_window_end_ts()?;
+            validate!(
+                now < claim_window_end_ts,
+                ErrorCode::CompetitionClaimWindowElapsed,
+                "claim window ended at unix_timestamp = {}",
+                claim_window_end_ts
+            )?;
+
+            return Ok(());
+        }
+
+        // don't allow claiming during round resolution stages / before expiry is applied
+        validate!(
+            self.status == CompetitionRoundStatus::Active
+                || self.status == CompetitionRoundStatus::WinnerSettlementComplete,
+            ErrorCode::CompetitionRoundOngoing
+        )?;
+
+        validate!(!self.is_expired(now)?, ErrorCode::CompetitionExpired)?;
+
         Ok(())
     }
 
+    pub fn forfeit_unclaimed_winnings(&mut self, now: i64) -> CompetitionResult<u128> {
+        validate!(
+            self.status == CompetitionRoundStatus::Expired,
+            ErrorCode::CompetitionStatusNotActive,
+            "Competition status = {:?} (should be Expired)",
+            self.status
+        )?;
+
+        let claim_window_end_ts = self.calculate_claim_window_end_ts()?;
+        validate!(
+            now >= claim_window_end_ts,
+            ErrorCode::CompetitionRoundOngoing,
+            "claim window ends at unix_timestamp = {} (seconds remaining {})",
+            claim_window_end_ts,
+            claim_window_end_ts - now
+        )?;
+
+        let unclaimed_winnings_forfeited = self.outstanding_unclaimed_winnings;
+        self.outstanding_unclaimed_winnings = 0;
+
+        Ok(unclaimed_winnings_forfeited)
+    }
+
     pub fn validate_round_settlement_hasnt_started(&self, now: i64) -> CompetitionResult {
         self.validate_round_is_active(now)?;
 
@@ -177,6 +805,8 @@ impl Competition {
     }
 
     pub fn validate_round_is_active(&self, now: i64) -> CompetitionResult {
+        self.validate_not_paused()?;
+
         validate!(
             self.status == CompetitionRoundStatus::Active,
             ErrorCode::CompetitionStatusNotActive,
@@ -213,24 +843,66 @@ impl Competition {
         )?;
 
         validate!(
-            self.number_of_competitors_settled <= self.number_of_competitors,
+            self.calculate_number_of_competitors_resolved()? <= self.number_of_competitors,
             ErrorCode::CompetitionInvariantIssue,
-            "self.number_of_competitors_settled={} > self.number_of_competitors={}",
+            "self.number_of_competitors_settled={} + self.number_of_competitors_forfeited={} > self.number_of_competitors={}",
             self.number_of_competitors_settled,
+            self.number_of_competitors_forfeited,
             self.number_of_competitors
         )?;
 
         Ok(())
     }
 
+    // settled competitors plus those forfeited at the settlement deadline
+    pub fn calculate_number_of_competitors_resolved(&self) -> CompetitionResult<u128> {
+        Ok(self
+            .number_of_competitors_settled
+            .safe_add(self.number_of_competitors_forfeited)?)
+    }
+
+    pub fn calculate_settlement_deadline_ts(&self) -> CompetitionResult<i64> {
+        Ok(self
+            .next_round_expiry_ts
+            .safe_add(self.get_settlement_grace_period().cast()?)?)
+    }
+
+    // past the settlement deadline, competitors that still aren't settled are left out of
+    // the draw (zero-entry) and catch up to the next round on their next interaction
+    pub fn forfeit_unsettled_competitors(&mut self, now: i64) -> CompetitionResult<u128> {
+        if self.status != CompetitionRoundStatus::Active
+            || now < self.calculate_settlement_deadline_ts()?
+        {
+            return Ok(0);
+        }
+
+        let number_of_competitors_unsettled = self
+            .number_of_competitors
+            .safe_sub(self.calculate_number_of_competitors_resolved()?)?;
+
+        if number_of_competitors_unsettled > 0 {
+            msg!(
+                "settlement deadline passed, forfeiting {} unsettled competitors for round_number={}",
+                number_of_competitors_unsettled,
+                self.round_number
+            );
+            self.number_of_competitors_forfeited = self
+                .number_of_competitors_forfeited
+                .safe_add(number_of_competitors_unsettled)?;
+        }
+
+        Ok(number_of_competitors_unsettled)
+    }
+
     pub fn validate_round_settlement_complete(&self) -> CompetitionResult {
+        let number_of_competitors_resolved = self.calculate_number_of_competitors_resolved()?;
         validate!(
-            self.number_of_competitors == self.number_of_competitors_settled,
+            self.number_of_competitors == number_of_competitors_resolved,
             ErrorCode::InvalidRoundSettlementDetected,
             "{} competitiors not not settled ({} != {})",
-            self.number_of_competitors - self.number_of_competitors_settled,
+            self.number_of_competitors - number_of_competitors_resolved,
             self.number_of_competitors,
-            self.number_of_competitors_settled
+            number_of_competitors_resolved
         )?;
 
         validate!(
@@ -246,7 +918,7 @@ impl Competition {
     pub fn validate_round_resolved(&self) -> CompetitionResult {
         validate!(
             self.number_of_competitors > 0
-                && self.number_of_competitors == self.number_of_competitors_settled,
+                && self.number_of_competitors == self.calculate_number_of_competitors_resolved()?,
             ErrorCode::CompetitionRoundInSettlementPhase,
             "Competition round_number={:?} is still ongoing",
             self.round_number
@@ -275,7 +947,11 @@ impl Competition {
         validate!(
             self.winner_randomness > competitor.min_draw
                 && self.winner_randomness <= competitor.max_draw,
-            ErrorCode::CompetitorNotWinner
+            ErrorCode::CompetitorNotWinner,
+            "winner_randomness={} not in ({}, {}]",
+            self.winner_randomness,
+            competitor.min_draw,
+            competitor.max_draw
         )?;
 
         Ok(())
@@ -300,6 +976,8 @@ impl Competition {
 
         self.validate_round_ready_for_settlement(now)?;
 
+        competitor.catch_up_round(self)?;
+
         if !self.competitor_can_be_settled(competitor)? {
             return Ok(()); // gracefully skip/fail
         }
@@ -370,6 +1048,140 @@ impl Competition {
         Ok(())
     }
 
+    // shared by the settle instructions: settles the competitor, indexes the ticket range
+    // of a competitor that was counted and returns whether it was newly settled
+    pub fn settle_and_index_competitor(
+        &mut self,
+        competitor: &mut Competitor,
+        user_stats: &UserStats,
+        ticket_index_page: Option<&mut TicketIndexPage>,
+        now: i64,
+        competitor_pubkey: Pubkey,
+        competition_pubkey: Pubkey,
+    ) -> CompetitionResult<bool> {
+        let number_of_competitors_settled_before = self.number_of_competitors_settled;
+
+        self.settle_competitor(
+            competitor,
+            user_stats,
+            now,
+            competitor_pubkey,
+            competition_pubkey,
+        )?;
+
+        let settled = self.number_of_competitors_settled > number_of_competitors_settled_before;
+        if settled {
+            self.index_ticket_range(
+                ticket_index_page,
+                number_of_competitors_settled_before,
+                competitor_pubkey,
+                competitor,
+            )?;
+        }
+
+        if self.number_of_competitors == self.calculate_number_of_competitors_resolved()? {
+            validate!(
+                self.total_score_settled != 0,
+                ErrorCode::InvalidRoundSettlementDetected,
+                "total_score_settled is 0 after settling all competitors, round cannot end until competitors have one entry"
+            )?;
+        }
+
+        Ok(settled)
+    }
+
+    // keeper batches of (competitor, competitor_pubkey, drift_user_stats, drift_user_stats_pubkey)
+    // are checked like the SettleCompetitor constraints and already settled competitors are
+    // skipped. stops once the round is resolved or at the end of the given ticket_index_page,
+    // returns the number of competitors newly settled
+    pub fn settle_competitors_batch(
+        &mut self,
+        competitors: &mut [(&mut Competitor, Pubkey, &UserStats, Pubkey)],
+        mut ticket_index_page: Option<&mut TicketIndexPage>,
+        now: i64,
+        competition_pubkey: Pubkey,
+    ) -> CompetitionResult<u32> {
+        let mut number_of_competitors_settled = 0_u32;
+
+        for (competitor, competitor_pubkey, user_stats, user_stats_pubkey) in competitors.iter_mut()
+        {
+            // nothing left to settle (e.g. another keeper raced this batch)
+            if self.number_of_competitors == self.calculate_number_of_competitors_resolved()? {
+                break;
+            }
+
+            if let Some(ticket_index_page) = &ticket_index_page {
+                if self.ticket_index_enabled()
+                    && TicketIndexPage::get_page_number(self.number_of_competitors_settled)?
+                        != ticket_index_page.page_number
+                {
+                    break;
+                }
+            }
+
+            validate!(
+                competitor.competition == competition_pubkey,
+                ErrorCode::InvalidBatchSettlementAccounts,
+                "competitor {} is not for competition {}",
+                competitor_pubkey,
+                competition_pubkey
+            )?;
+
+            validate!(
+                competitor.user_stats == *user_stats_pubkey,
+                ErrorCode::InvalidBatchSettlementAccounts,
+                "drift_user_stats {} is not for competitor {}",
+                user_stats_pubkey,
+                competitor_pubkey
+            )?;
+
+            if self.settle_and_index_competitor(
+                competitor,
+                user_stats,
+                ticket_index_page.as_deref_mut(),
+                now,
+                *competitor_pubkey,
+                competition_pubkey,
+            )? {
+                number_of_competitors_settled = number_of_competitors_settled.safe_add(1)?;
+            }
+        }
+
+        Ok(number_of_competitors_settled)
+    }
+
+    pub fn self_settle_competitor(
+        &mut self,
+        competitor: &mut Competitor,
+        user_stats: &UserStats,
+        ticket_index_page: Option<&mut TicketIndexPage>,
+        now: i64,
+        competitor_pubkey: Pubkey,
+        competition_pubkey: Pubkey,
+    ) -> CompetitionResult {
+        let settled = self.settle_and_index_competitor(
+            competitor,
+            user_stats,
+            ticket_index_page,
+            now,
+            competitor_pubkey,
+            competition_pubkey,
+        )?;
+
+        // unlike keepers, the competitor wants to know they were counted
+        validate!(
+            settled,
+            ErrorCode::CompetitorNotSettleable,
+            "competitor {} can't be settled for round_number={} (competitor round_number={}, status={:?})",
+            competitor_pubkey,
+            self.round_number,
+            competitor.competition_round_number,
+            competitor.status
+        )?;
+
+        Ok(())
+    }
+
     pub fn calculate_sponsor_max_prize(
         &self,
         spot_market: &SpotMarket,
@@ -440,12 +1252,338 @@ impl Competition {
         vault_balance: u64,
     ) -> CompetitionResult {
         self.validate_round_resolved()?;
+
+        // winners are drawn from [1, total_score_settled], whichever provider draws them
+        validate!(
+            self.total_score_settled >= 1,
+            ErrorCode::InvalidRandomnessRequestParams,
+            "total_score_settled = {} (no tickets to draw from)",
+            self.total_score_settled
+        )?;
+
         let (_, ratios) = self.calculate_prize_buckets_and_ratios(spot_market, vault_balance)?;
 
         let ratio_sum = ratios.iter().sum();
         self.prize_randomness_max = ratio_sum;
+        self.winner_randomness_max = self.total_score_settled;
 
         self.update_status(CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested)?;
+        self.randomness_request_retries = 0;
+
+        Ok(())
+    }
+
+    pub fn validate_randomness_provider(
+        &self,
+        randomness_provider: RandomnessProvider,
+    ) -> CompetitionResult {
+        validate!(
+            self.randomness_provider == randomness_provider,
+            ErrorCode::InvalidRandomnessProvider,
+            "randomness_provider = {:?} (should be {:?})",
+            self.randomness_provider,
+            randomness_provider
+        )?;
+
+        Ok(())
+    }
+
+    pub fn update_randomness_provider(
+        &mut self,
+        randomness_provider: RandomnessProvider,
+    ) -> CompetitionResult {
+        validate!(
+            randomness_provider != RandomnessProvider::CommitReveal
+                || self.randomness_reveals_missed == 0,
+            ErrorCode::InvalidRandomnessProvider,
+            "commit-reveal revoked after {} missed reveal(s)",
+            self.randomness_reveals_missed
+        )?;
+
+        self.randomness_provider = randomness_provider;
+
+        Ok(())
+    }
+
+    pub fn commit_randomness(&mut self, commitment: [u8; 32], now: i64) -> CompetitionResult {
+        self.validate_randomness_provider(RandomnessProvider::CommitReveal)?;
+        self.validate_round_is_active(now)?;
+
+        // the secret is fixed before anyone knows who settles with how many tickets
+        validate!(
+            now < self.next_round_expiry_ts,
+            ErrorCode::InvalidRandomnessCommitment,
+            "commitments for round_number={} closed at unix_timestamp={}",
+            self.round_number,
+            self.next_round_expiry_ts
+        )?;
+
+        validate!(
+            commitment != [0; 32],
+            ErrorCode::InvalidRandomnessCommitment,
+            "commitment is empty"
+        )?;
+
+        self.randomness_commitment = commitment;
+        self.randomness_commitment_round_number = self.round_number;
+
+        Ok(())
+    }
+
+    pub fn validate_randomness_commitment(&self) -> CompetitionResult {
+        validate!(
+            self.randomness_commitment != [0; 32]
+                && self.randomness_commitment_round_number == self.round_number,
+            ErrorCode::InvalidRandomnessCommitment,
+            "no commitment for round_number={} (last commitment for round_number={})",
+            self.round_number,
+            self.randomness_commitment_round_number
+        )?;
+
+        Ok(())
+    }
+
+    pub fn validate_randomness_reveal(&self, secret: &[u8; 32]) -> CompetitionResult {
+        self.validate_randomness_provider(RandomnessProvider::CommitReveal)?;
+        self.validate_randomness_commitment()?;
+
+        validate!(
+            self.status == CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested,
+            ErrorCode::InvalidStatusUpdateDetected,
+            "no outstanding randomness request, status = {:?}",
+            self.status
+        )?;
+
+        validate!(
+            hashv(&[&secret[..]]).to_bytes() == self.randomness_commitment,
+            ErrorCode::InvalidRandomnessReveal,
+            "secret doesn't match commitment for round_number={}",
+            self.round_number
+        )?;
+
+        Ok(())
+    }
+
+    pub fn calculate_randomness_reveal_deadline_ts(&self) -> CompetitionResult<i64> {
+        Ok(self
+            .randomness_requested_ts
+            .safe_add(self.get_randomness_request_timeout().cast()?)?)
+    }
+
+    pub fn calculate_randomness_slot_hash_pin_deadline_slot(&self) -> CompetitionResult<u64> {
+        Ok(self
+            .randomness_requested_slot
+            .safe_add(RANDOMNESS_SLOT_HASH_PIN_WINDOW)?)
+    }
+
+    // an unpinned reveal can't be derived once the window is over, whatever the sponsor does
+    pub fn is_randomness_slot_hash_pin_missed(&self, slot: u64) -> CompetitionResult<bool> {
+        Ok(!self.is_randomness_slot_hash_pinned()
+            && slot > self.calculate_randomness_slot_hash_pin_deadline_slot()?)
+    }
+
+    fn validate_randomness_request_outstanding(&self) -> CompetitionResult {
+        self.validate_not_paused()?;
+        self.validate_randomness_provider(RandomnessProvider::CommitReveal)?;
+
+        validate!(
+            self.status == CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested,
+            ErrorCode::InvalidStatusUpdateDetected,
+            "no outstanding randomness request, status = {:?}",
+            self.status
+        )?;
+
+        Ok(())
+    }
+
+    // the sponsor only answers for the reveal once the slot hash is pinned, a reveal missed
+    // before that is a missed pin (see resolve_missed_randomness_slot_hash_pin)
+    pub fn validate_randomness_reveal_missed(&self, now: i64) -> CompetitionResult {
+        self.validate_randomness_request_outstanding()?;
+
+        validate!(
+            self.is_randomness_slot_hash_pinned(),
+            ErrorCode::RandomnessRevealNotTimedOut,
+            "slot hash after slot {} not pinned yet",
+            self.randomness_requested_slot
+        )?;
+
+        let reveal_deadline_ts = self.calculate_randomness_reveal_deadline_ts()?;
+        validate!(
+            now >= reveal_deadline_ts,
+            ErrorCode::RandomnessRevealNotTimedOut,
+            "secret can still be revealed until ts={} (now={})",
+            reveal_deadline_ts,
+            now
+        )?;
+
+        Ok(())
+    }
+
+    pub fn is_randomness_slot_hash_pinned(&self) -> bool {
+        self.randomness_slot_hash != [0; 32]
+    }
+
+    // the hash of the first slot after the request wasn't known to the sponsor at commit time
+    // or to the keeper at request time. pinned by the first call once that slot lands and
+    // before the request slot can leave the sysvar, so the reveal doesn't depend on it
+    pub fn pin_randomness_slot_hash(
+        &mut self,
+        slot: u64,
+        slot_hash: [u8; 32],
+        current_slot: u64,
+    ) -> CompetitionResult {
+        self.validate_randomness_provider(RandomnessProvider::CommitReveal)?;
+
+        validate!(
+            self.status == CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested,
+            ErrorCode::InvalidStatusUpdateDetected,
+            "no outstanding randomness request, status = {:?}",
+            self.status
+        )?;
+
+        validate!(
+            !self.is_randomness_slot_hash_pinned(),
+            ErrorCode::SlotHashNotFound,
+            "slot hash of slot {} already pinned",
+            self.randomness_slot_hash_slot
+        )?;
+
+        validate!(
+            slot > self.randomness_requested_slot && slot_hash != [0; 32],
+            ErrorCode::SlotHashNotFound,
+            "slot {} is not after the request slot {}",
+            slot,
+            self.randomness_requested_slot
+        )?;
+
+        let pin_deadline_slot = self.calculate_randomness_slot_hash_pin_deadline_slot()?;
+        validate!(
+            current_slot <= pin_deadline_slot,
+            ErrorCode::SlotHashNotFound,
+            "slot hash pin window closed at slot {} (current slot {})",
+            pin_deadline_slot,
+            current_slot
+        )?;
+
+        self.randomness_slot_hash = slot_hash;
+        self.randomness_slot_hash_slot = slot;
+
+        Ok(())
+    }
+
+    // withholding an unfavourable secret is the sponsor's only lever, so a missed reveal doesn't
+    // draw from anything the sponsor or the caller could grind: the round is re-requested from
+    // switchboard (request_randomness takes over the outstanding request) and commit-reveal is
+    // revoked for good. sponsors don't post a bond to slash, so losing the provider is the
+    // penalty and a sponsor gets at most one withheld reveal per competition
+    pub fn penalize_missed_randomness_reveal(&mut self, now: i64) -> CompetitionResult {
+        self.validate_randomness_reveal_missed(now)?;
+
+        self.randomness_reveals_missed = self.randomness_reveals_missed.safe_add(1)?;
+        self.randomness_provider = RandomnessProvider::Switchboard;
+        self.randomness_request_retries = 0;
+
+        Ok(())
+    }
+
+    // nobody pinned the slot hash in time, so the secret can't be revealed anymore. that isn't
+    // on the sponsor: the round is re-requested from switchboard (which the sponsor can't grind
+    // either) but commit-reveal isn't revoked, the operator can switch back for the next round
+    pub fn resolve_missed_randomness_slot_hash_pin(&mut self, slot: u64) -> CompetitionResult {
+        self.validate_randomness_request_outstanding()?;
+
+        validate!(
+            self.is_randomness_slot_hash_pin_missed(slot)?,
+            ErrorCode::RandomnessRevealNotTimedOut,
+            "slot hash can still be pinned until slot {} (current slot {})",
+            self.calculate_randomness_slot_hash_pin_deadline_slot()?,
+            slot
+        )?;
+
+        self.randomness_provider = RandomnessProvider::Switchboard;
+        self.randomness_request_retries = 0;
+
+        Ok(())
+    }
+
+    // on-chain providers: sha256("randomness" || round_number || seeds..), first 16 bytes
+    // (little endian) to (0, total_score_settled], last 16 bytes to [0, prize_randomness_max]
+    // returns the hash alongside the ranged values so the pre-range bytes can be recorded
+    pub fn derive_randomness(&self, seeds: &[&[u8]]) -> CompetitionResult<([u8; 32], u128, u128)> {
+        validate!(
+            self.total_score_settled > 0,
+            ErrorCode::CompetitionWinnerNotDetermined,
+            "total_score_settled is 0"
+        )?;
+
+        let round_number_bytes = self.round_number.to_le_bytes();
+        let mut hash_input: Vec<&[u8]> = vec![&b"randomness"[..], &round_number_bytes[..]];
+        hash_input.extend_from_slice(seeds);
+        let hash_bytes = hashv(&hash_input).to_bytes();
+
+        let mut winner_bytes = [0_u8; 16];
+        winner_bytes.copy_from_slice(&hash_bytes[..16]);
+        let mut prize_bytes = [0_u8; 16];
+        prize_bytes.copy_from_slice(&hash_bytes[16..]);
+
+        let winner_randomness =
+            (u128::from_le_bytes(winner_bytes) % self.total_score_settled).safe_add(1)?;
+        let prize_randomness =
+            u128::from_le_bytes(prize_bytes) % self.prize_randomness_max.safe_add(1)?;
+
+        Ok((hash_bytes, winner_randomness, prize_randomness))
+    }
+
+    // switchboard delivers the raw values the function accepted for the requested bounds
+    pub fn range_switchboard_randomness(
+        &self,
+        winner_randomness_raw: u128,
+        prize_randomness_raw: u128,
+    ) -> CompetitionResult<(u128, u128)> {
+        let winner_randomness =
+            range_unbiased_randomness(winner_randomness_raw, 1, self.winner_randomness_max)?;
+        let prize_randomness =
+            range_unbiased_randomness(prize_randomness_raw, 0, self.prize_randomness_max)?;
+
+        Ok((winner_randomness, prize_randomness))
+    }
+
+    pub fn record_randomness_request(&mut self, now: i64, slot: u64) {
+        self.randomness_requested_ts = now;
+        self.randomness_requested_slot = slot;
+    }
+
+    // re-trigger an outstanding request the oracle never answered
+    pub fn retry_randomness_request(&mut self, now: i64) -> CompetitionResult {
+        self.validate_not_paused()?;
+
+        validate!(
+            self.status == CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested,
+            ErrorCode::InvalidStatusUpdateDetected,
+            "no outstanding randomness request, status = {:?}",
+            self.status
+        )?;
+
+        let retriable_ts = self
+            .randomness_requested_ts
+            .safe_add(self.get_randomness_request_timeout().cast()?)?;
+        validate!(
+            now >= retriable_ts,
+            ErrorCode::RandomnessRequestNotTimedOut,
+            "randomness request can be retried at ts={} (now={})",
+            retriable_ts,
+            now
+        )?;
+
+        validate!(
+            self.randomness_request_retries < self.get_max_randomness_request_retries(),
+            ErrorCode::RandomnessRequestRetriesExhausted,
+            "randomness request already retried {} times",
+            self.randomness_request_retries
+        )?;
+
+        self.randomness_request_retries = self.randomness_request_retries.safe_add(1)?;
 
         Ok(())
     }
@@ -479,14 +1617,7 @@ impl Competition {
             self.prize_randomness_max
         );
 
-        // prize amounts changed since random draw request
-        let draw = if ratio_sum < self.prize_randomness_max {
-            let ranged_draw = self.prize_randomness % ratio_sum;
-            msg!("prize_randomness range updated: {}", ranged_draw);
-            ranged_draw
-        } else {
-            self.prize_randomness
-        };
+        let draw = self.calculate_prize_draw(ratio_sum);
 
         let mut cumulative_ratio = 0;
         for (i, &prize_amount_i) in prize_buckets.iter().enumerate() {
@@ -505,44 +1636,141 @@ impl Competition {
         Err(ErrorCode::CompetitionWinnerNotDetermined)
     }
 
+    // prize amounts changed since random draw request: the draw is re-ranged to the
+    // current buckets, resolve_prize_amount records the result as prize_draw
+    pub fn calculate_prize_draw(&self, ratio_sum: u128) -> u128 {
+        if ratio_sum < self.prize_randomness_max {
+            let ranged_draw = self.prize_randomness % ratio_sum;
+            msg!(
+                "prize_randomness range updated: {} % {} = {}",
+                self.prize_randomness,
+                ratio_sum,
+                ranged_draw
+            );
+            ranged_draw
+        } else {
+            self.prize_randomness
+        }
+    }
+
     pub fn resolve_prize_amount(
         &mut self,
         spot_market: &SpotMarket,
         vault_balance: u64,
     ) -> CompetitionResult {
         let (prize_amount, _, _) = self.calculate_prize_amount(spot_market, vault_balance)?;
+        let (_, ratios) = self.calculate_prize_buckets_and_ratios(spot_market, vault_balance)?;
+        self.prize_draw = self.calculate_prize_draw(ratios.iter().sum());
         self.prize_amount = prize_amount;
         self.prize_base = spot_market.insurance_fund.shares_base;
 
         Ok(())
     }
 
-    pub fn calculate_next_winner_randomness(&mut self) -> CompetitionResult<u128> {
-        let winner_randomness_offset: u128 = self
-            .total_score_settled
-            .safe_div(self.number_of_winners.cast()?)?
-            .safe_add(1)?
-            .safe_mul(
-                self.prize_randomness
-                    .safe_mul(self.number_of_winners_settled.cast()?)?,
-            )?
-            .saturating_add(17)
-            ^ (self.prize_randomness)
-            ^ (self.winner_randomness) << 3; // Bitwise XOR and left shift for added unpredictability
+    pub fn calculate_remaining_score(&self) -> CompetitionResult<u128> {
+        self.total_score_settled
+            .safe_sub(self.winning_score_settled)
+    }
+
+    // the round's first draw is the delivered winner randomness. every later draw (next
+    // placement, repeat winner or skip_winner) is derived from it, never from the previous draw:
+    // sha256("winner_draw" || winner_randomness_delivered || round_number
+    //     || number_of_winners_settled || winner_redraws), all little endian, first 16 bytes
+    //     as a little endian u128, mapped to (0, total_score_settled]
+    // a draw that lands on a ticket already won this round is redone by settle_winner, so
+    // winners are sampled without replacement without loading the earlier winners
+    pub fn calculate_winner_draw(&self) -> CompetitionResult<u128> {
+        let remaining_score = self.calculate_remaining_score()?;
+        validate!(
+            remaining_score > 0,
+            ErrorCode::CompetitionWinnerNotDetermined,
+            "no tickets left to draw from ({} / {} won)",
+            self.winning_score_settled,
+            self.total_score_settled
+        )?;
+
+        let hash = hashv(&[
+            b"winner_draw",
+            &self.winner_randomness_delivered.to_le_bytes(),
+            &self.round_number.to_le_bytes(),
+            &self.number_of_winners_settled.to_le_bytes(),
+            &self.winner_redraws.to_le_bytes(),
+        ]);
+
+        let mut hash_bytes = [0_u8; 16];
+        hash_bytes.copy_from_slice(&hash.to_bytes()[..16]);
 
-        let next_winner_randomness = self
-            .winner_randomness
-            .saturating_add(winner_randomness_offset)
-            % (self.total_score_settled.saturating_add(1));
+        // modulo bias is negligible as total_score_settled is far below 2^128
+        let winner_draw =
+            (u128::from_le_bytes(hash_bytes) % self.total_score_settled).safe_add(1)?;
 
         msg!(
-            "winner_randomness: {} -> {} (offset={})",
-            self.winner_randomness,
-            next_winner_randomness,
-            winner_randomness_offset
+            "winner_randomness: {} (placement={}, redraws={}, remaining_score={})",
+            winner_draw,
+            self.number_of_winners_settled,
+            self.winner_redraws,
+            remaining_score
         );
 
-        Ok(next_winner_randomness.max(1))
+        Ok(winner_draw)
+    }
+
+    // everything needed to recompute winner_randomness, emitted on every draw
+    pub fn get_winner_draw_record(&self, competition_key: Pubkey, now: i64) -> WinnerDrawRecord {
+        WinnerDrawRecord {
+            competition: competition_key,
+            round_number: self.round_number,
+            winner_randomness_delivered: self.winner_randomness_delivered,
+            prize_randomness_delivered: self.prize_randomness_delivered,
+            winner_randomness_max: self.winner_randomness_max,
+            prize_randomness_max: self.prize_randomness_max,
+            prize_randomness: self.prize_randomness,
+            prize_draw: self.prize_draw,
+            total_score_settled: self.total_score_settled,
+            winner_placement: self.number_of_winners_settled,
+            winner_redraws: self.winner_redraws,
+            winning_score_settled: self.winning_score_settled,
+            winner_randomness: self.winner_randomness,
+            ts: now,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_round_winner_record(
+        &self,
+        competitor: &Competitor,
+        competitor_key: Pubkey,
+        competition_key: Pubkey,
+        prize_amount: u128,
+        prize_value: u64,
+        winner_badge_mint: Option<Pubkey>,
+        now: i64,
+    ) -> CompetitionRoundWinnerRecord {
+        CompetitionRoundWinnerRecord {
+            round_number: self.round_number,
+            competition: competition_key,
+            competitor: competitor_key,
+            competitor_authority: competitor.authority,
+            min_draw: competitor.min_draw,
+            max_draw: competitor.max_draw,
+            total_score_settled: self.total_score_settled,
+            number_of_competitors_settled: self.number_of_competitors_settled,
+
+            prize_amount,
+            prize_base: self.prize_base,
+            prize_value,
+
+            winner_placement: self.number_of_winners_settled,
+            number_of_winners: self.number_of_winners,
+
+            winner_randomness: self.winner_randomness,
+            prize_randomness: self.prize_randomness,
+            prize_randomness_max: self.prize_randomness_max,
+
+            ts: now,
+
+            winner_badge_mint: winner_badge_mint.unwrap_or_default(),
+        }
     }
 
     pub fn calculate_next_winner_prize_amount(&mut self) -> CompetitionResult<u128> {
@@ -585,6 +1813,7 @@ impl Competition {
         Ok(winner_prize_amount.min(prize_amount_remaining))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn settle_winner(
         &mut self,
         competitor: &mut Competitor,
@@ -593,7 +1822,10 @@ impl Competition {
         now: i64,
         competitor_key: Pubkey,
         competition_key: Pubkey,
+        winner_badge_mint: Option<Pubkey>,
     ) -> CompetitionResult {
+        self.validate_not_paused()?;
+
         if self.number_of_winners == self.number_of_winners_settled {
             self.update_status(CompetitionRoundStatus::WinnerSettlementComplete)?;
             return Ok(());
@@ -601,6 +1833,18 @@ impl Competition {
 
         self.validate_competitor_is_winner(competitor)?;
 
+        // sampling without replacement: a repeat winner isn't paid, the draw is redone instead
+        if competitor.has_won_round(self.round_number)? {
+            msg!(
+                "competitor {} already won round_number={}, redrawing",
+                competitor_key,
+                self.round_number
+            );
+            self.redraw_winner(now)?;
+            emit!(self.get_winner_draw_record(competition_key, now));
+            return Ok(());
+        }
+
         if competitor.unclaimed_winnings != 0 {
             apply_rebase_to_competitor_unclaimed_winnings(competitor, spot_market)?;
         }
@@ -617,29 +1861,15 @@ impl Competition {
             insurance_fund_vault_balance,
         )?;
 
-        emit!(CompetitionRoundWinnerRecord {
-            round_number: self.round_number,
-            competition: competition_key,
-            competitor: competitor_key,
-            competitor_authority: competitor.authority,
-            min_draw: competitor.min_draw,
-            max_draw: competitor.max_draw,
-            total_score_settled: self.total_score_settled,
-            number_of_competitors_settled: self.number_of_competitors_settled,
-
-            prize_amount: winner_prize_amount,
-            prize_base: self.prize_base,
-            prize_value: winner_prize_value,
-
-            winner_placement: self.number_of_winners_settled,
-            number_of_winners: self.number_of_winners,
-
-            winner_randomness: self.winner_randomness,
-            prize_randomness: self.prize_randomness,
-            prize_randomness_max: self.prize_randomness_max,
-
-            ts: now,
-        });
+        emit!(self.get_round_winner_record(
+            competitor,
+            competitor_key,
+            competition_key,
+            winner_prize_amount,
+            winner_prize_value,
+            winner_badge_mint,
+            now
+        ));
 
         competitor.unclaimed_winnings = competitor
             .unclaimed_winnings
@@ -657,6 +1887,23 @@ impl Competition {
         self.prize_amount_settled = self.prize_amount_settled.safe_add(winner_prize_amount)?;
         self.number_of_winners_settled = self.number_of_winners_settled.safe_add(1)?;
 
+        competitor.last_round_won = self.round_number.safe_add(1)?;
+        self.winning_score_settled = self
+            .winning_score_settled
+            .safe_add(competitor.max_draw.safe_sub(competitor.min_draw)?)?;
+
+        // every ticket holder has already won, remaining placements go unawarded
+        if self.number_of_winners_settled < self.number_of_winners
+            && self.calculate_remaining_score()? == 0
+        {
+            msg!(
+                "all tickets won after {} / {} winners, skipping remaining placements",
+                self.number_of_winners_settled,
+                self.number_of_winners
+            );
+            self.number_of_winners_settled = self.number_of_winners;
+        }
+
         validate!(
             self.prize_amount_settled <= self.prize_amount
                 && self.number_of_winners_settled <= self.number_of_winners,
@@ -671,27 +1918,171 @@ impl Competition {
         if self.number_of_winners == self.number_of_winners_settled {
             self.update_status(CompetitionRoundStatus::WinnerSettlementComplete)?;
         } else {
-            self.winner_randomness = self.calculate_next_winner_randomness()?; // update randomness for next winner to settle
+            self.winner_randomness = self.calculate_winner_draw()?; // update randomness for next winner to settle
+            self.winner_draw_ts = now;
+            emit!(self.get_winner_draw_record(competition_key, now));
+        }
+
+        Ok(())
+    }
+
+    fn redraw_winner(&mut self, now: i64) -> CompetitionResult {
+        self.winner_redraws = self.winner_redraws.safe_add(1)?;
+        self.winner_randomness = self.calculate_winner_draw()?;
+        self.winner_draw_ts = now;
+
+        Ok(())
+    }
+
+    // pass over a drawn winner nobody settled within winner_settlement_timeout. a timeout is
+    // the only reason: competitors can't be disqualified or closed once settlement starts, so
+    // every drawn ticket belongs to a competitor settle_winner accepts
+    pub fn skip_winner(&mut self, now: i64) -> CompetitionResult {
+        self.validate_not_paused()?;
+
+        validate!(
+            self.number_of_winners_settled < self.number_of_winners,
+            ErrorCode::WinnerNotSkippable,
+            "all {} winners settled",
+            self.number_of_winners
+        )?;
+
+        validate!(
+            self.status == CompetitionRoundStatus::WinnerAndPrizeRandomnessComplete
+                && self.winner_randomness != 0,
+            ErrorCode::CompetitionWinnerNotDetermined,
+            "CompetitionWinnerNotDetermined, winner randomness = {}",
+            self.winner_randomness
+        )?;
+
+        let skippable_ts = self
+            .winner_draw_ts
+            .safe_add(self.get_winner_settlement_timeout().cast()?)?;
+        validate!(
+            now >= skippable_ts,
+            ErrorCode::WinnerNotSkippable,
+            "drawn winner can be skipped at ts={} (now={})",
+            skippable_ts,
+            now
+        )?;
+
+        msg!(
+            "skipping winner_randomness={} for placement {} after timeout",
+            self.winner_randomness,
+            self.number_of_winners_settled
+        );
+        self.redraw_winner(now)?;
+
+        Ok(())
+    }
+
+    pub fn apply_pending_round_params(&mut self) -> CompetitionResult {
+        let pending_round_params = self.pending_round_params;
+
+        if pending_round_params.is_empty() {
+            return Ok(());
+        }
+
+        msg!(
+            "applying pending round params for round_number={}: {:?}",
+            self.round_number,
+            pending_round_params
+        );
+
+        if let Some(max_entries_per_competitor) =
+            pending_round_params.get_max_entries_per_competitor()
+        {
+            self.max_entries_per_competitor = max_entries_per_competitor;
         }
 
+        if let Some(round_duration) = pending_round_params.get_round_duration() {
+            self.round_duration = round_duration;
+        }
+
+        if let Some(min_sponsor_amount) = pending_round_params.get_min_sponsor_amount() {
+            self.sponsor_info.min_sponsor_amount = min_sponsor_amount;
+        }
+
+        if let Some(max_sponsor_fraction) = pending_round_params.get_max_sponsor_fraction() {
+            self.sponsor_info.max_sponsor_fraction = max_sponsor_fraction;
+        }
+
+        if let Some(number_of_winners) = pending_round_params.get_number_of_winners() {
+            self.number_of_winners = number_of_winners;
+        }
+
+        self.pending_round_params = PendingRoundParams::default();
+
+        Ok(())
+    }
+
+    // prizes already handed out stay reserved for winners, so the sponsor's
+    // withdrawable floor grows by the amount settled this round
+    pub fn roll_forward_prize_amount_settled(&mut self) -> CompetitionResult {
+        self.sponsor_info.min_sponsor_amount = self
+            .sponsor_info
+            .min_sponsor_amount
+            .saturating_add(self.prize_amount_settled.cast()?);
+
         Ok(())
     }
 
     pub fn reset_round(&mut self, now: i64) -> CompetitionResult {
         self.validate_round_settlement_complete()?;
+        self.start_next_round(now)?;
+        self.update_status(CompetitionRoundStatus::Active)?;
+
+        Ok(())
+    }
+
+    // a round that ends without competitors (or where every competitor was forfeited)
+    // has nothing to draw from, it rolls over without a winner and keeps the prize pool
+    pub fn roll_over_round_without_winner(&mut self, now: i64) -> CompetitionResult<bool> {
+        if self.status != CompetitionRoundStatus::Active
+            || now < self.next_round_expiry_ts
+            || self.total_score_settled > 0
+            || self.calculate_number_of_competitors_resolved()? < self.number_of_competitors
+        {
+            return Ok(false);
+        }
+
+        msg!(
+            "round_number={} ended with nothing to draw from ({} competitors forfeited), rolling over without a winner",
+            self.round_number,
+            self.number_of_competitors_forfeited
+        );
+        self.start_next_round(now)?;
+
+        Ok(true)
+    }
 
+    fn start_next_round(&mut self, now: i64) -> CompetitionResult {
         // necessary
         self.number_of_winners_settled = 0;
         self.total_score_settled = 0;
         self.number_of_competitors_settled = 0;
+        self.number_of_competitors_forfeited = 0;
+        self.winning_score_settled = 0;
+        self.winner_redraws = 0;
+        self.winner_draw_ts = 0;
+        self.randomness_requested_ts = 0;
+        self.randomness_requested_slot = 0;
+        self.randomness_request_retries = 0;
+        self.randomness_slot_hash = [0; 32];
+        self.randomness_slot_hash_slot = 0;
+        self.winner_randomness_delivered = 0;
+        self.prize_randomness_delivered = 0;
+        self.winner_randomness_max = 0;
+        self.prize_draw = 0;
         self.round_number = self.round_number.safe_add(1)?;
-        self.next_round_expiry_ts = self.calculate_next_round_expiry_ts(now)?;
+        self.round_start_ts = now;
 
-        // update min sponsor amount based on amount given
-        self.sponsor_info.min_sponsor_amount = self
-            .sponsor_info
-            .min_sponsor_amount
-            .saturating_add(self.prize_amount_settled.cast()?);
+        // scheduled changes take effect for the new round (incl. its duration), a
+        // scheduled min_sponsor_amount is the new floor before this round's prizes
+        self.apply_pending_round_params()?;
+        self.roll_forward_prize_amount_settled()?;
+        self.next_round_expiry_ts = self.calculate_next_round_expiry_ts(now)?;
+        self.round_schedule.prune(self.next_round_expiry_ts);
 
         // 'nice to clear'
         self.winner_randomness = 0;
@@ -700,8 +2091,6 @@ impl Competition {
         self.prize_amount = 0;
         self.prize_amount_settled = 0;
 
-        self.update_status(CompetitionRoundStatus::Active)?;
-
         Ok(())
     }
 }
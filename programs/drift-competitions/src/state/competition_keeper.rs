@@ -0,0 +1,54 @@
+use crate::state::Size;
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::error::CompetitionResult;
+
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct CompetitionKeeper {
+    pub authority: Pubkey,
+    pub competition: Pubkey,
+
+    // lamports earned for cranking, paid out from competition_authority
+    pub unclaimed_reward: u64,
+    pub total_reward: u64,
+
+    pub number_of_calls: u64,
+    pub last_call_ts: i64,
+
+    pub padding: [u8; 32],
+}
+
+impl Size for CompetitionKeeper {
+    const SIZE: usize = 128 + 8;
+}
+
+const_assert_eq!(
+    CompetitionKeeper::SIZE,
+    std::mem::size_of::<CompetitionKeeper>() + 8
+);
+
+impl CompetitionKeeper {
+    pub fn record_call(&mut self, reward: u64, now: i64) -> CompetitionResult {
+        self.number_of_calls = self.number_of_calls.safe_add(1)?;
+        self.unclaimed_reward = self.unclaimed_reward.safe_add(reward)?;
+        self.total_reward = self.total_reward.safe_add(reward)?;
+        self.last_call_ts = now;
+
+        Ok(())
+    }
+
+    pub fn claim_reward(&mut self, available: u64) -> CompetitionResult<u64> {
+        // pay what the vault can afford, the rest stays claimable
+        let reward = self.unclaimed_reward.min(available);
+        self.unclaimed_reward = self.unclaimed_reward.safe_sub(reward)?;
+
+        Ok(reward)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::state::Size;
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::error::{CompetitionResult, ErrorCode};
+use drift::validate;
+
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+pub const COMPETITION_REGISTRY_PAGE_SIZE: usize = 64;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct CompetitionRegistry {
+    pub admin: Pubkey, // lists and delists competitions
+    pub total_competitions_registered: u64,
+    // CompetitionRegistryPage accounts 0..number_of_pages, added by the admin as they fill up
+    pub number_of_pages: u32,
+
+    pub padding: [u8; 4],
+}
+
+impl Size for CompetitionRegistry {
+    const SIZE: usize = 48 + 8;
+}
+
+const_assert_eq!(
+    CompetitionRegistry::SIZE,
+    std::mem::size_of::<CompetitionRegistry>() + 8
+);
+
+impl CompetitionRegistry {
+    pub fn add_page(&mut self, page_number: u32) -> CompetitionResult {
+        validate!(
+            page_number == self.number_of_pages,
+            ErrorCode::CompetitionRegistryUpdateInvalid,
+            "next registry page is {} (not {})",
+            self.number_of_pages,
+            page_number
+        )?;
+
+        self.number_of_pages = self.number_of_pages.safe_add(1)?;
+
+        Ok(())
+    }
+}
+
+// listed competitions only, everything else (name, sponsor, status) is read off the
+// competition account so it can't go stale here
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct CompetitionRegistryPage {
+    pub page_number: u32,
+    pub number_of_competitions: u32,
+    pub competitions: [Pubkey; 64],
+}
+
+impl Size for CompetitionRegistryPage {
+    const SIZE: usize = 2056 + 8;
+}
+
+const_assert_eq!(
+    CompetitionRegistryPage::SIZE,
+    std::mem::size_of::<CompetitionRegistryPage>() + 8
+);
+
+impl CompetitionRegistryPage {
+    pub fn get_competitions(&self) -> &[Pubkey] {
+        &self.competitions[..self.number_of_competitions as usize]
+    }
+
+    fn find_index(&self, competition: &Pubkey) -> Option<usize> {
+        self.get_competitions()
+            .iter()
+            .position(|listed| listed == competition)
+    }
+
+    pub fn add(&mut self, competition: Pubkey) -> CompetitionResult {
+        validate!(
+            self.find_index(&competition).is_none(),
+            ErrorCode::CompetitionRegistryUpdateInvalid,
+            "competition {} already registered",
+            competition
+        )?;
+
+        validate!(
+            (self.number_of_competitions as usize) < COMPETITION_REGISTRY_PAGE_SIZE,
+            ErrorCode::CompetitionRegistryUpdateInvalid,
+            "registry page {} is full ({} competitions)",
+            self.page_number,
+            COMPETITION_REGISTRY_PAGE_SIZE
+        )?;
+
+        self.competitions[self.number_of_competitions as usize] = competition;
+        self.number_of_competitions = self.number_of_competitions.safe_add(1)?;
+
+        Ok(())
+    }
+
+    pub fn remove(&mut self, competition: &Pubkey) -> CompetitionResult {
+        let index = self.find_index(competition).ok_or_else(|| {
+            msg!(
+                "competition {} not on registry page {}",
+                competition,
+                self.page_number
+            );
+            ErrorCode::CompetitionRegistryUpdateInvalid
+        })?;
+
+        // swap remove, order isn't meaningful
+        let last_index = self.number_of_competitions.safe_sub(1)? as usize;
+        self.competitions[index] = self.competitions[last_index];
+        self.competitions[last_index] = Pubkey::default();
+        self.number_of_competitions = last_index as u32;
+
+        Ok(())
+    }
+}
@@ -55,7 +55,10 @@ pub struct Competitor {
     pub bonus_score: u64, // this can be used to claim raffle w/o purchase
 
     pub status: CompetitorStatus,
-    pub padding: [u8; 31],
+    pub padding1: [u8; 7],
+    // round_number + 1 of the last round this competitor won (0 = never)
+    pub last_round_won: u64,
+    pub padding: [u8; 16],
 }
 
 impl Size for Competitor {
@@ -95,10 +98,75 @@ impl Competitor {
         Ok(())
     }
 
+    pub fn close(&self, competition: &mut Competition, now: i64) -> CompetitionResult {
+        if competition.status == CompetitionRoundStatus::Expired {
+            // winnings are forfeited once the claim window has passed
+            validate!(
+                self.unclaimed_winnings == 0
+                    || now >= competition.calculate_claim_window_end_ts()?,
+                ErrorCode::CompetitorHasUnclaimedWinnings,
+                "competitor has {} unclaimed winnings",
+                self.unclaimed_winnings
+            )?;
+        } else {
+            validate!(
+                self.unclaimed_winnings == 0,
+                ErrorCode::CompetitorHasUnclaimedWinnings,
+                "competitor has {} unclaimed winnings",
+                self.unclaimed_winnings
+            )?;
+
+            competition.validate_round_settlement_hasnt_started(now)?;
+        }
+
+        // disqualified competitors were already removed from the count
+        if self.is_active()? {
+            competition.number_of_competitors = competition.number_of_competitors.safe_sub(1)?;
+        }
+
+        Ok(())
+    }
+
+    // competitors forfeited at a settlement deadline are left on the missed round, bring them
+    // to the current round (entries accrued meanwhile carry over to their next settlement)
+    pub fn catch_up_round(&mut self, competition: &Competition) -> CompetitionResult<bool> {
+        if !self.is_active()? || self.competition_round_number >= competition.round_number {
+            return Ok(false);
+        }
+
+        msg!(
+            "catching up competitor from round_number={} to round_number={}",
+            self.competition_round_number,
+            competition.round_number
+        );
+        self.competition_round_number = competition.round_number;
+        self.min_draw = 0;
+        self.max_draw = 0;
+
+        Ok(true)
+    }
+
+    pub fn has_won_round(&self, round_number: u64) -> CompetitionResult<bool> {
+        Ok(self.last_round_won == round_number.safe_add(1)?)
+    }
+
     pub fn is_active(&self) -> CompetitionResult<bool> {
         Ok(self.status == CompetitorStatus::Active)
     }
 
+    // the winner badge is only ever minted to the competitor's own authority
+    pub fn validate_winner_badge_authority(&self, winner_authority: &Pubkey) -> CompetitionResult {
+        validate!(
+            self.authority.eq(winner_authority),
+            ErrorCode::InvalidWinnerBadgeAuthority,
+            "winner_authority {} != competitor authority {}",
+            winner_authority,
+            self.authority
+        )?;
+
+        Ok(())
+    }
+
     pub fn calculate_snapshot_score(&self, user_stats: &UserStats) -> DriftResult<u64> {
         // 10 cents of taker volume (entry tier of 10 bps) => 1 ticket
         let taker_fee = user_stats
@@ -140,14 +208,8 @@ impl Competitor {
     ) -> CompetitionResult<u64> {
         // cpi update to insurance fund stake occurs outside this (in claim instruction)
 
-        // don't allow claiming during round resolution stages / expired
-        validate!(
-            competition.status == CompetitionRoundStatus::Active
-                || competition.status == CompetitionRoundStatus::WinnerSettlementComplete,
-            ErrorCode::CompetitionRoundOngoing
-        )?;
-
-        validate!(!competition.is_expired(now)?, ErrorCode::CompetitionExpired)?;
+        competition.validate_winnings_claimable(now)?;
+        self.catch_up_round(competition)?;
 
         validate!(
             spot_market.insurance_fund.shares_base == insurance_fund_stake.if_base,
@@ -8,7 +8,7 @@ use super::CompetitorStatus;
 pub struct CompetitionRoundSummaryRecord {
     pub competition: Pubkey,
     pub round_number: u64,   // the round number ending
-    pub round_start_ts: i64, // the round expiry ts - round duration
+    pub round_start_ts: i64, // when the round started
     pub round_end_ts: i64,   // the round expiry ts
 
     pub prize_placement: u32, // which prize bucket was selected (zero indexed). highest is 2 which is max_prize
@@ -30,6 +30,13 @@ pub struct CompetitionRoundSummaryRecord {
     pub total_if_shares: u128,    // snapshot of total shares in spot market insurance fund
 
     pub ts: i64, // unix timestamp this record was emitted
+
+    // appended after ts to keep the layout of older records decodable
+    pub prize_draw: u128, // prize_randomness re-ranged to the buckets if they shrank since the request
+    pub winner_randomness_delivered: u128, // raw winner value delivered to the program, ranged to [1, WINNER_MAX] on-chain
+    pub prize_randomness_delivered: u128, // raw prize value delivered to the program, ranged to [0, PRIZE_MAX] on-chain
+    pub winner_randomness_max: u128,      // WINNER_MAX requested (total_score_settled at request)
+    pub randomness_hash: [u8; 32], // raw bytes the delivered values were read from: the sha256 for on-chain providers, winner || prize for Switchboard
 }
 
 #[event]
@@ -58,6 +65,9 @@ pub struct CompetitionRoundWinnerRecord {
     pub prize_value: u64,   // token value of the if_shares at the time of settling winner
 
     pub ts: i64, // unix timestamp this record was emitted
+
+    // appended after ts to keep the layout of older records decodable
+    pub winner_badge_mint: Pubkey, // mint of the badge token given for this placement (default if none)
 }
 
 #[event]
@@ -80,3 +90,130 @@ pub struct CompetitorSettledRecord {
 
     pub ts: i64,
 }
+
+#[event]
+#[derive(Default)]
+pub struct CompetitionExpiredRecord {
+    pub competition: Pubkey,   // public key of corresponding competition account
+    pub round_number: u64,     // the round in progress at expiry
+    pub round_abandoned: bool, // whether the in-flight round was abandoned without a full draw
+
+    pub number_of_competitors: u128, // count of competitors at expiry
+    pub outstanding_unclaimed_winnings: u128, // if_shares still claimable by winners (unit: if_shares)
+    pub unclaimed_winnings_forfeited: u128, // if_shares forfeited after the claim window (unit: if_shares)
+
+    pub competition_expiry_ts: i64, // the competition expiry ts
+    pub claim_window_end_ts: i64,   // unclaimed winnings are forfeited after this ts
+
+    pub ts: i64, // unix timestamp this record was emitted
+}
+
+#[event]
+#[derive(Default)]
+pub struct SponsorProposedRecord {
+    pub competition: Pubkey, // public key of corresponding competition account
+    pub sponsor: Pubkey,     // current sponsor
+    pub pending_sponsor: Pubkey, // proposed sponsor (default when a proposal is cancelled)
+
+    pub ts: i64, // unix timestamp this record was emitted
+}
+
+#[event]
+#[derive(Default)]
+pub struct SponsorAcceptedRecord {
+    pub competition: Pubkey, // public key of corresponding competition account
+    pub previous_sponsor: Pubkey, // sponsor before the transfer
+    pub sponsor: Pubkey,     // sponsor after the transfer
+
+    pub ts: i64, // unix timestamp this record was emitted
+}
+
+#[event]
+#[derive(Default)]
+pub struct RoundParamsScheduledRecord {
+    pub competition: Pubkey, // public key of corresponding competition account
+    pub effective_round_number: u64, // first round the pending params apply to
+
+    pub max_entries_per_competitor: Option<u128>, // pending value (None if unchanged)
+    pub round_duration: Option<u64>,              // pending value (None if unchanged)
+    pub min_sponsor_amount: Option<u64>,          // pending value (None if unchanged)
+    pub max_sponsor_fraction: Option<u64>,        // pending value (None if unchanged)
+    pub number_of_winners: Option<u32>,           // pending value (None if unchanged)
+
+    pub ts: i64, // unix timestamp this record was emitted
+}
+
+#[event]
+#[derive(Default)]
+pub struct WinnerSkippedRecord {
+    pub competition: Pubkey, // public key of corresponding competition account
+    pub round_number: u64,   // count of rounds for this competition
+
+    pub winner_placement: u32, // placement left open by the skipped winner
+    pub skipped_winner_randomness: u128, // draw that was skipped
+    pub winner_randomness: u128, // redraw for the same placement
+    pub winner_redraws: u32,   // draws redone this round, incl. this one
+
+    pub ts: i64, // unix timestamp this record was emitted
+}
+
+#[event]
+#[derive(Default)]
+pub struct RandomnessRequestedRecord {
+    pub competition: Pubkey, // public key of corresponding competition account
+    pub round_number: u64,   // count of rounds for this competition
+    pub retry: u8,           // 0 for the initial request, then each re-trigger after a timeout
+    pub max_retries: u8,     // re-triggers allowed before the operator has to step in
+
+    pub winner_max: u128, // upper bound of the winner_randomness request
+    pub prize_max: u128,  // upper bound of the prize_randomness request
+
+    pub slot: u64, // slot of the request
+    pub ts: i64,   // unix timestamp this record was emitted
+}
+
+#[event]
+#[derive(Default)]
+pub struct RandomnessRevealedRecord {
+    pub competition: Pubkey, // public key of corresponding competition account
+    pub round_number: u64,   // count of rounds for this competition
+
+    pub commitment: [u8; 32], // sha256(secret) committed by the sponsor
+    pub secret: [u8; 32],     // revealed secret (zeroed when the reveal was missed)
+    pub slot_hash: [u8; 32],  // hash of the first slot after the request (zeroed if never pinned)
+    pub slot: u64,            // slot slot_hash was pinned from
+    pub reveal_missed: bool, // sponsor didn't reveal before the deadline, re-requested from switchboard
+    pub slot_hash_pin_missed: bool, // slot hash never pinned, re-requested from switchboard unpenalized
+    pub reveals_missed: u8,         // missed reveals for this competition so far
+
+    pub winner_randomness: u128, // derived winner_randomness (0 when the reveal was missed)
+    pub prize_randomness: u128,  // derived prize_randomness (0 when the reveal was missed)
+
+    pub ts: i64, // unix timestamp this record was emitted
+}
+
+// emitted for the round's first winner draw and every draw derived from it
+// (next placement, repeat winner redraw, skip_winner); later draws can be recomputed with
+// calculate_winner_draw: sha256("winner_draw" || winner_randomness_delivered || round_number
+//     || winner_placement || winner_redraws) mapped to (0, total_score_settled]
+#[event]
+#[derive(Default)]
+pub struct WinnerDrawRecord {
+    pub competition: Pubkey, // public key of corresponding competition account
+    pub round_number: u64,   // count of rounds for this competition
+
+    pub winner_randomness_delivered: u128, // raw winner value delivered to the program, ranged to [1, WINNER_MAX] on-chain
+    pub prize_randomness_delivered: u128, // raw prize value delivered to the program, ranged to [0, PRIZE_MAX] on-chain
+    pub winner_randomness_max: u128,      // WINNER_MAX requested (total_score_settled at request)
+    pub prize_randomness_max: u128,       // PRIZE_MAX requested (prize bucket ratio sum at request)
+    pub prize_randomness: u128,           // prize value the draws are derived with
+    pub prize_draw: u128, // prize_randomness re-ranged to the buckets if they shrank since the request
+    pub total_score_settled: u128, // total entries across all settled competitors
+
+    pub winner_placement: u32, // placement this draw is for. from [0, number_of_winners)
+    pub winner_redraws: u32,   // draws redone this round so far
+    pub winning_score_settled: u128, // tickets already won this round, draws landing on them are redone
+    pub winner_randomness: u128,     // drawn ticket. from (0, total_score_settled]
+
+    pub ts: i64, // unix timestamp this record was emitted
+}
@@ -1,10 +1,18 @@
 pub use competition::*;
+pub use competition_keeper::*;
+pub use competition_registry::*;
 pub use competitor::*;
+pub use round_schedule::*;
+pub use ticket_index::*;
 
 pub use events::*;
 pub use traits::*;
 
 mod competition;
+mod competition_keeper;
+mod competition_registry;
 mod competitor;
 pub mod events;
+mod round_schedule;
+mod ticket_index;
 mod traits;
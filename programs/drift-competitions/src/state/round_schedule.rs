@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::error::{CompetitionResult, ErrorCode};
+use drift::math::safe_math::SafeMath;
+use drift::validate;
+
+pub const ROUND_SCHEDULE_CAPACITY: usize = 16;
+pub const SECONDS_PER_DAY: i64 = 60 * 60 * 24;
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug)]
+pub enum RoundScheduleMode {
+    // next_round_expiry_ts + k * round_duration
+    Interval = 0,
+    // pop the next queued round end (falls back to Interval when empty)
+    Explicit = 1,
+    // next weekday/hour/minute matching the rule, skipping queued round ends (holidays)
+    Weekly = 2,
+}
+
+impl Default for RoundScheduleMode {
+    fn default() -> Self {
+        RoundScheduleMode::Interval
+    }
+}
+
+#[zero_copy(unsafe)]
+#[derive(Default, Eq, PartialEq, Debug)]
+pub struct RoundSchedule {
+    // ring buffer of ascending round end timestamps
+    // Explicit: upcoming round ends, Weekly: round ends to skip
+    pub expiries: [i64; ROUND_SCHEDULE_CAPACITY],
+    pub head: u8,
+    pub len: u8,
+    pub mode: RoundScheduleMode,
+    pub weekday_mask: u8, // Weekly: bit 0 = Monday .. bit 6 = Sunday
+    pub hour: u8,         // Weekly: utc hour of the round end
+    pub minute: u8,       // Weekly: utc minute of the round end
+    pub padding: [u8; 2],
+}
+
+impl RoundSchedule {
+    pub fn get(&self, index: usize) -> i64 {
+        self.expiries[(self.head as usize + index) % ROUND_SCHEDULE_CAPACITY]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = i64> + '_ {
+        (0..self.len as usize).map(move |i| self.get(i))
+    }
+
+    pub fn last(&self) -> Option<i64> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(self.get(self.len as usize - 1))
+        }
+    }
+
+    pub fn push(&mut self, expiry_ts: i64) -> CompetitionResult {
+        validate!(
+            (self.len as usize) < ROUND_SCHEDULE_CAPACITY,
+            ErrorCode::InvalidRoundSchedule,
+            "round schedule is full ({} entries)",
+            ROUND_SCHEDULE_CAPACITY
+        )?;
+
+        if let Some(last_expiry_ts) = self.last() {
+            validate!(
+                expiry_ts > last_expiry_ts,
+                ErrorCode::InvalidRoundSchedule,
+                "round schedule must be ascending ({} <= {})",
+                expiry_ts,
+                last_expiry_ts
+            )?;
+        }
+
+        let index = (self.head as usize + self.len as usize) % ROUND_SCHEDULE_CAPACITY;
+        self.expiries[index] = expiry_ts;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.expiries = [0; ROUND_SCHEDULE_CAPACITY];
+        self.head = 0;
+        self.len = 0;
+    }
+
+    // drop every entry at or before ts (consumed round ends / past holidays)
+    pub fn prune(&mut self, ts: i64) {
+        while self.len > 0 && self.get(0) <= ts {
+            self.expiries[self.head as usize] = 0;
+            self.head = ((self.head as usize + 1) % ROUND_SCHEDULE_CAPACITY) as u8;
+            self.len -= 1;
+        }
+    }
+
+    pub fn next_explicit_expiry_ts(&self, after: i64) -> Option<i64> {
+        self.iter().find(|expiry_ts| *expiry_ts > after)
+    }
+
+    pub fn validate_weekly_rule(&self) -> CompetitionResult {
+        validate!(
+            self.weekday_mask != 0 && self.weekday_mask < 1 << 7,
+            ErrorCode::InvalidRoundSchedule,
+            "invalid weekday_mask={:#09b}",
+            self.weekday_mask
+        )?;
+
+        validate!(
+            self.hour < 24 && self.minute < 60,
+            ErrorCode::InvalidRoundSchedule,
+            "invalid round end time {}:{}",
+            self.hour,
+            self.minute
+        )?;
+
+        Ok(())
+    }
+
+    pub fn next_weekly_expiry_ts(&self, after: i64) -> CompetitionResult<i64> {
+        self.validate_weekly_rule()?;
+
+        let time_of_day = (self.hour as i64)
+            .safe_mul(60 * 60)?
+            .safe_add((self.minute as i64).safe_mul(60)?)?;
+
+        let first_day = after.div_euclid(SECONDS_PER_DAY);
+        // each skipped round end can push the result back by at most a week
+        let last_day = first_day.safe_add(7 * (self.len as i64 + 1))?;
+
+        for day in first_day..=last_day {
+            // 1970-01-01 was a thursday
+            let weekday = day.safe_add(3)?.rem_euclid(7);
+            if self.weekday_mask & (1 << weekday) == 0 {
+                continue;
+            }
+
+            let expiry_ts = day.safe_mul(SECONDS_PER_DAY)?.safe_add(time_of_day)?;
+            if expiry_ts > after && !self.iter().any(|skipped_ts| skipped_ts == expiry_ts) {
+                return Ok(expiry_ts);
+            }
+        }
+
+        msg!("no round end found for weekly schedule after {}", after);
+        Err(ErrorCode::InvalidRoundSchedule)
+    }
+}
@@ -0,0 +1,128 @@
+use crate::state::Size;
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+
+use crate::error::{CompetitionResult, ErrorCode};
+use drift::validate;
+
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+pub const TICKET_INDEX_PAGE_SIZE: usize = 32;
+
+#[zero_copy(unsafe)]
+#[derive(Default, Eq, PartialEq, Debug)]
+pub struct TicketRange {
+    pub competitor: Pubkey,
+    // tickets are (min_draw, max_draw], same as the competitor account
+    pub min_draw: u128,
+    pub max_draw: u128,
+}
+
+// ticket ranges in settlement order, page N holds the competitors settled at
+// positions [N * TICKET_INDEX_PAGE_SIZE, (N + 1) * TICKET_INDEX_PAGE_SIZE)
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct TicketIndexPage {
+    pub competition: Pubkey,
+    pub round_number: u64,
+    pub page_number: u32,
+    pub number_of_entries: u32,
+    pub entries: [TicketRange; 32],
+}
+
+impl Size for TicketIndexPage {
+    const SIZE: usize = 2096 + 8;
+}
+
+const_assert_eq!(
+    TicketIndexPage::SIZE,
+    std::mem::size_of::<TicketIndexPage>() + 8
+);
+
+impl TicketIndexPage {
+    pub fn get_page_number(position: u128) -> CompetitionResult<u32> {
+        Ok(position.safe_div(TICKET_INDEX_PAGE_SIZE as u128)?.cast()?)
+    }
+
+    pub fn get_entries(&self) -> &[TicketRange] {
+        &self.entries[..self.number_of_entries as usize]
+    }
+
+    pub fn record(
+        &mut self,
+        round_number: u64,
+        position: u128,
+        competitor: Pubkey,
+        min_draw: u128,
+        max_draw: u128,
+    ) -> CompetitionResult {
+        validate!(
+            Self::get_page_number(position)? == self.page_number,
+            ErrorCode::TicketIndexPageInvalid,
+            "settlement position {} is not on page {}",
+            position,
+            self.page_number
+        )?;
+
+        let slot = (position % TICKET_INDEX_PAGE_SIZE as u128) as usize;
+
+        // pages are reused across rounds, the first slot starts the page over
+        if slot == 0 {
+            self.round_number = round_number;
+            self.number_of_entries = 0;
+        }
+
+        validate!(
+            self.round_number == round_number && self.number_of_entries as usize == slot,
+            ErrorCode::TicketIndexPageInvalid,
+            "page {} round_number={} number_of_entries={} can't record slot {} for round_number={}",
+            self.page_number,
+            self.round_number,
+            self.number_of_entries,
+            slot,
+            round_number
+        )?;
+
+        self.entries[slot] = TicketRange {
+            competitor,
+            min_draw,
+            max_draw,
+        };
+        self.number_of_entries = self.number_of_entries.safe_add(1)?;
+
+        Ok(())
+    }
+
+    pub fn find_winner(
+        &self,
+        round_number: u64,
+        winner_randomness: u128,
+    ) -> CompetitionResult<&TicketRange> {
+        validate!(
+            self.round_number == round_number,
+            ErrorCode::TicketIndexPageInvalid,
+            "page {} round_number={} != competition round_number={}",
+            self.page_number,
+            self.round_number,
+            round_number
+        )?;
+
+        // ranges are contiguous and ascending, so binary search on max_draw
+        let entries = self.get_entries();
+        let index = entries.partition_point(|entry| entry.max_draw < winner_randomness);
+
+        validate!(
+            index < entries.len() && entries[index].min_draw < winner_randomness,
+            ErrorCode::TicketIndexWinnerNotFound,
+            "winner_randomness={} not on page {}",
+            winner_randomness,
+            self.page_number
+        )?;
+
+        Ok(&entries[index])
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 
 mod competition_helpers {
-    use crate::state::Competition;
+    use crate::state::{Competition, RoundScheduleMode, SECONDS_PER_DAY};
     use drift::{
         math::{
             constants::{PERCENTAGE_PRECISION_U64, QUOTE_PRECISION},
@@ -56,6 +56,135 @@ mod competition_helpers {
         }
     }
 
+    #[test]
+    pub fn test_calculate_next_round_expiry_ts_explicit_schedule() {
+        let now = 1695330779;
+        let sweepstakes = &mut Competition::default();
+        sweepstakes.round_duration = 604800;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.next_round_expiry_ts = 1695650400;
+        sweepstakes.round_schedule.mode = RoundScheduleMode::Explicit;
+
+        let round_ends = [
+            1695650400 + 3600,
+            1695650400 + 86400 * 3,
+            1695650400 + 86400 * 10,
+        ];
+        for round_end in round_ends {
+            sweepstakes.round_schedule.push(round_end).unwrap();
+        }
+
+        // must be ascending
+        assert!(sweepstakes.round_schedule.push(round_ends[2]).is_err());
+
+        // current round is unaffected
+        assert_eq!(
+            sweepstakes.calculate_next_round_expiry_ts(now).unwrap(),
+            1695650400
+        );
+
+        let now = 1695650400;
+        assert_eq!(
+            sweepstakes.calculate_next_round_expiry_ts(now).unwrap(),
+            round_ends[0]
+        );
+
+        // stale entries are skipped when settlement is late
+        let now = round_ends[0] + 1;
+        assert_eq!(
+            sweepstakes.calculate_next_round_expiry_ts(now).unwrap(),
+            round_ends[1]
+        );
+
+        sweepstakes.next_round_expiry_ts = round_ends[1];
+        sweepstakes.round_schedule.prune(round_ends[1]);
+        assert_eq!(sweepstakes.round_schedule.len, 1);
+        assert_eq!(sweepstakes.round_schedule.last(), Some(round_ends[2]));
+
+        let now = round_ends[1];
+        assert_eq!(
+            sweepstakes.calculate_next_round_expiry_ts(now).unwrap(),
+            round_ends[2]
+        );
+
+        // falls back to round_duration once the calendar runs dry
+        sweepstakes.next_round_expiry_ts = round_ends[2];
+        sweepstakes.round_schedule.prune(round_ends[2]);
+        let now = round_ends[2];
+        assert_eq!(
+            sweepstakes.calculate_next_round_expiry_ts(now).unwrap(),
+            round_ends[2] + 604800
+        );
+
+        // ring buffer wraps around
+        for i in 0..16 {
+            sweepstakes.round_schedule.push(now + i + 1).unwrap();
+        }
+        assert!(sweepstakes.round_schedule.push(now + 17).is_err());
+        sweepstakes.round_schedule.prune(now + 1);
+        sweepstakes.round_schedule.push(now + 17).unwrap();
+        assert_eq!(sweepstakes.round_schedule.len, 16);
+        assert_eq!(sweepstakes.round_schedule.get(0), now + 2);
+        assert_eq!(sweepstakes.round_schedule.last(), Some(now + 17));
+    }
+
+    #[test]
+    pub fn test_calculate_next_round_expiry_ts_weekly_schedule() {
+        // thursday 2023-09-21 21:12:59 utc
+        let now = 1695330779;
+        let sweepstakes = &mut Competition::default();
+        sweepstakes.round_duration = 604800;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.next_round_expiry_ts = now;
+        sweepstakes.round_schedule.mode = RoundScheduleMode::Weekly;
+
+        // invalid rule
+        assert!(sweepstakes.calculate_next_round_expiry_ts(now).is_err());
+
+        // every friday 16:00 utc
+        sweepstakes.round_schedule.weekday_mask = 1 << 4;
+        sweepstakes.round_schedule.hour = 16;
+        let friday = 1695398400; // 2023-09-22 16:00:00 utc
+        assert_eq!(
+            sweepstakes.calculate_next_round_expiry_ts(now).unwrap(),
+            friday
+        );
+
+        // exactly at the round end rolls to the following week
+        assert_eq!(
+            sweepstakes.calculate_next_round_expiry_ts(friday).unwrap(),
+            friday + 7 * SECONDS_PER_DAY
+        );
+
+        // settlement delays don't cause drift
+        assert_eq!(
+            sweepstakes
+                .calculate_next_round_expiry_ts(friday + 3 * SECONDS_PER_DAY)
+                .unwrap(),
+            friday + 7 * SECONDS_PER_DAY
+        );
+
+        // skip a holiday
+        sweepstakes
+            .round_schedule
+            .push(friday + 7 * SECONDS_PER_DAY)
+            .unwrap();
+        assert_eq!(
+            sweepstakes.calculate_next_round_expiry_ts(friday).unwrap(),
+            friday + 14 * SECONDS_PER_DAY
+        );
+
+        // tuesdays and fridays
+        sweepstakes.round_schedule.weekday_mask = 1 << 1 | 1 << 4;
+        assert_eq!(
+            sweepstakes.calculate_next_round_expiry_ts(friday).unwrap(),
+            friday + 4 * SECONDS_PER_DAY
+        );
+
+        sweepstakes.round_schedule.hour = 24;
+        assert!(sweepstakes.calculate_next_round_expiry_ts(friday).is_err());
+    }
+
     #[test]
     pub fn test_prize_odds() {
         let sweepstakes = &mut Competition::default();
@@ -338,7 +467,19 @@ mod competition_helpers {
     }
 
     #[test]
-    pub fn test_calculate_next_winner_randomness() {
+    pub fn test_calculate_prize_draw() {
+        let sweepstakes = &mut Competition::default();
+        sweepstakes.prize_randomness_max = 10;
+        sweepstakes.prize_randomness = 7;
+
+        assert_eq!(sweepstakes.calculate_prize_draw(10), 7);
+        assert_eq!(sweepstakes.calculate_prize_draw(12), 7);
+        // buckets shrank since the request
+        assert_eq!(sweepstakes.calculate_prize_draw(5), 2);
+    }
+
+    #[test]
+    pub fn test_calculate_winner_draw() {
         let sweepstakes = &mut Competition::default();
 
         sweepstakes.next_round_expiry_ts = 1696859969;
@@ -352,40 +493,75 @@ mod competition_helpers {
         assert_eq!(sweepstakes.winner_randomness, 0);
         assert_eq!(sweepstakes.prize_randomness, 0);
 
+        sweepstakes.winner_randomness_delivered = 130816763257814;
+
         let mut res1: [u128; 1000] = [0; 1000];
         for i in 0..res1.len() {
-            res1[i] = sweepstakes.calculate_next_winner_randomness().unwrap();
+            res1[i] = sweepstakes.calculate_winner_draw().unwrap();
             sweepstakes.winner_randomness = res1[i];
-            assert!(sweepstakes.winner_randomness < sweepstakes.total_score_settled);
+            sweepstakes.number_of_winners_settled += 1;
+            assert!(sweepstakes.winner_randomness <= sweepstakes.total_score_settled);
             assert!(sweepstakes.winner_randomness > 0);
             assert!(!res1[..i].contains(&sweepstakes.winner_randomness));
         }
 
-        sweepstakes.prize_randomness = 1;
-        assert_eq!(sweepstakes.winner_randomness, 59690716702806);
-        assert_eq!(sweepstakes.prize_randomness, 1);
-        assert_eq!(res1[899], 155627316674945);
+        // pinned so the derivation can be reproduced off-chain
+        assert_eq!(res1[0], 173780906607931);
+        assert_eq!(res1[899], 257721915462475);
+        assert_eq!(res1[999], 308202746528267);
+
+        // derived from the delivered randomness, not the previous draw
+        sweepstakes.number_of_winners_settled = 999;
+        sweepstakes.winner_randomness = 1;
+        assert_eq!(sweepstakes.calculate_winner_draw().unwrap(), res1[999]);
+
+        // a redraw for the same placement gives a different draw
+        sweepstakes.winner_redraws = 1;
+        assert_eq!(
+            sweepstakes.calculate_winner_draw().unwrap(),
+            344399749137039
+        );
+
+        sweepstakes.winner_randomness_delivered = 1;
+        sweepstakes.winner_redraws = 0;
+        sweepstakes.winner_randomness = 0;
+        sweepstakes.number_of_winners_settled = 0;
 
         let mut res2: [u128; 1000] = [0; 1000];
         for i in 0..res2.len() {
-            res2[i] = sweepstakes.calculate_next_winner_randomness().unwrap();
+            res2[i] = sweepstakes.calculate_winner_draw().unwrap();
             sweepstakes.winner_randomness = res2[i];
-            assert!(sweepstakes.winner_randomness < sweepstakes.total_score_settled);
+            sweepstakes.number_of_winners_settled += 1;
+            assert!(sweepstakes.winner_randomness <= sweepstakes.total_score_settled);
             assert!(sweepstakes.winner_randomness > 0);
             assert!(!res1.contains(&sweepstakes.winner_randomness));
             // Check only up to the current index for res2
             assert!(!res2[..i].contains(&sweepstakes.winner_randomness));
         }
-        assert_eq!(res2[994], 40087212372938);
+
+        // every ticket already won, a redraw could never land on a new winner
+        sweepstakes.winning_score_settled = sweepstakes.total_score_settled;
+        assert!(sweepstakes.calculate_winner_draw().is_err());
+
+        sweepstakes.total_score_settled = 0;
+        sweepstakes.winning_score_settled = 0;
+        assert!(sweepstakes.calculate_winner_draw().is_err());
     }
 }
 
 mod competition_fcn {
+    use crate::error::ErrorCode;
     use crate::state::{
-        Competition, CompetitionRoundStatus, Competitor, CompetitorStatus, SponsorInfo,
+        Competition, CompetitionKeeper, CompetitionRegistry, CompetitionRegistryPage,
+        CompetitionRoundStatus, Competitor, CompetitorStatus, RandomnessProvider,
+        RoundScheduleMode, SponsorInfo, TicketIndexPage, COMPETITION_REGISTRY_PAGE_SIZE,
+        DEFAULT_MAX_RANDOMNESS_REQUEST_RETRIES, DEFAULT_RANDOMNESS_REQUEST_TIMEOUT,
+        DEFAULT_SETTLEMENT_GRACE_PERIOD, EXPIRED_COMPETITION_CLAIM_WINDOW, SECONDS_PER_DAY,
+        TICKET_INDEX_PAGE_SIZE,
     };
     use crate::utils::{self, get_test_sample_draw};
     use anchor_lang::prelude::Pubkey;
+    use anchor_lang::solana_program::hash::hashv;
     use drift::{
         math::{
             constants::{
@@ -535,7 +711,8 @@ mod competition_fcn {
                 vault_balance,
                 now,
                 Pubkey::default(),
-                Pubkey::default()
+                Pubkey::default(),
+                None,
             )
             .is_err());
         sweepstakes
@@ -546,6 +723,7 @@ mod competition_fcn {
                 now,
                 Pubkey::default(),
                 Pubkey::default(),
+                None,
             )
             .unwrap();
         assert!(sweepstakes
@@ -555,7 +733,8 @@ mod competition_fcn {
                 vault_balance,
                 now,
                 Pubkey::default(),
-                Pubkey::default()
+                Pubkey::default(),
+                None,
             )
             .is_err()); // cannot settle twice
         assert_eq!(
@@ -574,6 +753,7 @@ mod competition_fcn {
             number_of_competitors: 2,
             number_of_competitors_settled: 2,
             total_score_settled: 4,
+            winning_score_settled: 3,
             round_duration: 60,
             winner_randomness: 2,
             sponsor_info: SponsorInfo {
@@ -584,14 +764,18 @@ mod competition_fcn {
         };
 
         assert_eq!(expected_sweepstakes, sweepstakes);
+        // no recorded start (e.g. migrated mid-round), a full interval before the expiry
+        assert_eq!(sweepstakes.get_round_start_ts().unwrap(), 168000000);
 
         sweepstakes.reset_round(now).unwrap();
+        assert_eq!(sweepstakes.get_round_start_ts().unwrap(), now);
 
         let expected_sweepstakes = &mut Competition {
             number_of_winners: 1,
             number_of_winners_settled: 0,
             round_number: 1,
             status: CompetitionRoundStatus::Active,
+            round_start_ts: now,
             next_round_expiry_ts: 168000000 + 120,
             number_of_competitors: 2,
             total_score_settled: 0,
@@ -608,6 +792,255 @@ mod competition_fcn {
         assert_eq!(expected_sweepstakes, sweepstakes);
     }
 
+    #[test]
+    fn test_self_settle_competitor() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.ticket_index_enabled = 1;
+        sweepstakes.number_of_competitors = 3;
+
+        let comp1_key = Pubkey::new_unique();
+        let comp1 = &mut Competitor::default();
+        let comp2_key = Pubkey::new_unique();
+        let comp2 = &mut Competitor::default();
+        let comp3_key = Pubkey::new_unique();
+        let comp3 = &mut Competitor::default();
+
+        let us: &UserStats = &UserStats::default();
+        let mut page = TicketIndexPage::default();
+
+        // round still running
+        comp1.claim_entry().unwrap();
+        assert!(sweepstakes
+            .self_settle_competitor(
+                comp1,
+                us,
+                Some(&mut page),
+                now,
+                comp1_key,
+                Pubkey::default()
+            )
+            .is_err());
+
+        now += 60;
+
+        // the entry claimed alongside the settlement counts for this round
+        sweepstakes
+            .self_settle_competitor(
+                comp1,
+                us,
+                Some(&mut page),
+                now,
+                comp1_key,
+                Pubkey::default(),
+            )
+            .unwrap();
+        assert_eq!(sweepstakes.number_of_competitors_settled, 1);
+        assert_eq!(sweepstakes.total_score_settled, 1);
+        assert_eq!((comp1.min_draw, comp1.max_draw), (0, 1));
+        assert_eq!(page.number_of_entries, 1);
+        assert_eq!(page.find_winner(0, 1).unwrap().competitor, comp1_key);
+
+        // settling again isn't silently skipped like it is for keepers
+        assert_eq!(
+            sweepstakes.self_settle_competitor(
+                comp1,
+                us,
+                Some(&mut page),
+                now,
+                comp1_key,
+                Pubkey::default()
+            ),
+            Err(ErrorCode::CompetitorNotSettleable)
+        );
+        assert!(!sweepstakes
+            .settle_and_index_competitor(
+                comp1,
+                us,
+                Some(&mut page),
+                now,
+                comp1_key,
+                Pubkey::default()
+            )
+            .unwrap());
+        assert_eq!(sweepstakes.number_of_competitors_settled, 1);
+        assert_eq!(page.number_of_entries, 1);
+
+        // disqualified competitors can't be settled either
+        comp2.status = CompetitorStatus::Disqualified;
+        assert_eq!(
+            sweepstakes.self_settle_competitor(
+                comp2,
+                us,
+                Some(&mut page),
+                now,
+                comp2_key,
+                Pubkey::default()
+            ),
+            Err(ErrorCode::CompetitorNotSettleable)
+        );
+        comp2.status = CompetitorStatus::Active;
+
+        // the ticket index page is required when enabled
+        let mut without_page = *sweepstakes;
+        assert_eq!(
+            without_page.self_settle_competitor(
+                &mut comp2.clone(),
+                us,
+                None,
+                now,
+                comp2_key,
+                Pubkey::default()
+            ),
+            Err(ErrorCode::TicketIndexPageMissing)
+        );
+
+        // zero-entry competitor, fine while others hold tickets
+        sweepstakes
+            .self_settle_competitor(
+                comp2,
+                us,
+                Some(&mut page),
+                now,
+                comp2_key,
+                Pubkey::default(),
+            )
+            .unwrap();
+        assert_eq!((comp2.min_draw, comp2.max_draw), (1, 1));
+
+        // the last competitor can't leave the round without any entries
+        let mut no_entries = *sweepstakes;
+        no_entries.total_score_settled = 0;
+        no_entries.ticket_index_enabled = 0;
+        assert_eq!(
+            no_entries.self_settle_competitor(
+                &mut comp3.clone(),
+                us,
+                None,
+                now,
+                comp3_key,
+                Pubkey::default()
+            ),
+            Err(ErrorCode::InvalidRoundSettlementDetected)
+        );
+
+        sweepstakes
+            .self_settle_competitor(
+                comp3,
+                us,
+                Some(&mut page),
+                now,
+                comp3_key,
+                Pubkey::default(),
+            )
+            .unwrap();
+        assert_eq!(sweepstakes.number_of_competitors_settled, 3);
+        assert_eq!(page.number_of_entries, 3);
+        sweepstakes.validate_round_resolved().unwrap();
+    }
+
+    #[test]
+    fn test_settle_competitors_batch() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+
+        let competition_key = Pubkey::new_unique();
+        let us: &UserStats = &UserStats::default();
+        let us_key = Pubkey::new_unique();
+
+        let new_competitor = || Competitor {
+            competition: competition_key,
+            user_stats: us_key,
+            ..Competitor::default()
+        };
+
+        let settled = &mut new_competitor();
+        settled.claim_entry().unwrap();
+        let disqualified = &mut new_competitor();
+        disqualified.status = CompetitorStatus::Disqualified;
+        let fresh = &mut new_competitor();
+        fresh.claim_entry().unwrap();
+        let last = &mut new_competitor();
+        last.claim_entry().unwrap();
+        let other_competition = &mut Competitor {
+            competition: Pubkey::new_unique(),
+            ..new_competitor()
+        };
+        // disqualified competitors and other competitions' aren't counted
+        sweepstakes.number_of_competitors = 3;
+
+        let settled_key = Pubkey::new_unique();
+        let fresh_key = Pubkey::new_unique();
+
+        now += 60;
+        sweepstakes
+            .settle_competitor(settled, us, now, settled_key, competition_key)
+            .unwrap();
+
+        // already settled and disqualified competitors are skipped
+        let number_settled = sweepstakes
+            .settle_competitors_batch(
+                &mut [
+                    (&mut *settled, settled_key, us, us_key),
+                    (&mut *disqualified, Pubkey::new_unique(), us, us_key),
+                    (&mut *fresh, fresh_key, us, us_key),
+                ],
+                None,
+                now,
+                competition_key,
+            )
+            .unwrap();
+        assert_eq!(number_settled, 1);
+        assert_eq!(sweepstakes.number_of_competitors_settled, 2);
+        assert_eq!(sweepstakes.total_score_settled, 2);
+        assert_eq!((fresh.min_draw, fresh.max_draw), (1, 2));
+
+        // a competitor of another competition fails the whole batch
+        assert_eq!(
+            sweepstakes.settle_competitors_batch(
+                &mut [(&mut *other_competition, Pubkey::new_unique(), us, us_key)],
+                None,
+                now,
+                competition_key,
+            ),
+            Err(ErrorCode::InvalidBatchSettlementAccounts)
+        );
+        // as does drift_user_stats that aren't the competitor's
+        assert_eq!(
+            sweepstakes.settle_competitors_batch(
+                &mut [(&mut *last, Pubkey::new_unique(), us, Pubkey::new_unique())],
+                None,
+                now,
+                competition_key,
+            ),
+            Err(ErrorCode::InvalidBatchSettlementAccounts)
+        );
+        assert_eq!(sweepstakes.number_of_competitors_settled, 2);
+
+        // the batch stops once every competitor is resolved
+        let number_settled = sweepstakes
+            .settle_competitors_batch(
+                &mut [
+                    (&mut *last, Pubkey::new_unique(), us, us_key),
+                    (&mut *other_competition, Pubkey::new_unique(), us, us_key),
+                ],
+                None,
+                now,
+                competition_key,
+            )
+            .unwrap();
+        assert_eq!(number_settled, 1);
+        sweepstakes.validate_round_resolved().unwrap();
+    }
+
     #[test]
     fn test_competition_expiry() {
         let mut now = 168000000;
@@ -658,35 +1091,228 @@ mod competition_fcn {
     }
 
     #[test]
-    fn test_competition_prize_rebases() {
+    fn test_competition_expiry_claim_window() {
         let mut now = 168000000;
         let sweepstakes = &mut Competition::default();
 
-        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.next_round_expiry_ts = now - 10;
         sweepstakes.round_duration = 60;
         sweepstakes.number_of_winners = 1;
+        sweepstakes.competition_expiry_ts = now + 88;
 
-        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
-        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        // round in flight with a previous winner yet to claim
+        sweepstakes.status = CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested;
         sweepstakes.number_of_competitors = 2;
+        sweepstakes.number_of_competitors_settled = 2;
+        sweepstakes.total_score_settled = 4;
+        sweepstakes.prize_randomness_max = 21;
+        sweepstakes.prize_amount = 250;
+        sweepstakes.prize_amount_settled = 100;
+        sweepstakes.sponsor_info.min_sponsor_amount = 40;
+        sweepstakes.outstanding_unclaimed_winnings = 100;
+
         let comp1 = &mut Competitor::default();
-        comp1.claim_entry().unwrap();
+        comp1.unclaimed_winnings = 100;
+
+        let spot_market = SpotMarket::default();
+        let insurance_fund_stake = InsuranceFundStake::default();
+
+        assert!(sweepstakes.is_round_in_progress());
+        assert!(comp1
+            .claim_winnings(sweepstakes, &spot_market, &insurance_fund_stake, None, now)
+            .is_err()); // mid round
+
+        now += 88;
+        assert!(sweepstakes.forfeit_unclaimed_winnings(now).is_err()); // not expired yet
+        assert!(comp1
+            .claim_winnings(sweepstakes, &spot_market, &insurance_fund_stake, None, now)
+            .is_err()); // expired but expiry not applied
+
+        sweepstakes.expire(now).unwrap();
+        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Expired);
+        assert_eq!(sweepstakes.number_of_competitors_settled, 0);
+        assert_eq!(sweepstakes.total_score_settled, 0);
+        assert_eq!(sweepstakes.prize_randomness_max, 0);
+        assert_eq!(sweepstakes.prize_amount_settled, 0);
+        assert_eq!(sweepstakes.sponsor_info.min_sponsor_amount, 140); // settled prize rolled forward
+        assert_eq!(sweepstakes.outstanding_unclaimed_winnings, 100);
+
+        // winners can claim during the window
+        let shares_claimed = comp1
+            .claim_winnings(
+                sweepstakes,
+                &spot_market,
+                &insurance_fund_stake,
+                Some(10),
+                now,
+            )
+            .unwrap();
+        assert_eq!(shares_claimed, 10);
+        assert_eq!(comp1.unclaimed_winnings, 90);
+        assert_eq!(sweepstakes.outstanding_unclaimed_winnings, 90);
+        assert!(sweepstakes.forfeit_unclaimed_winnings(now).is_err());
+
+        let claim_window_end_ts = sweepstakes.calculate_claim_window_end_ts().unwrap();
+        assert_eq!(
+            claim_window_end_ts,
+            sweepstakes.competition_expiry_ts + EXPIRED_COMPETITION_CLAIM_WINDOW
+        );
+
+        now = claim_window_end_ts - 1;
+        assert!(sweepstakes.forfeit_unclaimed_winnings(now).is_err());
 
+        // after the window remaining winnings are forfeited
+        now = claim_window_end_ts;
+        assert!(comp1
+            .claim_winnings(sweepstakes, &spot_market, &insurance_fund_stake, None, now)
+            .is_err());
+        assert_eq!(comp1.unclaimed_winnings, 90);
+
+        assert_eq!(sweepstakes.forfeit_unclaimed_winnings(now).unwrap(), 90);
+        assert_eq!(sweepstakes.outstanding_unclaimed_winnings, 0);
+        assert_eq!(sweepstakes.forfeit_unclaimed_winnings(now).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_competition_pause_and_resume() {
+        let start = 168000000;
+        let mut now = start;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.number_of_competitors = 2;
+
+        let comp1 = &mut Competitor::default();
+        comp1.claim_entry().unwrap();
         let comp2 = &mut Competitor::default();
         comp2.claim_entry().unwrap();
-        comp2.bonus_score += 2;
 
         let us: &UserStats = &UserStats::default();
 
+        assert!(sweepstakes.resume(now).is_err()); // not paused
+
+        // pause while the round is running
+        sweepstakes.pause(now).unwrap();
+        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Paused);
+        assert_eq!(
+            sweepstakes.status_before_pause,
+            CompetitionRoundStatus::Active
+        );
+        assert!(sweepstakes.pause(now).is_err());
+        assert!(sweepstakes.validate_round_is_active(now).is_err());
+        assert!(sweepstakes
+            .update_status(CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested)
+            .is_err());
+
+        now += 90;
         assert!(sweepstakes
             .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
             .is_err());
+
+        sweepstakes.resume(now).unwrap();
+        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
+        assert_eq!(sweepstakes.paused_ts, 0);
+        assert_eq!(sweepstakes.next_round_expiry_ts, start + 60 + 90);
+        assert!(sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .is_err()); // round extended by paused duration
+
+        // pause mid-settlement doesn't extend an ended round
+        now = sweepstakes.next_round_expiry_ts;
+        sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+        sweepstakes.pause(now).unwrap();
         assert!(sweepstakes
             .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
             .is_err());
 
-        now += 60;
-
+        now += 1000;
+        sweepstakes.resume(now).unwrap();
+        assert_eq!(sweepstakes.next_round_expiry_ts, start + 60 + 90);
+        sweepstakes
+            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+        assert_eq!(sweepstakes.number_of_competitors_settled, 2);
+        assert_eq!(sweepstakes.total_score_settled, 2);
+    }
+
+    #[test]
+    fn test_competition_resume_with_weekly_schedule() {
+        // thursday 2023-09-21 21:12:59 utc
+        let mut now = 1695330779;
+        let friday = 1695398400; // 2023-09-22 16:00:00 utc
+        let sweepstakes = &mut Competition::default();
+        sweepstakes.round_duration = 604800;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.round_schedule.mode = RoundScheduleMode::Weekly;
+        sweepstakes.round_schedule.weekday_mask = 1 << 4; // fridays 16:00 utc
+        sweepstakes.round_schedule.hour = 16;
+        sweepstakes.next_round_expiry_ts = friday;
+
+        // paused and resumed before the round end: the round still ends on friday
+        sweepstakes.pause(now).unwrap();
+        now += 60 * 60;
+        sweepstakes.resume(now).unwrap();
+        assert_eq!(sweepstakes.next_round_expiry_ts, friday);
+
+        // paused across the round end: the round runs to the following friday, not
+        // friday + paused duration
+        sweepstakes.pause(now).unwrap();
+        now = friday + 2 * SECONDS_PER_DAY;
+        sweepstakes.resume(now).unwrap();
+        assert_eq!(
+            sweepstakes.next_round_expiry_ts,
+            friday + 7 * SECONDS_PER_DAY
+        );
+
+        // a skipped friday is still skipped
+        sweepstakes
+            .round_schedule
+            .push(friday + 14 * SECONDS_PER_DAY)
+            .unwrap();
+        sweepstakes.pause(now).unwrap();
+        now = friday + 8 * SECONDS_PER_DAY;
+        sweepstakes.resume(now).unwrap();
+        assert_eq!(
+            sweepstakes.next_round_expiry_ts,
+            friday + 21 * SECONDS_PER_DAY
+        );
+        assert_eq!(sweepstakes.round_schedule.len, 0);
+    }
+
+    #[test]
+    fn test_competition_prize_rebases() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+
+        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        sweepstakes.number_of_competitors = 2;
+        let comp1 = &mut Competitor::default();
+        comp1.claim_entry().unwrap();
+
+        let comp2 = &mut Competitor::default();
+        comp2.claim_entry().unwrap();
+        comp2.bonus_score += 2;
+
+        let us: &UserStats = &UserStats::default();
+
+        assert!(sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .is_err());
+        assert!(sweepstakes
+            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
+            .is_err());
+
+        now += 60;
+
         let mut spot_market = SpotMarket::default();
         spot_market.decimals = 6;
         spot_market.insurance_fund.total_shares = 1100 * PERCENTAGE_PRECISION;
@@ -778,7 +1404,8 @@ mod competition_fcn {
                 vault_balance,
                 now,
                 Pubkey::default(),
-                Pubkey::default()
+                Pubkey::default(),
+                None,
             )
             .is_err());
 
@@ -793,6 +1420,7 @@ mod competition_fcn {
                 now,
                 Pubkey::default(),
                 Pubkey::default(),
+                None,
             )
             .unwrap();
         assert_eq!(
@@ -811,7 +1439,8 @@ mod competition_fcn {
                 vault_balance,
                 now,
                 Pubkey::default(),
-                Pubkey::default()
+                Pubkey::default(),
+                None,
             )
             .is_err()); // cannot settle twice
         assert_eq!(
@@ -834,6 +1463,7 @@ mod competition_fcn {
             number_of_competitors: 2,
             number_of_competitors_settled: 2,
             total_score_settled: 4,
+            winning_score_settled: 3,
             round_duration: 60,
             prize_base: 5,
             prize_amount: 199,
@@ -991,6 +1621,7 @@ mod competition_fcn {
                 now,
                 Pubkey::default(),
                 Pubkey::default(),
+                None,
             )
             .unwrap();
         assert_eq!(sweepstakes.round_number, 0);
@@ -1005,6 +1636,7 @@ mod competition_fcn {
             number_of_competitors: 1,
             number_of_competitors_settled: 1,
             total_score_settled: 1,
+            winning_score_settled: 1,
             round_duration: 60,
             prize_base: 1,
             prize_amount: 6962025,
@@ -1022,10 +1654,12 @@ mod competition_fcn {
 
         assert_eq!(expected_sweepstakes, sweepstakes);
         sweepstakes.reset_round(now).unwrap();
+        let round_1_start_ts = now;
         let expected_sweepstakes = &mut Competition {
             number_of_winners: 1,
             round_number: 1,
             status: CompetitionRoundStatus::Active,
+            round_start_ts: round_1_start_ts,
             next_round_expiry_ts: 168000000 + 101 * 60,
             number_of_competitors: 1,
             total_score_settled: 0,
@@ -1087,6 +1721,7 @@ mod competition_fcn {
                 now,
                 Pubkey::default(),
                 Pubkey::default(),
+                None,
             )
             .unwrap();
         assert_eq!(sweepstakes.round_number, 1);
@@ -1095,6 +1730,7 @@ mod competition_fcn {
         let expected_sweepstakes2 = &mut Competition {
             round_number: 1,
             status: CompetitionRoundStatus::WinnerSettlementComplete,
+            round_start_ts: round_1_start_ts,
             next_round_expiry_ts: 168006060,
             // next_round_expiry_ts: 1113896280,
             number_of_competitors: 1,
@@ -1102,6 +1738,7 @@ mod competition_fcn {
             number_of_winners: 1,
             number_of_winners_settled: 1,
             total_score_settled: 1,
+            winning_score_settled: 1,
             round_duration: 60,
             prize_base: 1,
             prize_amount: 546016564,
@@ -1123,6 +1760,7 @@ mod competition_fcn {
 
         expected_sweepstakes2.status = CompetitionRoundStatus::Active;
         expected_sweepstakes2.round_number = 2;
+        expected_sweepstakes2.round_start_ts = now;
         expected_sweepstakes2.number_of_competitors_settled = 0;
         expected_sweepstakes2.total_score_settled = 0;
         expected_sweepstakes2.next_round_expiry_ts = 1113896280;
@@ -1132,6 +1770,7 @@ mod competition_fcn {
         expected_sweepstakes2.prize_randomness_max = 0;
         expected_sweepstakes2.prize_amount_settled = 0;
         expected_sweepstakes2.number_of_winners_settled = 0;
+        expected_sweepstakes2.winning_score_settled = 0;
         expected_sweepstakes2.sponsor_info.min_sponsor_amount = 552978589;
         assert_eq!(expected_sweepstakes2, sweepstakes);
         // todo do another assert for
@@ -1260,7 +1899,8 @@ mod competition_fcn {
                 vault_balance,
                 now,
                 Pubkey::default(),
-                Pubkey::default()
+                Pubkey::default(),
+                None,
             )
             .is_err());
 
@@ -1275,6 +1915,7 @@ mod competition_fcn {
                 now,
                 Pubkey::default(),
                 Pubkey::default(),
+                None,
             )
             .unwrap();
         assert_eq!(
@@ -1293,7 +1934,8 @@ mod competition_fcn {
                 vault_balance,
                 now,
                 Pubkey::default(),
-                Pubkey::default()
+                Pubkey::default(),
+                None,
             )
             .is_err()); // cannot settle twice
         assert_eq!(
@@ -1316,6 +1958,7 @@ mod competition_fcn {
             number_of_competitors: 2,
             number_of_competitors_settled: 2,
             total_score_settled: 4,
+            winning_score_settled: 3,
             round_duration: 60,
             prize_base: 5,
             prize_amount: 199,
@@ -1338,6 +1981,7 @@ mod competition_fcn {
 
         expected_sweepstakes.status = CompetitionRoundStatus::Active;
         expected_sweepstakes.round_number += 1;
+        expected_sweepstakes.round_start_ts = now;
         expected_sweepstakes.number_of_competitors_settled = 0;
         expected_sweepstakes.total_score_settled = 0;
         expected_sweepstakes.next_round_expiry_ts += 60;
@@ -1346,6 +1990,7 @@ mod competition_fcn {
         expected_sweepstakes.prize_randomness = 0;
         expected_sweepstakes.prize_randomness_max = 0;
         expected_sweepstakes.number_of_winners_settled = 0;
+        expected_sweepstakes.winning_score_settled = 0;
         expected_sweepstakes.prize_amount_settled = 0;
         expected_sweepstakes.sponsor_info.min_sponsor_amount += 199;
         assert_eq!(expected_sweepstakes, sweepstakes);
@@ -1469,7 +2114,8 @@ mod competition_fcn {
                 vault_balance,
                 now,
                 Pubkey::default(),
-                Pubkey::default()
+                Pubkey::default(),
+                None,
             )
             .is_err());
         assert_eq!(comp2.unclaimed_winnings as u128, 199);
@@ -1482,6 +2128,7 @@ mod competition_fcn {
                 now,
                 Pubkey::default(),
                 Pubkey::default(),
+                None,
             )
             .unwrap();
         assert_eq!(sweepstakes.prize_amount, sweepstakes.prize_amount_settled);
@@ -1617,7 +2264,8 @@ mod competition_fcn {
                 vault_balance,
                 now,
                 Pubkey::default(),
-                Pubkey::default()
+                Pubkey::default(),
+                None,
             )
             .is_err());
         sweepstakes
@@ -1628,6 +2276,7 @@ mod competition_fcn {
                 now,
                 Pubkey::default(),
                 Pubkey::default(),
+                None,
             )
             .unwrap();
 
@@ -1833,274 +2482,1965 @@ mod competition_fcn {
         let last_round_score_after = comp1.calculate_round_score(&us).unwrap();
         assert_eq!(last_round_score_after, comp1.bonus_score);
     }
-}
-
-mod competition_multiple_winners {
-    use crate::state::{Competition, CompetitionRoundStatus, Competitor};
-    use crate::utils::get_test_sample_draw;
-    use anchor_lang::prelude::Pubkey;
-    use drift::{
-        math::constants::{PERCENTAGE_PRECISION_U64, QUOTE_PRECISION},
-        state::{spot_market::SpotMarket, user::UserStats},
-    };
 
     #[test]
-    fn test_competition_2_winners_settlement() {
+    fn test_close_competitor() {
         let mut now = 168000000;
         let sweepstakes = &mut Competition::default();
 
         sweepstakes.next_round_expiry_ts = now + 60;
         sweepstakes.round_duration = 60;
-        sweepstakes.number_of_winners = 2;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.competition_expiry_ts = now + 600;
+        sweepstakes.number_of_competitors = 3;
 
-        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
-        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
-        sweepstakes.number_of_competitors = 2;
         let comp1 = &mut Competitor::default();
-        comp1.claim_entry().unwrap();
-
         let comp2 = &mut Competitor::default();
-        comp2.claim_entry().unwrap();
-        comp2.bonus_score += 2;
-
-        let us: &UserStats = &UserStats::default();
-        let mut spot_market = SpotMarket::default();
-        spot_market.decimals = 6;
-        spot_market.insurance_fund.total_shares = 100;
-        spot_market.insurance_fund.user_shares = 0;
+        let comp3 = &mut Competitor::default();
+        comp2.unclaimed_winnings = 50;
 
-        // 10k max
-        let vault_balance: u64 = (10000 * QUOTE_PRECISION) as u64;
+        let us: UserStats = UserStats::default();
 
-        assert!(sweepstakes
-            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
-            .is_err());
-        assert!(sweepstakes
-            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
-            .is_err());
+        comp1.close(sweepstakes, now).unwrap();
+        assert_eq!(sweepstakes.number_of_competitors, 2);
 
-        now += 60;
+        // unclaimed winnings must be claimed first
+        assert!(comp2.close(sweepstakes, now).is_err());
+        assert_eq!(sweepstakes.number_of_competitors, 2);
 
-        sweepstakes
-            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
-            .unwrap();
-        sweepstakes
-            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
-            .unwrap();
-        sweepstakes
-            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+        // disqualified competitors were already removed from the count
+        comp3
+            .update_status(sweepstakes, &us, CompetitorStatus::Disqualified, now)
             .unwrap();
+        assert_eq!(sweepstakes.number_of_competitors, 1);
 
-        sweepstakes.prize_randomness =
-            get_test_sample_draw(0, sweepstakes.prize_randomness_max).unwrap();
-        sweepstakes.winner_randomness =
-            get_test_sample_draw(1, sweepstakes.total_score_settled).unwrap();
-
-        sweepstakes
-            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
-            .unwrap();
+        // not while the round is mid-settlement
+        now += 60;
+        sweepstakes.number_of_competitors_settled = 1;
+        assert!(comp3.close(sweepstakes, now).is_err());
+        sweepstakes.number_of_competitors_settled = 0;
 
-        assert_eq!(
-            sweepstakes.status,
-            CompetitionRoundStatus::WinnerAndPrizeRandomnessComplete
-        );
-        sweepstakes
-            .resolve_prize_amount(&spot_market, vault_balance)
-            .unwrap();
-        assert_eq!(
-            sweepstakes.status,
-            CompetitionRoundStatus::WinnerAndPrizeRandomnessComplete
-        );
-        assert_eq!(sweepstakes.winner_randomness, 2);
-        sweepstakes
-            .settle_winner(
-                comp2,
-                &spot_market,
-                vault_balance,
-                now,
-                Pubkey::default(),
-                Pubkey::default(),
-            )
-            .unwrap();
-        assert_eq!(
-            sweepstakes.status,
-            CompetitionRoundStatus::WinnerAndPrizeRandomnessComplete
-        );
-        assert_eq!(sweepstakes.winner_randomness, 1);
+        comp3.close(sweepstakes, now).unwrap();
+        assert_eq!(sweepstakes.number_of_competitors, 1);
 
-        sweepstakes.winner_randomness = 1; // set so other comp wins
-        sweepstakes
-            .settle_winner(
-                comp1,
-                &spot_market,
-                vault_balance,
-                now,
-                Pubkey::default(),
-                Pubkey::default(),
-            )
-            .unwrap();
+        // forfeited winnings don't block closing after the claim window
+        now = sweepstakes.competition_expiry_ts;
+        sweepstakes.expire(now).unwrap();
+        assert!(comp2.close(sweepstakes, now).is_err());
 
-        assert_eq!(
-            sweepstakes.status,
-            CompetitionRoundStatus::WinnerSettlementComplete
-        );
+        now = sweepstakes.calculate_claim_window_end_ts().unwrap();
+        comp2.close(sweepstakes, now).unwrap();
+        assert_eq!(sweepstakes.number_of_competitors, 0);
     }
 
     #[test]
-    fn test_big_competition_1001_winners_settlement() {
+    fn test_close_competition() {
         let mut now = 168000000;
         let sweepstakes = &mut Competition::default();
 
         sweepstakes.next_round_expiry_ts = now + 60;
         sweepstakes.round_duration = 60;
-        sweepstakes.number_of_winners = 1001;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.competition_expiry_ts = now + 60;
+        sweepstakes.number_of_competitors = 1;
+        sweepstakes.outstanding_unclaimed_winnings = 10;
 
-        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
-        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        assert!(sweepstakes.validate_closable().is_err()); // not expired
 
-        const N_COMPS: usize = 1001;
-        let mut comps: Vec<Competitor> = Vec::with_capacity(N_COMPS);
-        while comps.len() < N_COMPS {
-            let mut c = Competitor::default();
-            c.bonus_score += 100000003522;
-            comps.push(c);
-        }
-        sweepstakes.number_of_competitors = N_COMPS as u128;
+        now += 60;
+        sweepstakes.expire(now).unwrap();
+        assert!(sweepstakes.validate_closable().is_err()); // competitors remaining
 
-        let us: &UserStats = &UserStats::default();
-        let mut spot_market = SpotMarket::default();
-        spot_market.decimals = 6;
-        spot_market.insurance_fund.total_shares = 1000000000;
-        spot_market.insurance_fund.user_shares = 0;
+        let comp1 = &mut Competitor::default();
+        comp1.close(sweepstakes, now).unwrap();
+        assert!(sweepstakes.validate_closable().is_err()); // winnings outstanding
 
-        // 10k max
-        let vault_balance: u64 = (55000 * QUOTE_PRECISION) as u64;
+        now = sweepstakes.calculate_claim_window_end_ts().unwrap();
+        sweepstakes.forfeit_unclaimed_winnings(now).unwrap();
+        sweepstakes.validate_closable().unwrap();
+    }
 
-        now += 60;
-        for c in &mut comps {
-            sweepstakes
-                .settle_competitor(c, us, now, Pubkey::default(), Pubkey::default())
-                .unwrap();
-        }
+    #[test]
+    fn test_sponsor_transfer() {
+        let sweepstakes = &mut Competition::default();
 
-        sweepstakes
-            .request_winner_and_prize_randomness(&spot_market, vault_balance)
-            .unwrap();
+        let sponsor = Pubkey::new_unique();
+        let new_sponsor = Pubkey::new_unique();
+        sweepstakes.sponsor_info.sponsor = sponsor;
 
-        sweepstakes.prize_randomness =
-            get_test_sample_draw(0, sweepstakes.prize_randomness_max).unwrap();
-        sweepstakes.winner_randomness =
-            get_test_sample_draw(1, sweepstakes.total_score_settled).unwrap();
-        let max_prize = sweepstakes
-            .calculate_sponsor_max_prize(&spot_market, vault_balance)
-            .unwrap();
-        assert_eq!(max_prize, 27500000000);
+        assert!(sweepstakes.accept_sponsor(new_sponsor).is_err()); // nothing proposed
+        assert!(sweepstakes.accept_sponsor(Pubkey::default()).is_err());
+        assert!(sweepstakes.propose_sponsor(sponsor).is_err());
 
-        assert_eq!(
-            sweepstakes
-                .calculate_prize_amount(&spot_market, vault_balance)
-                .unwrap(),
-            (20000000, 31, 0) // 1.1k
-        );
+        sweepstakes.propose_sponsor(new_sponsor).unwrap();
+        assert_eq!(sweepstakes.pending_sponsor, new_sponsor);
+        assert_eq!(sweepstakes.sponsor_info.sponsor, sponsor);
 
-        sweepstakes
-            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
-            .unwrap();
+        assert!(sweepstakes.accept_sponsor(Pubkey::new_unique()).is_err());
 
-        assert_eq!(sweepstakes.prize_amount, 20000000);
-        assert_eq!(sweepstakes.prize_base, 0);
+        // cancel and re-propose
+        sweepstakes.propose_sponsor(Pubkey::default()).unwrap();
+        assert!(sweepstakes.accept_sponsor(new_sponsor).is_err());
+        sweepstakes.propose_sponsor(new_sponsor).unwrap();
 
-        assert_eq!(sweepstakes.prize_randomness, 19);
-        assert_eq!(sweepstakes.prize_randomness_max, 39);
-        assert_eq!(sweepstakes.number_of_winners, 1001);
-        assert_eq!(sweepstakes.number_of_winners_settled, 0);
+        sweepstakes.accept_sponsor(new_sponsor).unwrap();
+        assert_eq!(sweepstakes.sponsor_info.sponsor, new_sponsor);
+        assert_eq!(sweepstakes.pending_sponsor, Pubkey::default());
+        assert!(sweepstakes.accept_sponsor(new_sponsor).is_err());
+    }
 
-        let mut num_full_cycles = 0;
-        let mut winnings_bucket_arr: [u128; N_COMPS] = [0; N_COMPS];
-        while sweepstakes.number_of_winners_settled != sweepstakes.number_of_winners {
-            for (index, c) in comps.iter_mut().enumerate() {
-                let winner_prize_amount = sweepstakes.calculate_next_winner_prize_amount().unwrap();
-                let res = sweepstakes.settle_winner(
-                    c,
-                    &spot_market,
-                    vault_balance,
-                    now,
-                    Pubkey::default(),
-                    Pubkey::default(),
-                );
-                if !res.is_err() {
-                    winnings_bucket_arr[index] += winner_prize_amount;
-                }
-            }
-            num_full_cycles += 1;
-        }
+    #[test]
+    fn test_competition_roles() {
+        let sweepstakes = &mut Competition::default();
 
-        let res: [u128; 1001] = [
-            6000, 3000, 3000, 0, 3000, 0, 3000, 3000, 0, 12000, 6000, 0, 0, 3000, 3000, 3000, 6000,
-            9000, 3000, 0, 0, 0, 3000, 3000, 0, 0, 3000, 0, 6000, 3000, 0, 3000, 3000, 0, 3000,
-            3000, 3000, 0, 3000, 3000, 6000, 0, 0, 0, 6000, 3000, 6000, 0, 0, 3000, 6000, 3000, 0,
-            12000, 0, 0, 6000, 3000, 3000, 6000, 3000, 0, 6000, 0, 6000, 3000, 3000, 0, 6000, 3000,
-            9000, 0, 3000, 0, 3000, 0, 0, 0, 3000, 0, 0, 6000, 3000, 6000, 3000, 9000, 6000, 0,
-            3000, 0, 0, 6000, 6000, 6000, 6000, 6000, 0, 12000, 0, 6000, 0, 0, 0, 3000, 0, 6000,
-            3000, 0, 6000, 3000, 3000, 3000, 9000, 6000, 6000, 9000, 6000, 0, 0, 9000, 3000, 3000,
-            3000, 3000, 3000, 0, 0, 0, 3000, 3000, 9000, 3000, 0, 6000, 0, 0, 3000, 0, 0, 3000, 0,
-            0, 3000, 3000, 0, 0, 3000, 0, 9000, 6000, 3000, 0, 6000, 0, 3000, 0, 6000, 6000, 0, 0,
-            3000, 0, 3000, 9000, 3000, 3000, 3000, 0, 9000, 0, 0, 3000, 6000, 0, 0, 9000, 6000, 0,
-            9000, 15000, 3000, 3000, 3000, 6000, 3000, 0, 3000, 6000, 3000, 0, 0, 3000, 12000,
-            3000, 3000, 0, 0, 3000, 0, 3000, 0, 6000, 3000, 3000, 0, 3000, 0, 3000, 3000, 3000,
-            3000, 6000, 6000, 9000, 0, 3000, 3000, 0, 0, 3000, 9000, 3000, 6000, 0, 9000, 0, 0, 0,
-            0, 3000, 9000, 3000, 0, 0, 6000, 3000, 6000, 0, 3000, 6000, 6000, 0, 0, 3000, 0, 3000,
-            9000, 3000, 0, 3000, 0, 0, 6000, 3000, 0, 9000, 9000, 0, 3000, 3000, 0, 3000, 0, 6000,
-            9000, 0, 3000, 6000, 6000, 12000, 0, 9000, 6000, 0, 0, 0, 6000, 0, 0, 0, 3000, 6000, 0,
-            3000, 0, 0, 3000, 12000, 3000, 6000, 3000, 12000, 6000, 0, 9000, 6000, 6000, 6000,
-            3000, 0, 0, 9000, 6000, 3000, 3000, 6000, 3000, 0, 3000, 3000, 0, 6000, 0, 6000, 6000,
-            0, 9000, 3000, 3000, 0, 3000, 0, 6000, 6000, 3000, 0, 0, 6000, 0, 3000, 0, 0, 6000,
-            3000, 0, 0, 9000, 6000, 0, 3000, 0, 6000, 3000, 0, 0, 3000, 9000, 9000, 3000, 0, 6000,
-            0, 3000, 6000, 0, 0, 0, 0, 3000, 3000, 0, 6000, 3000, 0, 0, 6000, 0, 3000, 0, 0, 0,
-            3000, 3000, 0, 0, 3000, 6000, 6000, 3000, 6000, 0, 3000, 0, 3000, 3000, 6000, 3000, 0,
-            3000, 0, 9000, 3000, 6000, 3000, 6000, 0, 0, 0, 3000, 3000, 0, 3000, 3000, 6000, 6000,
-            9000, 0, 3000, 3000, 0, 9000, 6000, 6000, 6000, 0, 3000, 3000, 0, 0, 0, 6000, 0, 3000,
-            0, 3000, 3000, 3000, 0, 6000, 3000, 0, 6000, 0, 0, 3000, 0, 9000, 0, 6000, 0, 0, 3000,
-            0, 6000, 0, 6000, 0, 6000, 9000, 0, 6000, 0, 3000, 3000, 0, 3000, 0, 6000, 3000, 0, 0,
-            0, 0, 0, 3000, 0, 3000, 3000, 3000, 6000, 0, 6000, 0, 6000, 3000, 0, 0, 0, 0, 3000,
-            6000, 6000, 0, 6000, 12000, 0, 3000, 0, 3000, 0, 0, 3000, 3000, 6000, 0, 3000, 6000, 0,
-            0, 9000, 10003000, 9000, 0, 3000, 6000, 6000, 0, 3000, 3000, 0, 3000, 6000, 3000, 3000,
-            0, 0, 4003000, 6000, 9000, 12000, 6000, 0, 3000, 0, 0, 0, 3000, 6000, 6000, 6000, 0,
-            3000, 9000, 6000, 3000, 6000, 6000, 3000, 3000, 3000, 6000, 0, 3000, 9000, 6000, 6000,
-            0, 3000, 0, 3000, 0, 6000, 3000, 3000, 6000, 6000, 6000, 9000, 0, 3000, 0, 6000, 0, 0,
-            3000, 3000, 3000, 9000, 0, 0, 6000, 3000, 3000, 3000, 3000, 0, 6000, 0, 0, 3000, 0,
-            6000, 3000, 3000, 0, 3000, 3000, 6000, 6000, 0, 6000, 0, 0, 0, 9000, 9000, 3000, 3000,
-            3000, 12000, 3000, 12000, 3006000, 3000, 3000, 0, 3000, 9000, 0, 0, 3000, 0, 6000,
-            3000, 3000, 0, 3000, 0, 6000, 0, 3000, 3000, 0, 3000, 0, 6000, 15000, 0, 6000, 0, 0, 0,
-            3000, 0, 9000, 3000, 0, 3000, 0, 6000, 0, 0, 0, 0, 0, 0, 3000, 9000, 3000, 9000, 3000,
-            3000, 0, 3000, 6000, 3000, 0, 0, 0, 0, 3000, 0, 0, 3000, 12000, 6000, 3000, 0, 3000,
-            3000, 6000, 0, 6000, 0, 0, 6000, 0, 0, 3000, 3000, 0, 3000, 6000, 3000, 0, 3000, 3000,
-            0, 3000, 3000, 0, 0, 9000, 3000, 0, 3000, 3000, 0, 0, 3000, 6000, 0, 6000, 0, 3000, 0,
-            0, 0, 6000, 3000, 0, 3000, 3000, 6000, 0, 3000, 9000, 6000, 3000, 0, 0, 3000, 6000,
-            3000, 3000, 0, 0, 3000, 3000, 3000, 9000, 6000, 3000, 0, 0, 9000, 3000, 3000, 0, 3000,
-            3000, 0, 6000, 6000, 0, 3000, 3000, 9000, 0, 0, 0, 0, 0, 6000, 3000, 3000, 12000, 3000,
-            3000, 3000, 0, 0, 0, 3000, 9000, 0, 0, 0, 3000, 0, 3000, 3000, 9000, 9000, 0, 3000, 0,
-            6000, 3000, 3000, 3000, 0, 0, 0, 3000, 9000, 0, 0, 3000, 3000, 9000, 0, 6000, 3000,
-            6000, 0, 6000, 0, 0, 3000, 3000, 3000, 3000, 0, 0, 0, 3000, 3000, 0, 0, 0, 3000, 6000,
-            0, 9000, 3000, 6000, 6000, 0, 6000, 9000, 9000, 0, 0, 3000, 0, 6000, 6000, 3000, 6000,
-            3000, 0, 9000, 6000, 0, 3000, 0, 3000, 0, 0, 0, 9000, 3000, 3000, 3000, 3000, 3000, 0,
-            0, 3000, 0, 3000, 3000, 6000, 0, 3000, 3000, 0, 3000, 6000, 0, 0, 6000, 3000, 3000,
-            9000, 0, 0, 3000, 3000, 0, 3000, 0, 3000, 0, 6000, 3000, 0, 9000, 3000, 3000, 0, 0,
-            6000, 6000, 0, 9000, 0, 0, 6000, 0, 3000, 6000, 3000, 0, 3000, 3000, 3000, 6000, 0,
-            3000, 0, 0, 0, 3000, 0, 0, 0, 3000, 3000, 6000, 3000, 9000, 9000, 3000, 9000, 9000, 0,
-            0, 6000, 3000, 15000, 12000, 0, 3000, 3000, 6000, 6000, 3000, 0, 3000, 0, 3000, 0, 0,
-            3000, 0, 6000, 9000, 9000, 6000, 0, 3000, 6000, 0, 0, 3000, 0, 6000, 0, 6000, 0, 0, 0,
-            0, 0, 3000, 6000, 3000, 9000, 0, 6000, 0, 0, 0, 0, 0, 9000, 0, 6000, 6000, 3000, 3000,
-            0, 0, 6000, 3000, 0, 9000, 0, 3000, 6000, 6000, 9000, 0, 3000, 3000, 0, 0, 0, 0, 6000,
-            3000, 0, 3000, 0, 9000, 0, 0, 3000, 3000,
-        ];
-        assert_eq!(winnings_bucket_arr, res);
-        assert_eq!(res.iter().sum::<u128>(), 19994000);
-        assert_eq!(num_full_cycles, 503);
-        assert!(sweepstakes.prize_amount > sweepstakes.prize_amount_settled);
-        assert_eq!(
-            sweepstakes.prize_amount - sweepstakes.prize_amount_settled,
-            6000
-        ); // 0.03150400031% of reward was dust
+        let sponsor = Pubkey::new_unique();
+        sweepstakes.sponsor_info.sponsor = sponsor;
+
+        // unset roles fall back to sponsor
+        assert_eq!(sweepstakes.get_operator(), sponsor);
+        assert_eq!(sweepstakes.get_moderator(), sponsor);
+
+        let operator = Pubkey::new_unique();
+        let moderator = Pubkey::new_unique();
+        sweepstakes.operator = operator;
+        sweepstakes.moderator = moderator;
+
+        assert_eq!(sweepstakes.get_operator(), operator);
+        assert_eq!(sweepstakes.get_moderator(), moderator);
+
+        // roles follow a sponsor transfer only when unset
+        let new_sponsor = Pubkey::new_unique();
+        sweepstakes.moderator = Pubkey::default();
+        sweepstakes.propose_sponsor(new_sponsor).unwrap();
+        sweepstakes.accept_sponsor(new_sponsor).unwrap();
+        assert_eq!(sweepstakes.get_operator(), operator);
+        assert_eq!(sweepstakes.get_moderator(), new_sponsor);
+    }
+
+    #[test]
+    fn test_keeper_allowlist_and_rewards() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.number_of_competitors = 1;
+        sweepstakes.keeper_reward = 5000;
+
+        let mut keeper = CompetitionKeeper::default();
+
+        // anyone can crank without the allowlist
+        sweepstakes.validate_keeper_is_allowed(false).unwrap();
+        sweepstakes.validate_keeper_is_allowed(true).unwrap();
+        sweepstakes.reward_keeper(None, now).unwrap();
+
+        sweepstakes.keeper_allowlist_enabled = 1;
+        assert!(sweepstakes.validate_keeper_is_allowed(false).is_err());
+        sweepstakes.validate_keeper_is_allowed(true).unwrap();
+
+        now += 60;
+        sweepstakes.reward_keeper(Some(&mut keeper), now).unwrap();
+        sweepstakes.reward_keeper(Some(&mut keeper), now).unwrap();
+        assert_eq!(keeper.number_of_calls, 2);
+        assert_eq!(keeper.unclaimed_reward, 10000);
+        assert_eq!(keeper.total_reward, 10000);
+        assert_eq!(keeper.last_call_ts, now);
+
+        // partial payout when the vault is short
+        assert_eq!(keeper.claim_reward(7500).unwrap(), 7500);
+        assert_eq!(keeper.unclaimed_reward, 2500);
+        assert_eq!(keeper.claim_reward(0).unwrap(), 0);
+        assert_eq!(keeper.claim_reward(u64::MAX).unwrap(), 2500);
+        assert_eq!(keeper.unclaimed_reward, 0);
+        assert_eq!(keeper.total_reward, 10000);
+    }
+
+    #[test]
+    fn test_competition_registry() {
+        let mut registry = CompetitionRegistry::default();
+        assert!(registry.add_page(1).is_err());
+        registry.add_page(0).unwrap();
+        registry.add_page(1).unwrap();
+        assert_eq!(registry.number_of_pages, 2);
+
+        let mut page = Box::<CompetitionRegistryPage>::default();
+        let competitions: Vec<Pubkey> = (0..COMPETITION_REGISTRY_PAGE_SIZE)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+
+        for competition in competitions.iter() {
+            page.add(*competition).unwrap();
+        }
+        assert_eq!(
+            page.number_of_competitions as usize,
+            COMPETITION_REGISTRY_PAGE_SIZE
+        );
+
+        // full / duplicate, more go on the next page
+        assert_eq!(
+            page.add(Pubkey::new_unique()),
+            Err(ErrorCode::CompetitionRegistryUpdateInvalid)
+        );
+        page.remove(&competitions[3]).unwrap();
+        assert_eq!(
+            page.add(competitions[0]),
+            Err(ErrorCode::CompetitionRegistryUpdateInvalid)
+        );
+        assert_eq!(
+            page.remove(&competitions[3]),
+            Err(ErrorCode::CompetitionRegistryUpdateInvalid)
+        );
+
+        assert_eq!(
+            page.number_of_competitions as usize,
+            COMPETITION_REGISTRY_PAGE_SIZE - 1
+        );
+        assert!(!page.get_competitions().contains(&competitions[3]));
+        // last entry was swapped into the freed slot
+        assert_eq!(
+            page.get_competitions()[3],
+            competitions[COMPETITION_REGISTRY_PAGE_SIZE - 1]
+        );
+
+        // the competition remembers its page, closing it has to delist it from there
+        let competition = &mut Competition::default();
+        assert!(!competition.is_registered());
+        assert_eq!(
+            competition.deregister(0),
+            Err(ErrorCode::CompetitionRegistryUpdateInvalid)
+        );
+        competition.register(1).unwrap();
+        assert!(competition.is_registered());
+        assert_eq!(
+            competition.register(0),
+            Err(ErrorCode::CompetitionRegistryUpdateInvalid)
+        );
+        assert_eq!(
+            competition.validate_registry_page(0),
+            Err(ErrorCode::CompetitionRegistryUpdateInvalid)
+        );
+        competition.validate_registry_page(1).unwrap();
+        competition.deregister(1).unwrap();
+        assert!(!competition.is_registered());
+        competition.register(0).unwrap();
+    }
+
+    #[test]
+    fn test_ticket_index_page() {
+        let sweepstakes = &mut Competition::default();
+        sweepstakes.ticket_index_enabled = 1;
+
+        let mut page_0 = TicketIndexPage::default();
+        let mut page_1 = TicketIndexPage::default();
+        page_1.page_number = 1;
+
+        // (min_draw, max_draw] in settlement order, incl zero entry competitors
+        let competitors: Vec<Pubkey> = (0..TICKET_INDEX_PAGE_SIZE + 2)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        let mut total_score_settled = 0_u128;
+
+        for (position, competitor_key) in competitors.iter().enumerate() {
+            let score = if position % 3 == 1 { 0 } else { 10 };
+            let competitor = Competitor {
+                min_draw: total_score_settled,
+                max_draw: total_score_settled + score,
+                ..Competitor::default()
+            };
+            total_score_settled += score;
+
+            let page = if position < TICKET_INDEX_PAGE_SIZE {
+                &mut page_0
+            } else {
+                &mut page_1
+            };
+
+            // page required when enabled
+            if position == 0 {
+                assert!(sweepstakes
+                    .index_ticket_range(None, 0, *competitor_key, &competitor)
+                    .is_err());
+            }
+
+            // only the next slot on the matching page can be written
+            assert!(sweepstakes
+                .index_ticket_range(
+                    Some(&mut *page),
+                    position as u128 + 1,
+                    *competitor_key,
+                    &competitor
+                )
+                .is_err());
+
+            sweepstakes
+                .index_ticket_range(Some(page), position as u128, *competitor_key, &competitor)
+                .unwrap();
+        }
+
+        assert_eq!(page_0.number_of_entries as usize, TICKET_INDEX_PAGE_SIZE);
+        assert_eq!(page_1.number_of_entries, 2);
+
+        // first ticket
+        assert_eq!(page_0.find_winner(0, 1).unwrap().competitor, competitors[0]);
+        // last ticket of competitor 0 is not competitor 1 (zero entries)
+        assert_eq!(
+            page_0.find_winner(0, 10).unwrap().competitor,
+            competitors[0]
+        );
+        assert_eq!(
+            page_0.find_winner(0, 11).unwrap().competitor,
+            competitors[2]
+        );
+
+        for winner_randomness in 1..=total_score_settled {
+            let page = if page_0.find_winner(0, winner_randomness).is_ok() {
+                &page_0
+            } else {
+                &page_1
+            };
+            let ticket_range = page.find_winner(0, winner_randomness).unwrap();
+            assert!(ticket_range.min_draw < winner_randomness);
+            assert!(ticket_range.max_draw >= winner_randomness);
+            assert_ne!(ticket_range.min_draw, ticket_range.max_draw);
+        }
+
+        assert!(page_0.find_winner(0, 0).is_err());
+        assert!(page_1.find_winner(0, 1).is_err());
+        assert!(page_1.find_winner(0, total_score_settled + 1).is_err());
+
+        // wrong round
+        assert!(page_0.find_winner(1, 1).is_err());
+
+        // a late page resolves on its own, the earlier pages aren't needed
+        let mut page_100 = TicketIndexPage {
+            page_number: 100,
+            ..TicketIndexPage::default()
+        };
+        let late_competitor = Competitor {
+            min_draw: 1_000_000,
+            max_draw: 1_000_010,
+            ..Competitor::default()
+        };
+        sweepstakes
+            .index_ticket_range(
+                Some(&mut page_100),
+                100 * TICKET_INDEX_PAGE_SIZE as u128,
+                competitors[1],
+                &late_competitor,
+            )
+            .unwrap();
+        assert_eq!(
+            page_100.find_winner(0, 1_000_001).unwrap().competitor,
+            competitors[1]
+        );
+        assert!(page_100.find_winner(0, 1_000_000).is_err());
+
+        // pages are reused next round starting from the first slot
+        let competitor = Competitor {
+            min_draw: 0,
+            max_draw: 5,
+            ..Competitor::default()
+        };
+        sweepstakes.round_number = 1;
+        assert!(sweepstakes
+            .index_ticket_range(Some(&mut page_1), 33, competitors[0], &competitor)
+            .is_err());
+        sweepstakes
+            .index_ticket_range(Some(&mut page_0), 0, competitors[0], &competitor)
+            .unwrap();
+        assert_eq!(page_0.round_number, 1);
+        assert_eq!(page_0.number_of_entries, 1);
+        assert_eq!(page_0.find_winner(1, 5).unwrap().competitor, competitors[0]);
+        assert!(page_0.find_winner(1, 6).is_err());
+
+        // no-op when disabled
+        sweepstakes.ticket_index_enabled = 0;
+        sweepstakes
+            .index_ticket_range(None, 0, competitors[0], &competitor)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_pending_round_params_applied_at_round_boundary() {
+        let now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.max_entries_per_competitor = 100;
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+
+        sweepstakes
+            .pending_round_params
+            .set_max_entries_per_competitor(0);
+        sweepstakes.pending_round_params.set_round_duration(120);
+        sweepstakes.pending_round_params.set_number_of_winners(3);
+        assert!(!sweepstakes.pending_round_params.is_empty());
+        assert_eq!(
+            sweepstakes.pending_round_params.get_min_sponsor_amount(),
+            None
+        );
+
+        // nothing changes mid-round
+        assert_eq!(sweepstakes.max_entries_per_competitor, 100);
+        assert_eq!(sweepstakes.round_duration, 60);
+        assert_eq!(sweepstakes.number_of_winners, 1);
+
+        // reset is refused until the round is settled, pending params stay queued
+        assert!(sweepstakes.reset_round(now + 60).is_err());
+        assert_eq!(sweepstakes.number_of_winners, 1);
+
+        sweepstakes.status = CompetitionRoundStatus::WinnerSettlementComplete;
+        sweepstakes.reset_round(now + 60).unwrap();
+
+        assert_eq!(sweepstakes.round_number, 1);
+        assert_eq!(sweepstakes.max_entries_per_competitor, 0);
+        assert_eq!(sweepstakes.round_duration, 120);
+        assert_eq!(sweepstakes.number_of_winners, 3);
+        assert_eq!(
+            sweepstakes.sponsor_info.max_sponsor_fraction,
+            PERCENTAGE_PRECISION_U64 / 2
+        );
+        assert!(sweepstakes.pending_round_params.is_empty());
+
+        // new round uses the new duration
+        assert_eq!(sweepstakes.next_round_expiry_ts, now + 60 + 120);
+    }
+
+    #[test]
+    fn test_pending_min_sponsor_amount_with_settled_prize() {
+        let now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.sponsor_info.min_sponsor_amount = 1000;
+        sweepstakes.prize_amount = 500;
+        sweepstakes.prize_amount_settled = 500;
+        sweepstakes
+            .pending_round_params
+            .set_min_sponsor_amount(2000);
+
+        sweepstakes.status = CompetitionRoundStatus::WinnerSettlementComplete;
+        sweepstakes.reset_round(now + 60).unwrap();
+
+        // the scheduled floor replaces the old one, this round's prize stays reserved on top
+        assert_eq!(sweepstakes.sponsor_info.min_sponsor_amount, 2500);
+        assert_eq!(sweepstakes.prize_amount_settled, 0);
+        assert!(sweepstakes.pending_round_params.is_empty());
+    }
+
+    #[test]
+    fn test_round_without_competitors_rolls_over() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.pending_round_params.set_number_of_winners(3);
+
+        // round still running
+        assert!(!sweepstakes.roll_over_round_without_winner(now).unwrap());
+        assert_eq!(sweepstakes.round_number, 0);
+
+        // nothing to draw from, randomness can't be requested
+        now += 60;
+        assert!(sweepstakes
+            .request_winner_and_prize_randomness(&SpotMarket::default(), 0)
+            .is_err());
+
+        assert!(sweepstakes.roll_over_round_without_winner(now).unwrap());
+        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
+        assert_eq!(sweepstakes.round_number, 1);
+        assert_eq!(sweepstakes.number_of_winners, 3);
+        assert!(sweepstakes.pending_round_params.is_empty());
+        assert_eq!(sweepstakes.next_round_expiry_ts, now + 60);
+
+        // rounds with competitors go through the draw
+        sweepstakes.number_of_competitors = 1;
+        now += 60;
+        assert!(!sweepstakes.roll_over_round_without_winner(now).unwrap());
+        assert_eq!(sweepstakes.round_number, 1);
+    }
+
+    #[test]
+    fn test_randomness_request_retry() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        // nothing outstanding
+        assert_eq!(
+            sweepstakes.retry_randomness_request(now),
+            Err(ErrorCode::InvalidStatusUpdateDetected)
+        );
+
+        sweepstakes.status = CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested;
+        sweepstakes.record_randomness_request(now, 100);
+        assert_eq!(
+            sweepstakes.get_randomness_request_timeout(),
+            DEFAULT_RANDOMNESS_REQUEST_TIMEOUT
+        );
+        assert_eq!(
+            sweepstakes.get_max_randomness_request_retries(),
+            DEFAULT_MAX_RANDOMNESS_REQUEST_RETRIES
+        );
+
+        sweepstakes.randomness_request_timeout = 60;
+        sweepstakes.max_randomness_request_retries = 2;
+
+        assert_eq!(
+            sweepstakes.retry_randomness_request(now + 59),
+            Err(ErrorCode::RandomnessRequestNotTimedOut)
+        );
+
+        for retry in 1..=2 {
+            now += 60;
+            sweepstakes.retry_randomness_request(now).unwrap();
+            sweepstakes.record_randomness_request(now, 100 + retry);
+            assert_eq!(sweepstakes.randomness_request_retries, retry as u8);
+            assert_eq!(sweepstakes.randomness_requested_ts, now);
+            assert_eq!(sweepstakes.randomness_requested_slot, 100 + retry);
+
+            // the timeout restarts with each attempt
+            assert_eq!(
+                sweepstakes.retry_randomness_request(now + 59),
+                Err(ErrorCode::RandomnessRequestNotTimedOut)
+            );
+        }
+
+        now += 60;
+        assert_eq!(
+            sweepstakes.retry_randomness_request(now),
+            Err(ErrorCode::RandomnessRequestRetriesExhausted)
+        );
+
+        sweepstakes.status = CompetitionRoundStatus::Paused;
+        assert_eq!(
+            sweepstakes.retry_randomness_request(now),
+            Err(ErrorCode::CompetitionPaused)
+        );
+    }
+
+    #[test]
+    fn test_settlement_deadline_forfeits_unsettled_competitors() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        sweepstakes.number_of_competitors = 2;
+
+        let comp1 = &mut Competitor::default();
+        comp1.claim_entry().unwrap();
+
+        // comp2's account can't be settled this round
+        let comp2 = &mut Competitor::default();
+        comp2.claim_entry().unwrap();
+
+        let us: &UserStats = &UserStats::default();
+        let mut spot_market = SpotMarket::default();
+        spot_market.decimals = 6;
+        spot_market.insurance_fund.total_shares = 100;
+        spot_market.insurance_fund.user_shares = 0;
+
+        let vault_balance: u64 = (10000 * QUOTE_PRECISION) as u64;
+
+        now += 60;
+        sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+
+        assert_eq!(
+            sweepstakes.get_settlement_grace_period(),
+            DEFAULT_SETTLEMENT_GRACE_PERIOD
+        );
+        sweepstakes.settlement_grace_period = 600;
+        assert_eq!(
+            sweepstakes.calculate_settlement_deadline_ts().unwrap(),
+            now + 600
+        );
+
+        // still within the grace period
+        assert_eq!(
+            sweepstakes
+                .forfeit_unsettled_competitors(now + 599)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            sweepstakes.request_winner_and_prize_randomness(&spot_market, vault_balance),
+            Err(ErrorCode::CompetitionRoundInSettlementPhase)
+        );
+
+        now += 600;
+        assert_eq!(sweepstakes.forfeit_unsettled_competitors(now).unwrap(), 1);
+        assert_eq!(sweepstakes.number_of_competitors_forfeited, 1);
+        assert_eq!(sweepstakes.number_of_competitors_settled, 1);
+        // only applied once
+        assert_eq!(sweepstakes.forfeit_unsettled_competitors(now).unwrap(), 0);
+
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        // settlement is closed for the round
+        assert!(sweepstakes
+            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
+            .is_err());
+
+        sweepstakes.prize_randomness =
+            get_test_sample_draw(0, sweepstakes.prize_randomness_max).unwrap();
+        sweepstakes.winner_randomness = 1;
+        sweepstakes
+            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+
+        // zero-entry for the round, can't be drawn
+        assert_eq!(
+            sweepstakes.validate_competitor_is_winner(comp2),
+            Err(ErrorCode::CompetitorHasWrongRoundNumber)
+        );
+
+        sweepstakes
+            .settle_winner(
+                comp1,
+                &spot_market,
+                vault_balance,
+                now,
+                Pubkey::default(),
+                Pubkey::default(),
+                None,
+            )
+            .unwrap();
+        sweepstakes.reset_round(now).unwrap();
+        assert_eq!(sweepstakes.round_number, 1);
+        assert_eq!(sweepstakes.number_of_competitors_forfeited, 0);
+        assert_eq!(comp2.competition_round_number, 0);
+
+        // caught up lazily on the next interaction
+        assert!(comp2.catch_up_round(sweepstakes).unwrap());
+        assert_eq!(comp2.competition_round_number, 1);
+        assert!(!comp2.catch_up_round(sweepstakes).unwrap());
+        comp2.competition_round_number = 0;
+
+        now = sweepstakes.next_round_expiry_ts;
+        comp1.unclaimed_winnings = 0;
+        sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+        sweepstakes
+            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+
+        // the free entry claimed before the missed round carried over
+        assert_eq!(comp2.competition_round_number, 2);
+        assert_eq!(comp2.max_draw - comp2.min_draw, 1);
+        assert_eq!(sweepstakes.number_of_competitors_settled, 2);
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_round_with_all_competitors_forfeited_rolls_over() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.number_of_competitors = 2;
+        sweepstakes.settlement_grace_period = 600;
+
+        now += 60;
+        // unsettled competitors block the rollover until they're forfeited
+        assert!(!sweepstakes.roll_over_round_without_winner(now).unwrap());
+
+        now += 600;
+        assert_eq!(sweepstakes.forfeit_unsettled_competitors(now).unwrap(), 2);
+        assert_eq!(sweepstakes.total_score_settled, 0);
+        assert_eq!(
+            sweepstakes.request_winner_and_prize_randomness(&SpotMarket::default(), 0),
+            Err(ErrorCode::InvalidRandomnessRequestParams)
+        );
+        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
+
+        assert!(sweepstakes.roll_over_round_without_winner(now).unwrap());
+        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
+        assert_eq!(sweepstakes.round_number, 1);
+        assert_eq!(sweepstakes.number_of_competitors, 2);
+        assert_eq!(sweepstakes.number_of_competitors_forfeited, 0);
+        assert!(sweepstakes.next_round_expiry_ts > now);
+    }
+
+    #[test]
+    fn test_commit_reveal_randomness() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        sweepstakes.number_of_competitors = 1;
+
+        let secret = [7_u8; 32];
+        let commitment = hashv(&[&secret[..]]).to_bytes();
+
+        // switchboard by default
+        assert_eq!(
+            sweepstakes.commit_randomness(commitment, now),
+            Err(ErrorCode::InvalidRandomnessProvider)
+        );
+        sweepstakes.randomness_provider = RandomnessProvider::CommitReveal;
+
+        assert_eq!(
+            sweepstakes.commit_randomness([0; 32], now),
+            Err(ErrorCode::InvalidRandomnessCommitment)
+        );
+        assert_eq!(
+            sweepstakes.validate_randomness_commitment(),
+            Err(ErrorCode::InvalidRandomnessCommitment)
+        );
+        sweepstakes.commit_randomness(commitment, now).unwrap();
+        sweepstakes.validate_randomness_commitment().unwrap();
+
+        let comp1 = &mut Competitor::default();
+        comp1.claim_entry().unwrap();
+
+        let us: &UserStats = &UserStats::default();
+        let mut spot_market = SpotMarket::default();
+        spot_market.decimals = 6;
+        spot_market.insurance_fund.total_shares = 100;
+        spot_market.insurance_fund.user_shares = 0;
+
+        let vault_balance: u64 = (10000 * QUOTE_PRECISION) as u64;
+
+        now += 60;
+        // too late to swap the secret once the round is over
+        assert!(sweepstakes.commit_randomness(commitment, now).is_err());
+
+        sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+
+        // nothing requested yet
+        assert_eq!(
+            sweepstakes.validate_randomness_reveal(&secret),
+            Err(ErrorCode::InvalidStatusUpdateDetected)
+        );
+
+        assert_eq!(
+            sweepstakes.pin_randomness_slot_hash(101, [1; 32], 101),
+            Err(ErrorCode::InvalidStatusUpdateDetected)
+        );
+
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        sweepstakes.record_randomness_request(now, 100);
+
+        assert_eq!(
+            sweepstakes.validate_randomness_reveal(&[8; 32]),
+            Err(ErrorCode::InvalidRandomnessReveal)
+        );
+        sweepstakes.validate_randomness_reveal(&secret).unwrap();
+
+        // only a slot after the request, pinned once
+        assert!(!sweepstakes.is_randomness_slot_hash_pinned());
+        assert_eq!(
+            sweepstakes.pin_randomness_slot_hash(100, [1; 32], 101),
+            Err(ErrorCode::SlotHashNotFound)
+        );
+        // only while the request slot is still in the sysvar
+        assert_eq!(
+            sweepstakes
+                .calculate_randomness_slot_hash_pin_deadline_slot()
+                .unwrap(),
+            612
+        );
+        assert!(!sweepstakes.is_randomness_slot_hash_pin_missed(612).unwrap());
+        assert!(sweepstakes.is_randomness_slot_hash_pin_missed(613).unwrap());
+        let mut too_late = *sweepstakes;
+        assert_eq!(
+            too_late.pin_randomness_slot_hash(101, [1; 32], 613),
+            Err(ErrorCode::SlotHashNotFound)
+        );
+        sweepstakes
+            .pin_randomness_slot_hash(101, [1; 32], 612)
+            .unwrap();
+        assert!(sweepstakes.is_randomness_slot_hash_pinned());
+        assert!(!sweepstakes.is_randomness_slot_hash_pin_missed(613).unwrap());
+        assert_eq!(sweepstakes.randomness_slot_hash_slot, 101);
+        assert_eq!(
+            sweepstakes.pin_randomness_slot_hash(102, [2; 32], 102),
+            Err(ErrorCode::SlotHashNotFound)
+        );
+
+        let slot_hash = sweepstakes.randomness_slot_hash;
+        assert_eq!(slot_hash, [1_u8; 32]);
+        let (randomness_hash, winner_randomness, prize_randomness) = sweepstakes
+            .derive_randomness(&[&secret, &slot_hash])
+            .unwrap();
+        // the pre-range bytes are the sha256 of the round and seeds
+        let round_number_bytes = sweepstakes.round_number.to_le_bytes();
+        assert_eq!(
+            randomness_hash,
+            hashv(&[&b"randomness"[..], &round_number_bytes, &secret, &slot_hash]).to_bytes()
+        );
+        assert_eq!(winner_randomness, 1);
+        assert!(prize_randomness <= sweepstakes.prize_randomness_max);
+
+        sweepstakes.winner_randomness = winner_randomness;
+        sweepstakes.prize_randomness = prize_randomness;
+        sweepstakes
+            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        sweepstakes
+            .settle_winner(
+                comp1,
+                &spot_market,
+                vault_balance,
+                now,
+                Pubkey::default(),
+                Pubkey::default(),
+                None,
+            )
+            .unwrap();
+        sweepstakes.reset_round(now).unwrap();
+
+        // a new round needs a new commitment
+        assert_eq!(
+            sweepstakes.validate_randomness_commitment(),
+            Err(ErrorCode::InvalidRandomnessCommitment)
+        );
+    }
+
+    #[test]
+    fn test_missed_randomness_reveal() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        sweepstakes.number_of_competitors = 1;
+        sweepstakes.randomness_request_timeout = 60;
+
+        sweepstakes
+            .update_randomness_provider(RandomnessProvider::CommitReveal)
+            .unwrap();
+        let secret = [7_u8; 32];
+        sweepstakes
+            .commit_randomness(hashv(&[&secret[..]]).to_bytes(), now)
+            .unwrap();
+
+        let comp1 = &mut Competitor::default();
+        comp1.claim_entry().unwrap();
+
+        let us: &UserStats = &UserStats::default();
+        let mut spot_market = SpotMarket::default();
+        spot_market.decimals = 6;
+        spot_market.insurance_fund.total_shares = 100;
+        spot_market.insurance_fund.user_shares = 0;
+
+        let vault_balance: u64 = (10000 * QUOTE_PRECISION) as u64;
+
+        now += 60;
+        sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+
+        // nothing requested yet
+        assert_eq!(
+            sweepstakes.validate_randomness_reveal_missed(now),
+            Err(ErrorCode::InvalidStatusUpdateDetected)
+        );
+
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        sweepstakes.record_randomness_request(now, 100);
+        assert_eq!(
+            sweepstakes
+                .calculate_randomness_reveal_deadline_ts()
+                .unwrap(),
+            now + 60
+        );
+
+        // the sponsor isn't on the hook before the slot hash is pinned
+        assert_eq!(
+            sweepstakes.validate_randomness_reveal_missed(now + 60),
+            Err(ErrorCode::RandomnessRevealNotTimedOut)
+        );
+        assert_eq!(
+            sweepstakes.resolve_missed_randomness_slot_hash_pin(612),
+            Err(ErrorCode::RandomnessRevealNotTimedOut)
+        );
+        // nobody pinned in time: back to switchboard without revoking commit-reveal
+        let mut pin_missed = *sweepstakes;
+        pin_missed
+            .resolve_missed_randomness_slot_hash_pin(613)
+            .unwrap();
+        assert_eq!(pin_missed.randomness_reveals_missed, 0);
+        assert_eq!(
+            pin_missed.randomness_provider,
+            RandomnessProvider::Switchboard
+        );
+        assert_eq!(
+            pin_missed.status,
+            CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested
+        );
+        pin_missed
+            .update_randomness_provider(RandomnessProvider::CommitReveal)
+            .unwrap();
+
+        sweepstakes
+            .pin_randomness_slot_hash(101, [1; 32], 101)
+            .unwrap();
+        assert_eq!(
+            sweepstakes.resolve_missed_randomness_slot_hash_pin(613),
+            Err(ErrorCode::RandomnessRevealNotTimedOut)
+        );
+        assert_eq!(
+            sweepstakes.validate_randomness_reveal_missed(now + 59),
+            Err(ErrorCode::RandomnessRevealNotTimedOut)
+        );
+        let mut not_missed = *sweepstakes;
+        assert_eq!(
+            not_missed.penalize_missed_randomness_reveal(now + 59),
+            Err(ErrorCode::RandomnessRevealNotTimedOut)
+        );
+        assert_eq!(not_missed.randomness_reveals_missed, 0);
+
+        now += 60;
+        sweepstakes.validate_randomness_reveal_missed(now).unwrap();
+        // the sponsor can still reveal until someone falls back
+        sweepstakes.validate_randomness_reveal(&secret).unwrap();
+
+        sweepstakes.penalize_missed_randomness_reveal(now).unwrap();
+        assert_eq!(sweepstakes.randomness_reveals_missed, 1);
+        assert_eq!(
+            sweepstakes.randomness_provider,
+            RandomnessProvider::Switchboard
+        );
+        // nothing drawn, the request stays outstanding for switchboard to answer
+        assert_eq!(
+            sweepstakes.status,
+            CompetitionRoundStatus::WinnerAndPrizeRandomnessRequested
+        );
+        assert_eq!(sweepstakes.winner_randomness, 0);
+        // request_randomness re-triggers it right away
+        sweepstakes.retry_randomness_request(now).unwrap();
+        assert_eq!(sweepstakes.randomness_request_retries, 1);
+        assert_eq!(
+            sweepstakes.validate_randomness_reveal(&secret),
+            Err(ErrorCode::InvalidRandomnessProvider)
+        );
+
+        // commit-reveal is revoked for the sponsor
+        assert_eq!(
+            sweepstakes.update_randomness_provider(RandomnessProvider::CommitReveal),
+            Err(ErrorCode::InvalidRandomnessProvider)
+        );
+        sweepstakes
+            .update_randomness_provider(RandomnessProvider::Switchboard)
+            .unwrap();
+
+        sweepstakes.winner_randomness = 1;
+        sweepstakes.prize_randomness = 0;
+        sweepstakes
+            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        sweepstakes
+            .settle_winner(
+                comp1,
+                &spot_market,
+                vault_balance,
+                now,
+                Pubkey::default(),
+                Pubkey::default(),
+                None,
+            )
+            .unwrap();
+        sweepstakes.reset_round(now).unwrap();
+        assert_eq!(sweepstakes.randomness_slot_hash, [0; 32]);
+        assert_eq!(sweepstakes.randomness_reveals_missed, 1);
+    }
+
+    #[test]
+    fn test_derive_randomness_range() {
+        let sweepstakes = &mut Competition::default();
+
+        assert_eq!(
+            sweepstakes.derive_randomness(&[&[0; 32]]),
+            Err(ErrorCode::CompetitionWinnerNotDetermined)
+        );
+
+        sweepstakes.total_score_settled = 3;
+        sweepstakes.prize_randomness_max = 5;
+
+        let mut winners_drawn = [false; 3];
+        for seed in 0..64_u8 {
+            let (_, winner_randomness, prize_randomness) =
+                sweepstakes.derive_randomness(&[&[seed; 32]]).unwrap();
+            assert!(winner_randomness >= 1 && winner_randomness <= 3);
+            assert!(prize_randomness <= 5);
+            winners_drawn[winner_randomness as usize - 1] = true;
+        }
+        assert_eq!(winners_drawn, [true; 3]);
+
+        // the round number is mixed in
+        sweepstakes.total_score_settled = u128::MAX;
+        let draw = sweepstakes.derive_randomness(&[&[9; 32]]).unwrap();
+        sweepstakes.round_number = 1;
+        assert_ne!(sweepstakes.derive_randomness(&[&[9; 32]]).unwrap(), draw);
+    }
+
+    #[test]
+    fn test_range_switchboard_randomness() {
+        let sweepstakes = &mut Competition::default();
+        sweepstakes.winner_randomness_max = 3;
+        sweepstakes.prize_randomness_max = 5;
+
+        assert_eq!(
+            sweepstakes.range_switchboard_randomness(0, 0).unwrap(),
+            (1, 0)
+        );
+        assert_eq!(
+            sweepstakes.range_switchboard_randomness(5, 13).unwrap(),
+            (3, 1)
+        );
+        // 2^128 % 3 == 1 and 2^128 % 6 == 4: raw values the function would have redrawn
+        assert_eq!(
+            sweepstakes.range_switchboard_randomness(u128::MAX, 0),
+            Err(ErrorCode::InvalidRandomnessDelivered)
+        );
+        assert_eq!(
+            sweepstakes.range_switchboard_randomness(u128::MAX - 1, u128::MAX - 3),
+            Err(ErrorCode::InvalidRandomnessDelivered)
+        );
+        assert_eq!(
+            sweepstakes
+                .range_switchboard_randomness(u128::MAX - 1, u128::MAX - 4)
+                .unwrap(),
+            ((u128::MAX - 1) % 3 + 1, (u128::MAX - 4) % 6)
+        );
+
+        // nothing requested
+        sweepstakes.winner_randomness_max = 0;
+        assert_eq!(
+            sweepstakes.range_switchboard_randomness(0, 0),
+            Err(ErrorCode::InvalidRandomnessDelivered)
+        );
+
+        // full range
+        assert_eq!(
+            utils::range_unbiased_randomness(u128::MAX, 0, u128::MAX).unwrap(),
+            u128::MAX
+        );
+    }
+
+    fn slot_hashes_data(entries: &[(u64, [u8; 32])]) -> Vec<u8> {
+        let mut data = (entries.len() as u64).to_le_bytes().to_vec();
+        for (slot, hash) in entries {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(hash);
+        }
+        data
+    }
+
+    #[test]
+    fn test_slot_hashes() {
+        assert_eq!(
+            utils::get_most_recent_slot_hash(&slot_hashes_data(&[])),
+            Err(ErrorCode::SlotHashNotFound)
+        );
+
+        // most recent first
+        let data = slot_hashes_data(&[(104, [4; 32]), (102, [2; 32]), (101, [1; 32])]);
+
+        assert_eq!(
+            utils::get_most_recent_slot_hash(&data).unwrap(),
+            (104, [4; 32])
+        );
+
+        // truncated account data
+        assert_eq!(
+            utils::get_most_recent_slot_hash(&data[..20]),
+            Err(ErrorCode::SlotHashNotFound)
+        );
+
+        // first slot after the request, skipped slots included
+        assert_eq!(
+            utils::get_first_slot_hash_after(&data, 101).unwrap(),
+            (102, [2; 32])
+        );
+        assert_eq!(
+            utils::get_first_slot_hash_after(&data, 102).unwrap(),
+            (104, [4; 32])
+        );
+        assert_eq!(
+            utils::get_first_slot_hash_after(&data, 103).unwrap(),
+            (104, [4; 32])
+        );
+        // not landed yet
+        assert_eq!(
+            utils::get_first_slot_hash_after(&data, 104),
+            Err(ErrorCode::SlotHashNotFound)
+        );
+        // the request slot was evicted, so slots after it may have been too
+        assert_eq!(
+            utils::get_first_slot_hash_after(&data, 100),
+            Err(ErrorCode::SlotHashNotFound)
+        );
+        assert_eq!(
+            utils::get_first_slot_hash_after(&data[..60], 101),
+            Err(ErrorCode::SlotHashNotFound)
+        );
+    }
+}
+
+mod competition_multiple_winners {
+    use crate::error::ErrorCode;
+    use crate::state::{
+        Competition, CompetitionRoundStatus, Competitor, DEFAULT_WINNER_SETTLEMENT_TIMEOUT,
+    };
+    use crate::utils::get_test_sample_draw;
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::solana_program::hash::hashv;
+    use drift::{
+        math::constants::{PERCENTAGE_PRECISION_U64, QUOTE_PRECISION},
+        state::{spot_market::SpotMarket, user::UserStats},
+    };
+
+    #[test]
+    fn test_competition_2_winners_settlement() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 2;
+
+        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        sweepstakes.number_of_competitors = 2;
+        let comp1 = &mut Competitor::default();
+        comp1.claim_entry().unwrap();
+
+        let comp2 = &mut Competitor::default();
+        comp2.claim_entry().unwrap();
+        comp2.bonus_score += 2;
+
+        let us: &UserStats = &UserStats::default();
+        let mut spot_market = SpotMarket::default();
+        spot_market.decimals = 6;
+        spot_market.insurance_fund.total_shares = 100;
+        spot_market.insurance_fund.user_shares = 0;
+
+        // 10k max
+        let vault_balance: u64 = (10000 * QUOTE_PRECISION) as u64;
+
+        assert!(sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .is_err());
+        assert!(sweepstakes
+            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
+            .is_err());
+
+        now += 60;
+
+        sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+        sweepstakes
+            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+
+        sweepstakes.prize_randomness =
+            get_test_sample_draw(0, sweepstakes.prize_randomness_max).unwrap();
+        sweepstakes.winner_randomness =
+            get_test_sample_draw(1, sweepstakes.total_score_settled).unwrap();
+
+        sweepstakes
+            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+
+        assert_eq!(
+            sweepstakes.status,
+            CompetitionRoundStatus::WinnerAndPrizeRandomnessComplete
+        );
+        sweepstakes
+            .resolve_prize_amount(&spot_market, vault_balance)
+            .unwrap();
+        assert_eq!(
+            sweepstakes.status,
+            CompetitionRoundStatus::WinnerAndPrizeRandomnessComplete
+        );
+        assert_eq!(sweepstakes.winner_randomness, 2);
+        sweepstakes
+            .settle_winner(
+                comp2,
+                &spot_market,
+                vault_balance,
+                now,
+                Pubkey::default(),
+                Pubkey::default(),
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            sweepstakes.status,
+            CompetitionRoundStatus::WinnerAndPrizeRandomnessComplete
+        );
+        assert!(sweepstakes.winner_randomness >= 1 && sweepstakes.winner_randomness <= 4);
+        assert!(comp2.has_won_round(0).unwrap());
+        assert_eq!(sweepstakes.winning_score_settled, 3);
+
+        // comp2 drawn again: not paid twice, the draw is redone
+        let comp2_unclaimed_winnings = comp2.unclaimed_winnings;
+        sweepstakes.winner_randomness = 2;
+        sweepstakes
+            .settle_winner(
+                comp2,
+                &spot_market,
+                vault_balance,
+                now,
+                Pubkey::default(),
+                Pubkey::default(),
+                None,
+            )
+            .unwrap();
+        assert_eq!(comp2.unclaimed_winnings, comp2_unclaimed_winnings);
+        assert_eq!(sweepstakes.number_of_winners_settled, 1);
+        assert_eq!(sweepstakes.winner_redraws, 1);
+        assert_ne!(sweepstakes.winner_randomness, 2);
+
+        sweepstakes.winner_randomness = 1; // set so other comp wins
+        sweepstakes
+            .settle_winner(
+                comp1,
+                &spot_market,
+                vault_balance,
+                now,
+                Pubkey::default(),
+                Pubkey::default(),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            sweepstakes.status,
+            CompetitionRoundStatus::WinnerSettlementComplete
+        );
+    }
+
+    #[test]
+    fn test_winner_draws_reproducible_from_records() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 3;
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        sweepstakes.number_of_competitors = 3;
+
+        let competitors = &mut [
+            Competitor::default(),
+            Competitor::default(),
+            Competitor::default(),
+        ];
+        for competitor in competitors.iter_mut() {
+            competitor.claim_entry().unwrap();
+        }
+
+        let us: &UserStats = &UserStats::default();
+        let mut spot_market = SpotMarket::default();
+        spot_market.decimals = 6;
+        spot_market.insurance_fund.total_shares = 100;
+        spot_market.insurance_fund.user_shares = 0;
+
+        let vault_balance: u64 = (10000 * QUOTE_PRECISION) as u64;
+
+        now += 60;
+        for competitor in competitors.iter_mut() {
+            sweepstakes
+                .settle_competitor(competitor, us, now, Pubkey::default(), Pubkey::default())
+                .unwrap();
+        }
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        assert_eq!(sweepstakes.winner_randomness_max, 3);
+
+        // raw values as delivered by switchboard, ranged on-chain (see resolve_randomness)
+        sweepstakes.winner_randomness_delivered = 4;
+        sweepstakes.prize_randomness_delivered =
+            get_test_sample_draw(0, sweepstakes.prize_randomness_max).unwrap();
+        let (winner_randomness, prize_randomness) = sweepstakes
+            .range_switchboard_randomness(
+                sweepstakes.winner_randomness_delivered,
+                sweepstakes.prize_randomness_delivered,
+            )
+            .unwrap();
+        assert_eq!(winner_randomness, 2);
+        assert_eq!(prize_randomness, sweepstakes.prize_randomness_delivered);
+        sweepstakes.winner_randomness = winner_randomness;
+        sweepstakes.prize_randomness = prize_randomness;
+        sweepstakes
+            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        // buckets unchanged since the request
+        assert_eq!(sweepstakes.prize_draw, sweepstakes.prize_randomness);
+
+        let competition_key = Pubkey::new_unique();
+        let record = sweepstakes.get_winner_draw_record(competition_key, now);
+        assert_eq!(record.winner_randomness, 2);
+        assert_eq!(record.winner_randomness_delivered, 4);
+
+        while sweepstakes.status != CompetitionRoundStatus::WinnerSettlementComplete {
+            // repeat winners are passed too, settle_winner redoes their draw
+            let winner = competitors
+                .iter_mut()
+                .find(|competitor| {
+                    competitor.min_draw < sweepstakes.winner_randomness
+                        && sweepstakes.winner_randomness <= competitor.max_draw
+                })
+                .unwrap();
+            sweepstakes
+                .settle_winner(
+                    winner,
+                    &spot_market,
+                    vault_balance,
+                    now,
+                    Pubkey::default(),
+                    competition_key,
+                    None,
+                )
+                .unwrap();
+
+            if sweepstakes.status == CompetitionRoundStatus::WinnerSettlementComplete {
+                break;
+            }
+
+            let record = sweepstakes.get_winner_draw_record(competition_key, now);
+
+            // recomputed by an outside auditor from the records alone
+            let hash = hashv(&[
+                b"winner_draw",
+                &record.winner_randomness_delivered.to_le_bytes(),
+                &record.round_number.to_le_bytes(),
+                &record.winner_placement.to_le_bytes(),
+                &record.winner_redraws.to_le_bytes(),
+            ]);
+            let mut hash_bytes = [0_u8; 16];
+            hash_bytes.copy_from_slice(&hash.to_bytes()[..16]);
+            assert_eq!(
+                record.winner_randomness,
+                u128::from_le_bytes(hash_bytes) % record.total_score_settled + 1
+            );
+            assert_eq!(record.winner_randomness_delivered, 2);
+            assert_eq!(record.winner_randomness_max, 3);
+        }
+
+        assert_eq!(sweepstakes.number_of_winners_settled, 3);
+        assert!(competitors
+            .iter()
+            .all(|competitor| competitor.has_won_round(0).unwrap()));
+    }
+
+    #[test]
+    fn test_winner_badge() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1;
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        sweepstakes.winner_badges_enabled = 1;
+        sweepstakes.number_of_competitors = 1;
+
+        let authority = Pubkey::new_unique();
+        let comp1 = &mut Competitor::default();
+        comp1.authority = authority;
+        comp1.claim_entry().unwrap();
+
+        // badges only go to the winner's own authority
+        comp1.validate_winner_badge_authority(&authority).unwrap();
+        assert_eq!(
+            comp1.validate_winner_badge_authority(&Pubkey::new_unique()),
+            Err(ErrorCode::InvalidWinnerBadgeAuthority)
+        );
+
+        let us: &UserStats = &UserStats::default();
+        let mut spot_market = SpotMarket::default();
+        spot_market.decimals = 6;
+        spot_market.insurance_fund.total_shares = 1100 * PERCENTAGE_PRECISION;
+        spot_market.insurance_fund.user_shares = PERCENTAGE_PRECISION;
+        spot_market.insurance_fund.shares_base = 1;
+        let vault_balance: u64 = (1580000 * QUOTE_PRECISION) as u64;
+
+        now += 60;
+        sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        sweepstakes.prize_randomness =
+            get_test_sample_draw(0, sweepstakes.prize_randomness_max).unwrap();
+        sweepstakes.winner_randomness = 1;
+        sweepstakes
+            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+
+        let competitor_key = Pubkey::new_unique();
+        let competition_key = Pubkey::new_unique();
+        let winner_badge_mint = Pubkey::new_unique();
+
+        // the record names the badge minted for the placement, default without one
+        let record = sweepstakes.get_round_winner_record(
+            comp1,
+            competitor_key,
+            competition_key,
+            10,
+            20,
+            Some(winner_badge_mint),
+            now,
+        );
+        assert_eq!(record.winner_badge_mint, winner_badge_mint);
+        assert_eq!(record.competitor_authority, authority);
+        assert_eq!(record.winner_placement, 0);
+        let record = sweepstakes.get_round_winner_record(
+            comp1,
+            competitor_key,
+            competition_key,
+            10,
+            20,
+            None,
+            now,
+        );
+        assert_eq!(record.winner_badge_mint, Pubkey::default());
+
+        sweepstakes
+            .settle_winner(
+                comp1,
+                &spot_market,
+                vault_balance,
+                now,
+                competitor_key,
+                competition_key,
+                Some(winner_badge_mint),
+            )
+            .unwrap();
+        assert_eq!(sweepstakes.number_of_winners_settled, 1);
+        assert_eq!(
+            sweepstakes.status,
+            CompetitionRoundStatus::WinnerSettlementComplete
+        );
+        assert!(comp1.has_won_round(sweepstakes.round_number).unwrap());
+    }
+
+    #[test]
+    fn test_skip_winner() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 2;
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        sweepstakes.number_of_competitors = 2;
+
+        let comp1 = &mut Competitor::default();
+        comp1.claim_entry().unwrap();
+
+        let comp2 = &mut Competitor::default();
+        comp2.claim_entry().unwrap();
+        comp2.bonus_score += 2;
+
+        let us: &UserStats = &UserStats::default();
+        let mut spot_market = SpotMarket::default();
+        spot_market.decimals = 6;
+        spot_market.insurance_fund.total_shares = 100;
+        spot_market.insurance_fund.user_shares = 0;
+
+        let vault_balance: u64 = (10000 * QUOTE_PRECISION) as u64;
+
+        now += 60;
+        sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+        sweepstakes
+            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+
+        // nothing drawn yet
+        assert_eq!(
+            sweepstakes.skip_winner(now),
+            Err(ErrorCode::CompetitionWinnerNotDetermined)
+        );
+
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        sweepstakes.prize_randomness =
+            get_test_sample_draw(0, sweepstakes.prize_randomness_max).unwrap();
+        sweepstakes.winner_randomness = 2;
+        sweepstakes
+            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+        sweepstakes.winner_draw_ts = now;
+
+        let timeout = DEFAULT_WINNER_SETTLEMENT_TIMEOUT as i64;
+        assert_eq!(sweepstakes.get_winner_settlement_timeout(), 60 * 60);
+
+        // no timeout yet
+        assert_eq!(
+            sweepstakes.skip_winner(now + timeout - 1),
+            Err(ErrorCode::WinnerNotSkippable)
+        );
+
+        now += timeout;
+        sweepstakes.skip_winner(now).unwrap();
+        assert_eq!(sweepstakes.winner_redraws, 1);
+        assert_eq!(sweepstakes.winner_draw_ts, now);
+        assert_eq!(sweepstakes.number_of_winners_settled, 0);
+        assert_eq!(comp2.unclaimed_winnings, 0);
+        assert!(sweepstakes.winner_randomness >= 1 && sweepstakes.winner_randomness <= 4);
+
+        // the redraw restarts the timeout, which the operator can shorten
+        sweepstakes.winner_settlement_timeout = 300;
+        assert_eq!(
+            sweepstakes.skip_winner(now + 299),
+            Err(ErrorCode::WinnerNotSkippable)
+        );
+        now += 300;
+        sweepstakes.skip_winner(now).unwrap();
+        assert_eq!(sweepstakes.winner_redraws, 2);
+        assert_eq!(sweepstakes.winner_draw_ts, now);
+        assert_eq!(
+            sweepstakes.winner_randomness,
+            sweepstakes.calculate_winner_draw().unwrap()
+        );
+
+        sweepstakes.winner_randomness = 1;
+        sweepstakes
+            .settle_winner(
+                comp1,
+                &spot_market,
+                vault_balance,
+                now,
+                Pubkey::default(),
+                Pubkey::default(),
+                None,
+            )
+            .unwrap();
+        assert_eq!(sweepstakes.number_of_winners_settled, 1);
+        assert_eq!(sweepstakes.winner_draw_ts, now);
+    }
+
+    #[test]
+    fn test_competition_more_winners_than_competitors() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 3;
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+        sweepstakes.number_of_competitors = 2;
+
+        let comp1 = &mut Competitor::default();
+        comp1.claim_entry().unwrap();
+
+        let comp2 = &mut Competitor::default();
+        comp2.claim_entry().unwrap();
+        comp2.bonus_score += 2;
+
+        let us: &UserStats = &UserStats::default();
+        let mut spot_market = SpotMarket::default();
+        spot_market.decimals = 6;
+        spot_market.insurance_fund.total_shares = 100;
+        spot_market.insurance_fund.user_shares = 0;
+
+        let vault_balance: u64 = (10000 * QUOTE_PRECISION) as u64;
+
+        now += 60;
+        sweepstakes
+            .settle_competitor(comp1, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+        sweepstakes
+            .settle_competitor(comp2, us, now, Pubkey::default(), Pubkey::default())
+            .unwrap();
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+
+        sweepstakes.prize_randomness =
+            get_test_sample_draw(0, sweepstakes.prize_randomness_max).unwrap();
+        sweepstakes.winner_randomness =
+            get_test_sample_draw(1, sweepstakes.total_score_settled).unwrap();
+        sweepstakes
+            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+
+        sweepstakes.winner_randomness = 2;
+        sweepstakes
+            .settle_winner(
+                comp2,
+                &spot_market,
+                vault_balance,
+                now,
+                Pubkey::default(),
+                Pubkey::default(),
+                None,
+            )
+            .unwrap();
+        assert_eq!(sweepstakes.number_of_winners_settled, 1);
+
+        sweepstakes.winner_randomness = 1;
+        sweepstakes
+            .settle_winner(
+                comp1,
+                &spot_market,
+                vault_balance,
+                now,
+                Pubkey::default(),
+                Pubkey::default(),
+                None,
+            )
+            .unwrap();
+
+        // every ticket has won, the third placement can't be drawn
+        assert_eq!(sweepstakes.number_of_winners_settled, 3);
+        assert_eq!(
+            sweepstakes.status,
+            CompetitionRoundStatus::WinnerSettlementComplete
+        );
+        assert!(sweepstakes.prize_amount_settled < sweepstakes.prize_amount);
+        assert_eq!(
+            sweepstakes.outstanding_unclaimed_winnings,
+            (comp1.unclaimed_winnings + comp2.unclaimed_winnings) as u128
+        );
+    }
+
+    #[test]
+    fn test_big_competition_1001_winners_settlement() {
+        let mut now = 168000000;
+        let sweepstakes = &mut Competition::default();
+
+        sweepstakes.next_round_expiry_ts = now + 60;
+        sweepstakes.round_duration = 60;
+        sweepstakes.number_of_winners = 1001;
+
+        assert_eq!(sweepstakes.status, CompetitionRoundStatus::Active);
+        sweepstakes.sponsor_info.max_sponsor_fraction = PERCENTAGE_PRECISION_U64 / 2;
+
+        const N_COMPS: usize = 1001;
+        let mut comps: Vec<Competitor> = Vec::with_capacity(N_COMPS);
+        while comps.len() < N_COMPS {
+            let mut c = Competitor::default();
+            c.bonus_score += 100000003522;
+            comps.push(c);
+        }
+        sweepstakes.number_of_competitors = N_COMPS as u128;
+
+        let us: &UserStats = &UserStats::default();
+        let mut spot_market = SpotMarket::default();
+        spot_market.decimals = 6;
+        spot_market.insurance_fund.total_shares = 1000000000;
+        spot_market.insurance_fund.user_shares = 0;
+
+        // 10k max
+        let vault_balance: u64 = (55000 * QUOTE_PRECISION) as u64;
+
+        now += 60;
+        for c in &mut comps {
+            sweepstakes
+                .settle_competitor(c, us, now, Pubkey::default(), Pubkey::default())
+                .unwrap();
+        }
+
+        sweepstakes
+            .request_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+
+        sweepstakes.prize_randomness =
+            get_test_sample_draw(0, sweepstakes.prize_randomness_max).unwrap();
+        sweepstakes.winner_randomness =
+            get_test_sample_draw(1, sweepstakes.total_score_settled).unwrap();
+        let max_prize = sweepstakes
+            .calculate_sponsor_max_prize(&spot_market, vault_balance)
+            .unwrap();
+        assert_eq!(max_prize, 27500000000);
+
+        assert_eq!(
+            sweepstakes
+                .calculate_prize_amount(&spot_market, vault_balance)
+                .unwrap(),
+            (20000000, 31, 0) // 1.1k
+        );
+
+        sweepstakes
+            .resolve_winner_and_prize_randomness(&spot_market, vault_balance)
+            .unwrap();
+
+        assert_eq!(sweepstakes.prize_amount, 20000000);
+        assert_eq!(sweepstakes.prize_base, 0);
+
+        assert_eq!(sweepstakes.prize_randomness, 19);
+        assert_eq!(sweepstakes.prize_randomness_max, 39);
+        assert_eq!(sweepstakes.number_of_winners, 1001);
+        assert_eq!(sweepstakes.number_of_winners_settled, 0);
+
+        let score = (comps[0].max_draw - comps[0].min_draw) as u128;
+        let mut num_draws = 0;
+        let mut winnings_bucket_arr: [u128; N_COMPS] = [0; N_COMPS];
+        while sweepstakes.number_of_winners_settled != sweepstakes.number_of_winners {
+            // equal ticket ranges, so the drawn competitor follows from winner_randomness. only
+            // the drawn competitor is passed, like settle_winner, even for a repeat winner
+            let index = ((sweepstakes.winner_randomness - 1) / score) as usize;
+            let winner_prize_amount = sweepstakes.calculate_next_winner_prize_amount().unwrap();
+            let number_of_winners_settled_before = sweepstakes.number_of_winners_settled;
+            sweepstakes
+                .settle_winner(
+                    &mut comps[index],
+                    &spot_market,
+                    vault_balance,
+                    now,
+                    Pubkey::default(),
+                    Pubkey::default(),
+                    None,
+                )
+                .unwrap();
+            if sweepstakes.number_of_winners_settled > number_of_winners_settled_before {
+                winnings_bucket_arr[index] += winner_prize_amount;
+            }
+            num_draws += 1;
+        }
+
+        // every competitor won exactly once
+        assert!(comps.iter().all(|c| c.has_won_round(0).unwrap()));
+        assert!(winnings_bucket_arr.iter().all(|w| *w > 0));
+        assert_eq!(winnings_bucket_arr.iter().sum::<u128>(), 19994000);
+        assert_eq!(sweepstakes.winner_redraws, 6664);
+        assert_eq!(num_draws, N_COMPS + sweepstakes.winner_redraws as usize);
+        assert_eq!(
+            sweepstakes.winning_score_settled,
+            sweepstakes.total_score_settled
+        );
+        assert!(sweepstakes.prize_amount > sweepstakes.prize_amount_settled);
+        assert_eq!(
+            sweepstakes.prize_amount - sweepstakes.prize_amount_settled,
+            6000
+        ); // 0.03150400031% of reward was dust
+    }
+}
+
+mod competition_validation {
+    use crate::error::ErrorCode;
+    use crate::state::{
+        validate_competition_migration, Competition, CompetitionRoundStatus, RandomnessProvider,
+        RoundScheduleMode, Size, LEGACY_COMPETITION_SIZE,
+    };
+    use crate::validation::{
+        validate_competition_expiry_ts, validate_competition_name, validate_competition_params,
+        validate_max_sponsor_fraction, validate_number_of_winners, validate_randomness_provider,
+        validate_round_duration,
+    };
+    use drift::math::constants::PERCENTAGE_PRECISION_U64;
+
+    #[test]
+    fn test_validate_max_sponsor_fraction() {
+        validate_max_sponsor_fraction(0).unwrap();
+        validate_max_sponsor_fraction(PERCENTAGE_PRECISION_U64).unwrap();
+        assert_eq!(
+            validate_max_sponsor_fraction(PERCENTAGE_PRECISION_U64 + 1),
+            Err(ErrorCode::InvalidMaxSponsorFraction)
+        );
+    }
+
+    #[test]
+    fn test_validate_number_of_winners() {
+        assert_eq!(
+            validate_number_of_winners(0),
+            Err(ErrorCode::InvalidNumberOfWinners)
+        );
+        validate_number_of_winners(1).unwrap();
+        validate_number_of_winners(u32::MAX).unwrap();
+    }
+
+    #[test]
+    fn test_validate_round_duration() {
+        assert_eq!(
+            validate_round_duration(0),
+            Err(ErrorCode::InvalidRoundDuration)
+        );
+        validate_round_duration(1).unwrap();
+    }
+
+    #[test]
+    fn test_validate_competition_expiry_ts() {
+        let next_round_expiry_ts = 168000000;
+
+        // perpetual
+        validate_competition_expiry_ts(next_round_expiry_ts, 0).unwrap();
+
+        validate_competition_expiry_ts(next_round_expiry_ts, next_round_expiry_ts + 1).unwrap();
+        assert_eq!(
+            validate_competition_expiry_ts(next_round_expiry_ts, next_round_expiry_ts),
+            Err(ErrorCode::InvalidCompetitionExpiryTs)
+        );
+        assert_eq!(
+            validate_competition_expiry_ts(next_round_expiry_ts, next_round_expiry_ts - 1),
+            Err(ErrorCode::InvalidCompetitionExpiryTs)
+        );
+    }
+
+    #[test]
+    fn test_validate_competition_params() {
+        let now = 168000000;
+
+        validate_competition_params(now + 60, 0, 60, PERCENTAGE_PRECISION_U64 / 2, 1).unwrap();
+
+        assert_eq!(
+            validate_competition_params(now + 60, 0, 60, PERCENTAGE_PRECISION_U64 * 2, 1),
+            Err(ErrorCode::InvalidMaxSponsorFraction)
+        );
+        assert_eq!(
+            validate_competition_params(now + 60, 0, 60, PERCENTAGE_PRECISION_U64, 0),
+            Err(ErrorCode::InvalidNumberOfWinners)
+        );
+        assert_eq!(
+            validate_competition_params(now + 60, 0, 0, PERCENTAGE_PRECISION_U64, 1),
+            Err(ErrorCode::InvalidRoundDuration)
+        );
+        assert_eq!(
+            validate_competition_params(now + 60, now, 60, PERCENTAGE_PRECISION_U64, 1),
+            Err(ErrorCode::InvalidCompetitionExpiryTs)
+        );
+    }
+
+    fn pad_name(name: &[u8]) -> [u8; 32] {
+        let mut padded = [b' '; 32];
+        padded[..name.len()].copy_from_slice(name);
+        padded
+    }
+
+    #[test]
+    fn test_validate_competition_name() {
+        validate_competition_name(&pad_name(b"sweepstakes")).unwrap();
+        validate_competition_name(&pad_name("Friday Frenzy 🎉".as_bytes())).unwrap();
+        validate_competition_name(&pad_name(&[b'a'; 32])).unwrap();
+
+        for invalid_name in [
+            pad_name(b""),
+            pad_name(b" sweepstakes"),
+            pad_name(b"sweep\nstakes"),
+            [0; 32],
+            pad_name(&[0xff, 0xfe]),
+            // multi-byte char cut off by the 32 byte limit
+            pad_name(&"ééééééééééééééééé".as_bytes()[..31]),
+        ] {
+            assert_eq!(
+                validate_competition_name(&invalid_name),
+                Err(ErrorCode::InvalidCompetitionName)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_randomness_provider() {
+        validate_randomness_provider(RandomnessProvider::Switchboard).unwrap();
+        validate_randomness_provider(RandomnessProvider::CommitReveal).unwrap();
+        // test builds count as devnet
+        validate_randomness_provider(RandomnessProvider::SlotHash).unwrap();
+    }
+    #[test]
+    fn test_validate_competition_migration() {
+        // baseline layout: name, sponsor_info, 4 pubkeys, 11 u128s, 4 u64s, 2 u32s, status, bump,
+        // then 30 bytes of padding
+        let mut data = vec![0_u8; LEGACY_COMPETITION_SIZE];
+        let sponsor = [7_u8; 32];
+        data[8..40].copy_from_slice(&[b's'; 32]); // name
+        data[40..72].copy_from_slice(&sponsor); // sponsor_info.sponsor
+        data[72..80].copy_from_slice(&100_u64.to_le_bytes()); // min_sponsor_amount
+        data[184..216].copy_from_slice(&[9; 32]); // competition_authority
+        data[216..232].copy_from_slice(&42_u128.to_le_bytes()); // number_of_competitors
+        data[248..264].copy_from_slice(&1234_u128.to_le_bytes()); // total_score_settled
+        data[392..400].copy_from_slice(&3_u64.to_le_bytes()); // round_number
+        data[400..408].copy_from_slice(&1_700_000_000_i64.to_le_bytes()); // next_round_expiry_ts
+        data[416..424].copy_from_slice(&604800_u64.to_le_bytes()); // round_duration
+        data[424..428].copy_from_slice(&2_u32.to_le_bytes()); // number_of_winners
+        data[432] = 2; // status
+        data[433] = 254; // competition_authority_bump
+
+        validate_competition_migration(&data).unwrap();
+
+        // already migrated / unexpected size
+        assert_eq!(
+            validate_competition_migration(&vec![0; Competition::SIZE]),
+            Err(ErrorCode::InvalidCompetitionMigration)
+        );
+        assert_eq!(
+            validate_competition_migration(&data[..LEGACY_COMPETITION_SIZE - 1]),
+            Err(ErrorCode::InvalidCompetitionMigration)
+        );
+
+        // dirty padding would surface as garbage in the new fields
+        let mut dirty = data.clone();
+        dirty[LEGACY_COMPETITION_SIZE - 1] = 1;
+        assert_eq!(
+            validate_competition_migration(&dirty),
+            Err(ErrorCode::InvalidCompetitionMigration)
+        );
+
+        // realloc zero-fills the new bytes
+        data.resize(Competition::SIZE, 0);
+        let mut competition = Competition::default();
+        bytemuck::bytes_of_mut(&mut competition).copy_from_slice(&data[8..]);
+
+        assert_eq!(competition.name, [b's'; 32]);
+        assert_eq!(competition.sponsor_info.sponsor.to_bytes(), sponsor);
+        assert_eq!(competition.sponsor_info.min_sponsor_amount, 100);
+        assert_eq!(competition.competition_authority.to_bytes(), [9; 32]);
+        assert_eq!(competition.number_of_competitors, 42);
+        assert_eq!(competition.total_score_settled, 1234);
+        assert_eq!(competition.round_number, 3);
+        assert_eq!(competition.next_round_expiry_ts, 1_700_000_000);
+        assert_eq!(competition.round_duration, 604800);
+        assert_eq!(competition.number_of_winners, 2);
+        assert_eq!(
+            competition.status,
+            CompetitionRoundStatus::WinnerAndPrizeRandomnessComplete
+        );
+        assert_eq!(competition.competition_authority_bump, 254);
+
+        // new fields read as unset
+        assert_eq!(competition.get_operator(), competition.sponsor_info.sponsor);
+        assert_eq!(
+            competition.get_moderator(),
+            competition.sponsor_info.sponsor
+        );
+        assert_eq!(competition.round_schedule.mode, RoundScheduleMode::Interval);
+        assert_eq!(
+            competition.randomness_provider,
+            RandomnessProvider::Switchboard
+        );
+        assert!(!competition.winner_badges_enabled());
+        assert_eq!(competition.winner_randomness_delivered, 0);
     }
 }
@@ -114,3 +114,86 @@ pub fn calculate_revenue_pool_deposit_tokens_from_entries(
 
     Ok(deposit_tokens.cast()?)
 }
+
+// the SlotHashes sysvar is too large to deserialize on-chain, so read it in place:
+// u64 number of entries, then (slot: u64, hash: [u8; 32]) entries with the most recent first
+const SLOT_HASH_ENTRY_SIZE: usize = 8 + 32;
+
+fn get_slot_hash_entry(data: &[u8], index: usize) -> Option<(u64, [u8; 32])> {
+    let start = 8 + index * SLOT_HASH_ENTRY_SIZE;
+    let entry = data.get(start..start + SLOT_HASH_ENTRY_SIZE)?;
+
+    let slot = u64::from_le_bytes(entry[..8].try_into().ok()?);
+    let hash: [u8; 32] = entry[8..].try_into().ok()?;
+
+    Some((slot, hash))
+}
+
+fn get_number_of_slot_hashes(data: &[u8]) -> usize {
+    data.get(..8)
+        .and_then(|len| len.try_into().ok())
+        .map_or(0, |len| u64::from_le_bytes(len) as usize)
+}
+
+pub fn get_most_recent_slot_hash(data: &[u8]) -> CompetitionResult<(u64, [u8; 32])> {
+    if get_number_of_slot_hashes(data) == 0 {
+        msg!("slot hashes sysvar is empty");
+        return Err(ErrorCode::SlotHashNotFound);
+    }
+
+    get_slot_hash_entry(data, 0).ok_or(ErrorCode::SlotHashNotFound)
+}
+
+// the hash of the first slot after `slot` (slots can be skipped). `slot` itself has to still be
+// in the sysvar, otherwise an older entry after it may already have been evicted
+pub fn get_first_slot_hash_after(data: &[u8], slot: u64) -> CompetitionResult<(u64, [u8; 32])> {
+    let mut first_slot_hash_after = None;
+
+    for index in 0..get_number_of_slot_hashes(data) {
+        let (entry_slot, entry_hash) =
+            get_slot_hash_entry(data, index).ok_or(ErrorCode::SlotHashNotFound)?;
+
+        if entry_slot <= slot {
+            return first_slot_hash_after.ok_or_else(|| {
+                msg!("no slot after slot {} has landed yet", slot);
+                ErrorCode::SlotHashNotFound
+            });
+        }
+
+        first_slot_hash_after = Some((entry_slot, entry_hash));
+    }
+
+    msg!("slot {} is no longer in the slot hashes sysvar", slot);
+    Err(ErrorCode::SlotHashNotFound)
+}
+
+// raw % window + min, the same ranging the switchboard function does. raw values in the biased
+// tail of the u128 range are redrawn by the function, so one landing here wasn't ranged fairly
+pub fn range_unbiased_randomness(raw: u128, min: u128, max: u128) -> CompetitionResult<u128> {
+    validate!(
+        min <= max,
+        ErrorCode::InvalidRandomnessDelivered,
+        "invalid randomness bounds: min {} > max {}",
+        min,
+        max
+    )?;
+
+    let window = match (max - min).checked_add(1) {
+        Some(window) => window,
+        // [0, u128::MAX], every raw value is fair
+        None => return Ok(raw),
+    };
+
+    // 2^128 % window, the size of the biased tail
+    let tail = (u128::MAX % window + 1) % window;
+    validate!(
+        raw <= u128::MAX - tail,
+        ErrorCode::InvalidRandomnessDelivered,
+        "raw randomness {} is in the biased tail for [{}, {}]",
+        raw,
+        min,
+        max
+    )?;
+
+    Ok(raw % window + min)
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use drift::math::constants::PERCENTAGE_PRECISION_U64;
+
+use crate::error::{CompetitionResult, ErrorCode};
+use crate::state::RandomnessProvider;
+use drift::validate;
+
+// shared checks for admin supplied params, so a bad update can't brick settlement
+
+pub fn validate_competition_name(name: &[u8; 32]) -> CompetitionResult {
+    // utf-8, right padded with spaces
+    let name = std::str::from_utf8(name).map_err(|_| {
+        msg!("competition name is not valid utf-8");
+        ErrorCode::InvalidCompetitionName
+    })?;
+
+    validate!(
+        !name.trim_end_matches(' ').is_empty() && !name.starts_with(' '),
+        ErrorCode::InvalidCompetitionName,
+        "competition name must be non-empty and right padded with spaces"
+    )?;
+
+    validate!(
+        !name.chars().any(|c| c.is_control()),
+        ErrorCode::InvalidCompetitionName,
+        "competition name contains control characters"
+    )?;
+
+    Ok(())
+}
+
+pub fn validate_max_sponsor_fraction(max_sponsor_fraction: u64) -> CompetitionResult {
+    validate!(
+        max_sponsor_fraction <= PERCENTAGE_PRECISION_U64,
+        ErrorCode::InvalidMaxSponsorFraction,
+        "max_sponsor_fraction={} > PERCENTAGE_PRECISION={}",
+        max_sponsor_fraction,
+        PERCENTAGE_PRECISION_U64
+    )?;
+
+    Ok(())
+}
+
+pub fn validate_number_of_winners(number_of_winners: u32) -> CompetitionResult {
+    // prize amounts are divided by number_of_winners
+    validate!(
+        number_of_winners > 0,
+        ErrorCode::InvalidNumberOfWinners,
+        "number_of_winners must be > 0"
+    )?;
+
+    Ok(())
+}
+
+pub fn validate_round_duration(round_duration: u64) -> CompetitionResult {
+    // next round expiry is derived by dividing by round_duration
+    validate!(
+        round_duration > 0,
+        ErrorCode::InvalidRoundDuration,
+        "round_duration must be > 0"
+    )?;
+
+    Ok(())
+}
+
+pub fn validate_competition_expiry_ts(
+    next_round_expiry_ts: i64,
+    competition_expiry_ts: i64,
+) -> CompetitionResult {
+    // perpetual when competition_expiry_ts == 0
+    validate!(
+        competition_expiry_ts == 0 || competition_expiry_ts > next_round_expiry_ts,
+        ErrorCode::InvalidCompetitionExpiryTs,
+        "competition_expiry_ts={} must be after next_round_expiry_ts={}",
+        competition_expiry_ts,
+        next_round_expiry_ts
+    )?;
+
+    Ok(())
+}
+
+pub fn validate_competition_params(
+    next_round_expiry_ts: i64,
+    competition_expiry_ts: i64,
+    round_duration: u64,
+    max_sponsor_fraction: u64,
+    number_of_winners: u32,
+) -> CompetitionResult {
+    validate_max_sponsor_fraction(max_sponsor_fraction)?;
+    validate_number_of_winners(number_of_winners)?;
+    validate_round_duration(round_duration)?;
+    validate_competition_expiry_ts(next_round_expiry_ts, competition_expiry_ts)?;
+
+    Ok(())
+}
+
+pub fn validate_randomness_provider(randomness_provider: RandomnessProvider) -> CompetitionResult {
+    // slot hashes can be influenced by the leader, fine for local rounds but not real prizes
+    validate!(
+        randomness_provider != RandomnessProvider::SlotHash || cfg!(any(test, feature = "devnet")),
+        ErrorCode::InvalidRandomnessProvider,
+        "slot hash randomness is only available in devnet builds"
+    )?;
+
+    Ok(())
+}
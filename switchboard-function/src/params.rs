@@ -12,77 +12,21 @@ pub struct ContainerParams {
 }
 
 impl ContainerParams {
-    pub fn decode(container_params: &Vec<u8>) -> std::result::Result<Self, SwitchboardClientError> {
-        let params = String::from_utf8(container_params.clone()).unwrap();
-
-        let mut program_id: Pubkey = Pubkey::default();
-        let mut winner_min_result: u128 = 0;
-        let mut winner_max_result: u128 = 0;
-        let mut prize_min_result: u128 = 0;
-        let mut prize_max_result: u128 = 0;
-        let mut competition_key: Pubkey = Pubkey::default();
-        let mut spot_market_key: Pubkey = Pubkey::default();
-        let mut if_vault_key: Pubkey = Pubkey::default();
-
-        for env_pair in params.split(',') {
-            let pair: Vec<&str> = env_pair.splitn(2, '=').collect();
-            if pair.len() == 2 {
-                match pair[0] {
-                    "PID" => program_id = Pubkey::from_str(pair[1]).unwrap(),
-                    "WINNER_MIN" => winner_min_result = pair[1].parse::<u128>().unwrap(),
-                    "WINNER_MAX" => winner_max_result = pair[1].parse::<u128>().unwrap(),
-                    "PRIZE_MIN" => prize_min_result = pair[1].parse::<u128>().unwrap(),
-                    "PRIZE_MAX" => prize_max_result = pair[1].parse::<u128>().unwrap(),
-                    "COMPETITION" => competition_key = Pubkey::from_str(pair[1]).unwrap(),
-                    "SPOT_MARKET" => spot_market_key = Pubkey::from_str(pair[1]).unwrap(),
-                    "IF_VAULT" => if_vault_key = Pubkey::from_str(pair[1]).unwrap(),
-                    _ => {}
-                }
-            }
-        }
-
-        if program_id == Pubkey::default() {
-            return Err(SwitchboardClientError::CustomMessage(
-                "PID cannot be undefined".to_string(),
-            ));
-        }
-        if winner_max_result == 0 {
-            return Err(SwitchboardClientError::CustomMessage(
-                "WINNER_MAX must be greater than 0".to_string(),
-            ));
-        }
-        if prize_max_result == 0 {
-            return Err(SwitchboardClientError::CustomMessage(
-                "PRIZE_MAX must be greater than 0".to_string(),
-            ));
-        }
-        if competition_key == Pubkey::default() {
-            return Err(SwitchboardClientError::CustomMessage(
-                "COMPETITION cannot be undefined".to_string(),
-            ));
-        }
-
-        if spot_market_key == Pubkey::default() {
-            return Err(SwitchboardClientError::CustomMessage(
-                "SPOT_MARKET cannot be undefined".to_string(),
-            ));
-        }
-
-        if if_vault_key == Pubkey::default() {
-            return Err(SwitchboardClientError::CustomMessage(
-                "IF_VAULT cannot be undefined".to_string(),
-            ));
-        }
+    // versioned binary params written by the program's request_randomness
+    pub fn decode(container_params: &[u8]) -> std::result::Result<Self, SwitchboardClientError> {
+        let params = container_params::ContainerParams::decode(container_params).map_err(|e| {
+            SwitchboardClientError::CustomMessage(format!("invalid container params: {}", e))
+        })?;
 
         Ok(Self {
-            program_id,
-            winner_min_result,
-            winner_max_result,
-            prize_min_result,
-            prize_max_result,
-            competition_key,
-            spot_market_key,
-            if_vault_key,
+            program_id: Pubkey::new_from_array(params.program_id),
+            winner_min_result: params.winner_min,
+            winner_max_result: params.winner_max,
+            prize_min_result: params.prize_min,
+            prize_max_result: params.prize_max,
+            competition_key: Pubkey::new_from_array(params.competition),
+            spot_market_key: Pubkey::new_from_array(params.spot_market),
+            if_vault_key: Pubkey::new_from_array(params.if_vault),
         })
     }
 }
@@ -93,18 +37,18 @@ mod tests {
 
     #[test]
     fn test_params_decode() {
-        let request_params_string = format!(
-            "PID={},WINNER_MIN={},WINNER_MAX={},PRIZE_MIN={},PRIZE_MAX={},COMPETITION={},SPOT_MARKET={},IF_VAULT={}",
-            anchor_spl::token::ID,
-            1,
-            6,
-            1,
-            6,
-            anchor_spl::token::ID,
-            anchor_spl::token::ID,
-            anchor_spl::token::ID
-        );
-        let request_params_bytes = request_params_string.into_bytes();
+        let request_params_bytes = container_params::ContainerParams {
+            program_id: anchor_spl::token::ID.to_bytes(),
+            competition: anchor_spl::token::ID.to_bytes(),
+            spot_market: anchor_spl::token::ID.to_bytes(),
+            if_vault: anchor_spl::token::ID.to_bytes(),
+            winner_min: 1,
+            winner_max: 6,
+            prize_min: 1,
+            prize_max: 6,
+        }
+        .encode()
+        .unwrap();
 
         let params = ContainerParams::decode(&request_params_bytes).unwrap();
 
@@ -117,4 +61,14 @@ mod tests {
         assert_eq!(params.spot_market_key, anchor_spl::token::ID);
         assert_eq!(params.if_vault_key, anchor_spl::token::ID);
     }
+
+    #[test]
+    fn test_params_decode_malformed() {
+        // the old KEY=VALUE format errors instead of panicking
+        let legacy_params = format!("PID={},WINNER_MIN=1", anchor_spl::token::ID).into_bytes();
+        assert!(ContainerParams::decode(&legacy_params).is_err());
+
+        assert!(ContainerParams::decode(&[]).is_err());
+        assert!(ContainerParams::decode(&[container_params::CONTAINER_PARAMS_VERSION]).is_err());
+    }
 }
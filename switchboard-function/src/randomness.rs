@@ -0,0 +1,262 @@
+use crate::*;
+
+// draws are retried while they land in the biased tail of the u128 range, each attempt
+// is accepted with probability > 1/2 so this is only hit by a broken randomness source
+pub const MAX_RANDOMNESS_ATTEMPTS: u32 = 128;
+
+pub trait RandomSource {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) -> std::result::Result<(), SwitchboardClientError>;
+}
+
+// enclave randomness, used in production
+pub struct GramineRandomSource;
+
+impl RandomSource for GramineRandomSource {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) -> std::result::Result<(), SwitchboardClientError> {
+        Gramine::read_rand(bytes).map_err(|e| {
+            SwitchboardClientError::CustomMessage(format!(
+                "gramine failed to generate randomness: {:?}",
+                e
+            ))
+        })
+    }
+}
+
+// splitmix64, reproducible draws for local simulation and tests
+pub struct SeededRandomSource {
+    state: u64,
+}
+
+impl SeededRandomSource {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl RandomSource for SeededRandomSource {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) -> std::result::Result<(), SwitchboardClientError> {
+        for chunk in bytes.chunks_mut(8) {
+            self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = self.state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^= z >> 31;
+            chunk.copy_from_slice(&z.to_le_bytes()[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+fn next_u128(
+    random_source: &mut impl RandomSource,
+) -> std::result::Result<u128, SwitchboardClientError> {
+    let mut bytes = [0u8; 16];
+    random_source.fill_bytes(&mut bytes)?;
+    Ok(u128::from_le_bytes(bytes))
+}
+
+// uniform draw from [min, max], see generate_raw_randomness
+pub fn generate_randomness(
+    random_source: &mut impl RandomSource,
+    min: u128,
+    max: u128,
+) -> std::result::Result<u128, SwitchboardClientError> {
+    let raw_result = generate_raw_randomness(random_source, min, max)?;
+
+    // We add one so its inclusive [min, max]
+    Ok(match (max - min).checked_add(1) {
+        Some(window) => raw_result % window + min,
+        None => raw_result,
+    })
+}
+
+// raw u128 for a draw from [min, max] by rejection sampling: raw values at or above the largest
+// multiple of the window that fits in 2^128 are redrawn instead of folded in with %. the
+// program ranges the accepted raw value itself (raw % window + min) and refuses the tail
+pub fn generate_raw_randomness(
+    random_source: &mut impl RandomSource,
+    min: u128,
+    max: u128,
+) -> std::result::Result<u128, SwitchboardClientError> {
+    if min > max {
+        return Err(SwitchboardClientError::CustomMessage(format!(
+            "invalid randomness bounds: min {} > max {}",
+            min, max
+        )));
+    }
+
+    let window = match (max - min).checked_add(1) {
+        Some(window) => window,
+        // [0, u128::MAX], every raw value is fair
+        None => return next_u128(random_source),
+    };
+
+    // 2^128 % window, the size of the biased tail
+    let tail = (u128::MAX % window + 1) % window;
+    let max_unbiased = u128::MAX - tail;
+
+    for _ in 0..MAX_RANDOMNESS_ATTEMPTS {
+        let raw_result = next_u128(random_source)?;
+        if raw_result <= max_unbiased {
+            return Ok(raw_result);
+        }
+    }
+
+    Err(SwitchboardClientError::CustomMessage(format!(
+        "no unbiased draw for [{}, {}] after {} attempts",
+        min, max, MAX_RANDOMNESS_ATTEMPTS
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // replays fixed raw values
+    struct ScriptedRandomSource {
+        values: Vec<u128>,
+    }
+
+    impl RandomSource for ScriptedRandomSource {
+        fn fill_bytes(
+            &mut self,
+            bytes: &mut [u8],
+        ) -> std::result::Result<(), SwitchboardClientError> {
+            let value = self.values.remove(0);
+            bytes.copy_from_slice(&value.to_le_bytes());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_generate_randomness_with_flipped_bounds() {
+        let random_source = &mut SeededRandomSource::new(0);
+        assert!(generate_randomness(random_source, 100, 50).is_err());
+    }
+
+    #[test]
+    fn test_generate_randomness_with_equal_bounds() {
+        let random_source = &mut SeededRandomSource::new(0);
+        for bound in [0, 100, u128::MAX] {
+            assert_eq!(
+                generate_randomness(random_source, bound, bound).unwrap(),
+                bound
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_randomness_within_bounds() {
+        let random_source = &mut SeededRandomSource::new(0);
+        for _ in 0..1000 {
+            let result = generate_randomness(random_source, 100, 200).unwrap();
+            assert!((100..=200).contains(&result));
+        }
+
+        // full range doesn't overflow the window
+        let random_source = &mut ScriptedRandomSource {
+            values: vec![u128::MAX],
+        };
+        assert_eq!(
+            generate_randomness(random_source, 0, u128::MAX).unwrap(),
+            u128::MAX
+        );
+    }
+
+    #[test]
+    fn test_generate_randomness_rejects_biased_tail() {
+        // window of 3: 2^128 % 3 == 1, so only u128::MAX is in the tail
+        let random_source = &mut ScriptedRandomSource {
+            values: vec![u128::MAX, u128::MAX - 1, 7],
+        };
+        assert_eq!(
+            generate_randomness(random_source, 1, 3).unwrap(),
+            (u128::MAX - 1) % 3 + 1
+        );
+
+        // window of 5: 2^128 % 5 == 1 as well
+        let random_source = &mut ScriptedRandomSource {
+            values: vec![u128::MAX, 7],
+        };
+        assert_eq!(generate_randomness(random_source, 0, 4).unwrap(), 2);
+
+        // powers of two divide 2^128, nothing is rejected
+        let random_source = &mut ScriptedRandomSource {
+            values: vec![u128::MAX],
+        };
+        assert_eq!(generate_randomness(random_source, 0, 15).unwrap(), 15);
+
+        // a source stuck in the tail errors instead of looping forever
+        let random_source = &mut ScriptedRandomSource {
+            values: vec![u128::MAX; MAX_RANDOMNESS_ATTEMPTS as usize],
+        };
+        assert!(generate_randomness(random_source, 1, 3).is_err());
+    }
+
+    #[test]
+    fn test_generate_raw_randomness() {
+        // the raw value the program ranges, not the ranged draw
+        let random_source = &mut ScriptedRandomSource {
+            values: vec![u128::MAX, u128::MAX - 1],
+        };
+        assert_eq!(
+            generate_raw_randomness(random_source, 1, 3).unwrap(),
+            u128::MAX - 1
+        );
+
+        let random_source = &mut SeededRandomSource::new(3);
+        let raw_result = generate_raw_randomness(random_source, 1, 6).unwrap();
+        let random_source = &mut SeededRandomSource::new(3);
+        assert_eq!(
+            generate_randomness(random_source, 1, 6).unwrap(),
+            raw_result % 6 + 1
+        );
+    }
+
+    #[test]
+    fn test_generate_randomness_accepted_range_is_exact() {
+        // every outcome has the same number of accepted raw values
+        for window in [1_u128, 2, 3, 6, 7, 10, 31, 100, 1 << 64, (1 << 127) + 1] {
+            let tail = (u128::MAX % window + 1) % window;
+            let accepted = u128::MAX - tail; // number of accepted values - 1
+            assert_eq!(accepted % window, window - 1);
+        }
+    }
+
+    // chi-squared goodness of fit against the uniform distribution
+    fn chi_squared(counts: &[u64], samples: u64) -> f64 {
+        let expected = samples as f64 / counts.len() as f64;
+        counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum()
+    }
+
+    #[test]
+    fn test_generate_randomness_distribution() {
+        // small PRIZE_MAX style windows, 99.9th percentile of chi-squared with window - 1 dof
+        for (min, max, critical_value) in [(0_u128, 2_u128, 13.82), (1, 6, 20.52), (0, 9, 27.88)] {
+            let random_source = &mut SeededRandomSource::new(42);
+            let window = (max - min + 1) as usize;
+            let samples = 60_000;
+
+            let mut counts = vec![0_u64; window];
+            for _ in 0..samples {
+                let result = generate_randomness(random_source, min, max).unwrap();
+                counts[(result - min) as usize] += 1;
+            }
+
+            assert!(counts.iter().all(|&count| count > 0));
+            let statistic = chi_squared(&counts, samples);
+            assert!(
+                statistic < critical_value,
+                "[{}, {}] chi-squared {} >= {} ({:?})",
+                min,
+                max,
+                statistic,
+                critical_value,
+                counts
+            );
+        }
+    }
+}
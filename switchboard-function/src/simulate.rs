@@ -0,0 +1,275 @@
+use crate::*;
+use std::str::FromStr;
+
+pub const SIMULATE_USAGE: &str = "\
+usage: simple-randomness-function simulate (--params <hex> | --params-file <path>) [options]
+
+  --params <hex>         container params as hex (as stored on the function request)
+  --params-file <path>   file holding the container params, raw bytes or hex
+  --seed <u64>           seed for the local random source (default 0)
+  --function <pubkey>    switchboard function account (default: default pubkey)
+  --request <pubkey>     switchboard function request account (default: default pubkey)
+  --signer <pubkey>      enclave signer account in the instruction (default: default pubkey)
+
+the instruction is for inspection only: receive_randomness checks the signer against the
+function's enclave signer, so a transaction signed by any other key fails on-chain";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SimulateOptions {
+    pub container_params: Vec<u8>,
+    pub seed: u64,
+    pub function: Pubkey,
+    pub function_request: Pubkey,
+    pub enclave_signer: Pubkey,
+}
+
+impl SimulateOptions {
+    pub fn parse(args: &[String]) -> std::result::Result<Self, SwitchboardClientError> {
+        let mut options = SimulateOptions::default();
+        let mut container_params = None;
+
+        let mut args = args.iter();
+        while let Some(flag) = args.next() {
+            let value = args.next().ok_or_else(|| {
+                SwitchboardClientError::CustomMessage(format!("missing value for {}", flag))
+            })?;
+
+            match flag.as_str() {
+                "--params" => container_params = Some(decode_hex(value)?),
+                "--params-file" => {
+                    let bytes = std::fs::read(value).map_err(|e| {
+                        SwitchboardClientError::CustomMessage(format!(
+                            "failed to read {}: {}",
+                            value, e
+                        ))
+                    })?;
+                    container_params = Some(decode_params_file(bytes)?);
+                }
+                "--seed" => {
+                    options.seed = value.parse().map_err(|_| {
+                        SwitchboardClientError::CustomMessage(format!("invalid seed {}", value))
+                    })?
+                }
+                "--function" => options.function = parse_pubkey(flag, value)?,
+                "--request" => options.function_request = parse_pubkey(flag, value)?,
+                "--signer" => options.enclave_signer = parse_pubkey(flag, value)?,
+                _ => {
+                    return Err(SwitchboardClientError::CustomMessage(format!(
+                        "unknown argument {}",
+                        flag
+                    )))
+                }
+            }
+        }
+
+        options.container_params = container_params.ok_or_else(|| {
+            SwitchboardClientError::CustomMessage(
+                "one of --params or --params-file is required".to_string(),
+            )
+        })?;
+
+        Ok(options)
+    }
+}
+
+// same params decoding and instruction as the enclave, with a seeded random source
+pub fn simulate(
+    options: &SimulateOptions,
+) -> std::result::Result<Instruction, SwitchboardClientError> {
+    let params = ContainerParams::decode(&options.container_params)?;
+
+    generate_receive_randomness_ixn(
+        &mut SeededRandomSource::new(options.seed),
+        &params,
+        options.function,
+        options.function_request,
+        options.enclave_signer,
+    )
+}
+
+// json, for inspecting the accounts and data (not submittable, see SIMULATE_USAGE)
+pub fn format_instruction(ixn: &Instruction) -> String {
+    let accounts: Vec<String> = ixn
+        .accounts
+        .iter()
+        .map(|account| {
+            format!(
+                "{{\"pubkey\":\"{}\",\"isSigner\":{},\"isWritable\":{}}}",
+                account.pubkey, account.is_signer, account.is_writable
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"programId\":\"{}\",\"accounts\":[{}],\"data\":\"{}\"}}",
+        ixn.program_id,
+        accounts.join(","),
+        encode_hex(&ixn.data)
+    )
+}
+
+fn parse_pubkey(flag: &str, value: &str) -> std::result::Result<Pubkey, SwitchboardClientError> {
+    Pubkey::from_str(value).map_err(|_| {
+        SwitchboardClientError::CustomMessage(format!("invalid pubkey for {}: {}", flag, value))
+    })
+}
+
+// params files can be dumped straight from the request account or written as hex
+fn decode_params_file(bytes: Vec<u8>) -> std::result::Result<Vec<u8>, SwitchboardClientError> {
+    let hex_params = std::str::from_utf8(&bytes)
+        .ok()
+        .and_then(|text| decode_hex(text.trim()).ok());
+
+    Ok(hex_params.unwrap_or(bytes))
+}
+
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn decode_hex(hex: &str) -> std::result::Result<Vec<u8>, SwitchboardClientError> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() % 2 != 0 {
+        return Err(SwitchboardClientError::CustomMessage(
+            "hex params must have an even number of digits".to_string(),
+        ));
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| {
+                    SwitchboardClientError::CustomMessage(format!("invalid hex at offset {}", i))
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_params() -> Vec<u8> {
+        container_params::ContainerParams {
+            program_id: anchor_spl::token::ID.to_bytes(),
+            competition: Pubkey::new_unique().to_bytes(),
+            spot_market: Pubkey::new_unique().to_bytes(),
+            if_vault: Pubkey::new_unique().to_bytes(),
+            winner_min: 1,
+            winner_max: 6,
+            prize_min: 0,
+            prize_max: 31,
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let bytes = sample_params();
+        assert_eq!(decode_hex(&encode_hex(&bytes)).unwrap(), bytes);
+        assert_eq!(decode_hex("0x00ff").unwrap(), vec![0, 255]);
+        assert!(decode_hex("abc").is_err());
+        assert!(decode_hex("zz").is_err());
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let params = sample_params();
+        let signer = Pubkey::new_unique();
+
+        let options = SimulateOptions::parse(&args(&[
+            "--params",
+            &encode_hex(&params),
+            "--seed",
+            "7",
+            "--signer",
+            &signer.to_string(),
+        ]))
+        .unwrap();
+        assert_eq!(options.container_params, params);
+        assert_eq!(options.seed, 7);
+        assert_eq!(options.enclave_signer, signer);
+        assert_eq!(options.function, Pubkey::default());
+
+        assert!(SimulateOptions::parse(&args(&["--seed", "7"])).is_err());
+        assert!(SimulateOptions::parse(&args(&["--params"])).is_err());
+        assert!(SimulateOptions::parse(&args(&["--params", "00", "--bogus", "1"])).is_err());
+        assert!(SimulateOptions::parse(&args(&["--params", "00", "--signer", "nope"])).is_err());
+    }
+
+    #[test]
+    fn test_params_file() {
+        let params = sample_params();
+
+        // raw bytes as dumped from the request account
+        assert_eq!(decode_params_file(params.clone()).unwrap(), params);
+        // hex with a trailing newline
+        let hex_file = format!("{}\n", encode_hex(&params)).into_bytes();
+        assert_eq!(decode_params_file(hex_file).unwrap(), params);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let params = sample_params();
+        let options = SimulateOptions {
+            container_params: params.clone(),
+            seed: 7,
+            enclave_signer: Pubkey::new_unique(),
+            ..SimulateOptions::default()
+        };
+
+        let ixn = simulate(&options).unwrap();
+        assert_eq!(ixn.program_id, anchor_spl::token::ID);
+        assert_eq!(ixn.accounts.len(), 6);
+        assert!(ixn.accounts[0].is_writable);
+        assert_eq!(ixn.accounts[5].pubkey, options.enclave_signer);
+        assert!(ixn.accounts[5].is_signer);
+
+        assert_eq!(ixn.data.len(), 8 + 16 + 16);
+        assert_eq!(
+            &ixn.data[..8],
+            &get_ixn_discriminator("receive_randomness")[..]
+        );
+        // raw values, ranged by the program
+        let winner_result = u128::from_le_bytes(ixn.data[8..24].try_into().unwrap());
+        let prize_result = u128::from_le_bytes(ixn.data[24..40].try_into().unwrap());
+        let random_source = &mut SeededRandomSource::new(7);
+        assert_eq!(
+            generate_randomness(random_source, 1, 6).unwrap(),
+            winner_result % 6 + 1
+        );
+        assert_eq!(
+            generate_randomness(random_source, 0, 31).unwrap(),
+            prize_result % 32
+        );
+
+        // same seed, same draw
+        assert_eq!(simulate(&options).unwrap(), ixn);
+        let other_seed = SimulateOptions {
+            container_params: params,
+            seed: 8,
+            ..SimulateOptions::default()
+        };
+        assert_ne!(simulate(&other_seed).unwrap().data, ixn.data);
+
+        let formatted = format_instruction(&ixn);
+        assert!(formatted.contains(&format!("\"programId\":\"{}\"", anchor_spl::token::ID)));
+        assert!(formatted.contains(&encode_hex(&ixn.data)));
+    }
+
+    #[test]
+    fn test_simulate_malformed_params() {
+        let options = SimulateOptions {
+            container_params: b"PID=11111111111111111111111111111111".to_vec(),
+            ..SimulateOptions::default()
+        };
+        assert!(simulate(&options).is_err());
+    }
+}
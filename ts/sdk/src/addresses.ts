@@ -1,5 +1,6 @@
 import { PublicKey } from '@solana/web3.js';
 import * as anchor from '@coral-xyz/anchor';
+import { BPF_LOADER_UPGRADEABLE_PROGRAM_ID } from './constants';
 
 export function getCompetitionAddressSync(
 	programId: PublicKey,
@@ -41,3 +42,66 @@ export function getCompetitionAuthorityAddressSync(
 		programId
 	)[0];
 }
+
+export function getCompetitionRegistryAddressSync(
+	programId: PublicKey
+): PublicKey {
+	return PublicKey.findProgramAddressSync(
+		[Buffer.from(anchor.utils.bytes.utf8.encode('competition_registry'))],
+		programId
+	)[0];
+}
+
+export function getCompetitionRegistryPageAddressSync(
+	programId: PublicKey,
+	pageNumber: number
+): PublicKey {
+	const pageNumberBuffer = Buffer.alloc(4);
+	pageNumberBuffer.writeUInt32LE(pageNumber);
+	return PublicKey.findProgramAddressSync(
+		[
+			Buffer.from(anchor.utils.bytes.utf8.encode('competition_registry_page')),
+			pageNumberBuffer,
+		],
+		programId
+	)[0];
+}
+
+export function getCompetitionKeeperAddressSync(
+	programId: PublicKey,
+	competition: PublicKey,
+	keeperAuthority: PublicKey
+): PublicKey {
+	return PublicKey.findProgramAddressSync(
+		[
+			Buffer.from(anchor.utils.bytes.utf8.encode('competition_keeper')),
+			competition.toBuffer(),
+			keeperAuthority.toBuffer(),
+		],
+		programId
+	)[0];
+}
+
+export function getTicketIndexPageAddressSync(
+	programId: PublicKey,
+	competition: PublicKey,
+	pageNumber: number
+): PublicKey {
+	const pageNumberBuffer = Buffer.alloc(4);
+	pageNumberBuffer.writeUInt32LE(pageNumber);
+	return PublicKey.findProgramAddressSync(
+		[
+			Buffer.from(anchor.utils.bytes.utf8.encode('ticket_index_page')),
+			competition.toBuffer(),
+			pageNumberBuffer,
+		],
+		programId
+	)[0];
+}
+
+export function getProgramDataAddressSync(programId: PublicKey): PublicKey {
+	return PublicKey.findProgramAddressSync(
+		[programId.toBuffer()],
+		BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+	)[0];
+}
@@ -13,14 +13,19 @@ import {
 	getSpotMarketVaultPublicKey,
 	SpotMarketAccount,
 	PRICE_PRECISION,
+	isVariant,
 } from '@drift-labs/sdk';
-import { Program } from '@coral-xyz/anchor';
+import { IdlAccounts, Program } from '@coral-xyz/anchor';
 import { DriftCompetitions, IDL } from './types/drift_competitions';
 import {
+	AccountMeta,
 	ComputeBudgetProgram,
+	Keypair,
 	PublicKey,
 	SetComputeUnitLimitParams,
+	Signer,
 	SYSVAR_RENT_PUBKEY,
+	SYSVAR_SLOT_HASHES_PUBKEY,
 	Transaction,
 	TransactionInstruction,
 	TransactionSignature,
@@ -29,13 +34,31 @@ import { encodeName } from './name';
 import {
 	getCompetitionAddressSync,
 	getCompetitionAuthorityAddressSync,
+	getCompetitionKeeperAddressSync,
+	getCompetitionRegistryAddressSync,
+	getCompetitionRegistryPageAddressSync,
 	getCompetitorAddressSync,
+	getProgramDataAddressSync,
+	getTicketIndexPageAddressSync,
 } from './addresses';
 import * as anchor from '@coral-xyz/anchor';
-import { DRIFT_COMPETITION_PROGRAM_ID } from './constants';
+import {
+	DRIFT_COMPETITION_PROGRAM_ID,
+	TICKET_INDEX_PAGE_SIZE,
+} from './constants';
 import { LogParser } from './parsers';
-import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import {
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
 import { sleep } from './utils';
+import {
+	CompetitorStatus,
+	RandomnessProvider,
+	RoundScheduleMode,
+} from './types/types';
+
+type CompetitionAccount = IdlAccounts<DriftCompetitions>['competition'];
 
 export class CompetitionsClient {
 	driftClient: DriftClient;
@@ -98,45 +121,432 @@ export class CompetitionsClient {
 			})
 			.accounts({
 				competition: competitionAddress,
+				sponsor: this.program.provider.publicKey,
+				payer: this.program.provider.publicKey,
 			})
 			.rpc();
 	}
 
+	/**
+	 * Round params (maxEntriesPerCompetitor, minSponsorAmount, maxSponsorFraction, numberOfWinners)
+	 * apply at the next round boundary. Scheduling moved to updateCompetitionSchedule
+	 */
 	public async updateCompetition(
 		competition: PublicKey,
 		{
-			nextRoundExpiryTs = null,
-			competitionExpiryTs = null,
-			roundDuration = null,
 			maxEntriesPerCompetitor = null,
 			minSponsorAmount = null,
 			maxSponsorFraction = null,
 			numberOfWinners = null,
-			resetRoundState = null,
+			winnerBadgesEnabled = null,
+			keeperAllowlistEnabled = null,
+			keeperReward = null,
+			ticketIndexEnabled = null,
+			randomnessProvider = null,
+			operator = null,
+			moderator = null,
 		}: {
-			nextRoundExpiryTs?: BN | null;
-			competitionExpiryTs?: BN | null;
-			roundDuration?: BN | null;
 			maxEntriesPerCompetitor?: BN | null;
 			minSponsorAmount?: BN | null;
 			maxSponsorFraction?: BN | null;
 			numberOfWinners?: number | null;
-			resetRoundState?: boolean | null;
+			winnerBadgesEnabled?: boolean | null;
+			keeperAllowlistEnabled?: boolean | null;
+			keeperReward?: BN | null;
+			ticketIndexEnabled?: boolean | null;
+			randomnessProvider?: RandomnessProvider | null;
+			operator?: PublicKey | null;
+			moderator?: PublicKey | null;
 		}
 	): Promise<TransactionSignature> {
 		return await this.program.methods
 			.updateCompetition({
-				nextRoundExpiryTs,
-				competitionExpiryTs,
-				roundDuration,
 				maxEntriesPerCompetitor,
 				minSponsorAmount,
 				maxSponsorFraction,
 				numberOfWinners,
-				resetRoundState,
+				winnerBadgesEnabled,
+				keeperAllowlistEnabled,
+				keeperReward,
+				ticketIndexEnabled,
+				randomnessProvider,
+				operator,
+				moderator,
 			})
 			.accounts({
 				competition: competition,
+				sponsor: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	public async updateCompetitionSchedule(
+		competition: PublicKey,
+		{
+			nextRoundExpiryTs = null,
+			competitionExpiryTs = null,
+			roundDuration = null,
+			settlementGracePeriod = null,
+			winnerSettlementTimeout = null,
+			randomnessRequestTimeout = null,
+			maxRandomnessRequestRetries = null,
+			resetRoundState = null,
+		}: {
+			nextRoundExpiryTs?: BN | null;
+			competitionExpiryTs?: BN | null;
+			roundDuration?: BN | null;
+			settlementGracePeriod?: number | null;
+			winnerSettlementTimeout?: number | null;
+			randomnessRequestTimeout?: number | null;
+			maxRandomnessRequestRetries?: number | null;
+			resetRoundState?: boolean | null;
+		}
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.updateCompetitionSchedule({
+				nextRoundExpiryTs,
+				competitionExpiryTs,
+				roundDuration,
+				settlementGracePeriod,
+				winnerSettlementTimeout,
+				randomnessRequestTimeout,
+				maxRandomnessRequestRetries,
+				resetRoundState,
+			})
+			.accounts({
+				competition,
+				operator: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	public async updateRoundSchedule(
+		competition: PublicKey,
+		{
+			mode = null,
+			weekdayMask = null,
+			hour = null,
+			minute = null,
+			clearExpiries = null,
+			addExpiries = [],
+		}: {
+			mode?: RoundScheduleMode | null;
+			weekdayMask?: number | null;
+			hour?: number | null;
+			minute?: number | null;
+			clearExpiries?: boolean | null;
+			addExpiries?: BN[];
+		}
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.updateRoundSchedule({
+				mode,
+				weekdayMask,
+				hour,
+				minute,
+				clearExpiries,
+				addExpiries,
+			})
+			.accounts({
+				competition,
+				operator: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	public async pauseCompetition(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.pauseCompetition()
+			.accounts({
+				competition,
+				operator: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	public async resumeCompetition(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.resumeCompetition()
+			.accounts({
+				competition,
+				operator: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	public async proposeSponsor(
+		competition: PublicKey,
+		pendingSponsor: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.proposeSponsor(pendingSponsor)
+			.accounts({
+				competition,
+				sponsor: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	public async acceptSponsor(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.acceptSponsor()
+			.accounts({
+				competition,
+				pendingSponsor: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	public async updateCompetitorStatus(
+		competition: PublicKey,
+		competitor: PublicKey,
+		userStats: PublicKey,
+		newStatus: CompetitorStatus
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.updateCompetitorStatus(newStatus)
+			.accounts({
+				competition,
+				moderator: this.program.provider.publicKey,
+				competitor,
+				driftUserStats: userStats,
+			})
+			.rpc();
+	}
+
+	public async addCompetitionKeeper(
+		competition: PublicKey,
+		keeperAuthority: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.addCompetitionKeeper()
+			.accounts({
+				competitionKeeper: this.getCompetitionKeeperPublicKey(
+					competition,
+					keeperAuthority
+				),
+				competition,
+				keeperAuthority,
+				operator: this.program.provider.publicKey,
+				rent: SYSVAR_RENT_PUBKEY,
+				systemProgram: anchor.web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	public async removeCompetitionKeeper(
+		competition: PublicKey,
+		keeperAuthority: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.removeCompetitionKeeper()
+			.accounts({
+				competitionKeeper: this.getCompetitionKeeperPublicKey(
+					competition,
+					keeperAuthority
+				),
+				competition,
+				keeperAuthority,
+				competitionAuthority: getCompetitionAuthorityAddressSync(
+					this.program.programId,
+					competition
+				),
+				operator: this.program.provider.publicKey,
+				systemProgram: anchor.web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	public async claimKeeperReward(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.claimKeeperReward()
+			.accounts({
+				keeper: this.program.provider.publicKey,
+				competitionKeeper: this.getCompetitionKeeperPublicKey(competition),
+				competition,
+				competitionAuthority: getCompetitionAuthorityAddressSync(
+					this.program.programId,
+					competition
+				),
+				systemProgram: anchor.web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	/**
+	 * commitment is sha256(secret), revealed with revealRandomness once the round's randomness is requested
+	 */
+	public async commitRandomness(
+		competition: PublicKey,
+		commitment: number[]
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.commitRandomness(commitment)
+			.accounts({
+				competition,
+				sponsor: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Admin ix, signed by the program upgrade authority
+	 */
+	public async initializeCompetitionRegistry(): Promise<TransactionSignature> {
+		return await this.program.methods
+			.initializeCompetitionRegistry()
+			.accounts({
+				competitionRegistry: getCompetitionRegistryAddressSync(
+					this.program.programId
+				),
+				program: this.program.programId,
+				programData: getProgramDataAddressSync(this.program.programId),
+				admin: this.program.provider.publicKey,
+				rent: SYSVAR_RENT_PUBKEY,
+				systemProgram: anchor.web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Admin ix, signed by the registry admin. Pages are added in order as they fill up
+	 */
+	public async initializeCompetitionRegistryPage(
+		pageNumber: number
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.initializeCompetitionRegistryPage(pageNumber)
+			.accounts({
+				competitionRegistryPage: getCompetitionRegistryPageAddressSync(
+					this.program.programId,
+					pageNumber
+				),
+				competitionRegistry: getCompetitionRegistryAddressSync(
+					this.program.programId
+				),
+				admin: this.program.provider.publicKey,
+				rent: SYSVAR_RENT_PUBKEY,
+				systemProgram: anchor.web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Admin ix, signed by the registry admin. Lists on the last page by default
+	 */
+	public async registerCompetition(
+		competition: PublicKey,
+		pageNumber?: number
+	): Promise<TransactionSignature> {
+		const competitionRegistry = getCompetitionRegistryAddressSync(
+			this.program.programId
+		);
+		if (pageNumber === undefined) {
+			const registry = await this.program.account.competitionRegistry.fetch(
+				competitionRegistry
+			);
+			pageNumber = registry.numberOfPages - 1;
+		}
+
+		return await this.program.methods
+			.registerCompetition()
+			.accounts({
+				competition,
+				competitionRegistry,
+				competitionRegistryPage: getCompetitionRegistryPageAddressSync(
+					this.program.programId,
+					pageNumber
+				),
+				admin: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Admin ix, signed by the registry admin
+	 */
+	public async deregisterCompetition(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		const competitionAccount = await this.program.account.competition.fetch(
+			competition
+		);
+
+		return await this.program.methods
+			.deregisterCompetition()
+			.accounts({
+				competition,
+				competitionRegistry: getCompetitionRegistryAddressSync(
+					this.program.programId
+				),
+				competitionRegistryPage: getCompetitionRegistryPageAddressSync(
+					this.program.programId,
+					competitionAccount.registryPageNumber
+				),
+				admin: this.program.provider.publicKey,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Every listed competition, across all registry pages
+	 */
+	public async getRegisteredCompetitions(): Promise<PublicKey[]> {
+		const registry = await this.program.account.competitionRegistry.fetch(
+			getCompetitionRegistryAddressSync(this.program.programId)
+		);
+
+		const pages =
+			await this.program.account.competitionRegistryPage.fetchMultiple(
+				[...Array(registry.numberOfPages).keys()].map((pageNumber) =>
+					getCompetitionRegistryPageAddressSync(
+						this.program.programId,
+						pageNumber
+					)
+				)
+			);
+
+		return pages.flatMap((page) =>
+			page ? page.competitions.slice(0, page.numberOfCompetitions) : []
+		);
+	}
+
+	/**
+	 * Registry page a competition has to be delisted from when it closes
+	 */
+	getOptionalRegistryPage(competitionAccount: CompetitionAccount): PublicKey {
+		return this.getOptionalAccount(
+			competitionAccount.registered
+				? getCompetitionRegistryPageAddressSync(
+						this.program.programId,
+						competitionAccount.registryPageNumber
+				  )
+				: undefined
+		);
+	}
+
+	/**
+	 * Admin ix, signed by the program upgrade authority. Grows a competition created before the current layout
+	 */
+	public async migrateCompetition(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.migrateCompetition()
+			.accounts({
+				competition,
+				program: this.program.programId,
+				programData: getProgramDataAddressSync(this.program.programId),
+				admin: this.program.provider.publicKey,
+				systemProgram: anchor.web3.SystemProgram.programId,
 			})
 			.rpc();
 	}
@@ -349,34 +759,167 @@ export class CompetitionsClient {
 		return await this.createAndSendTxn(instructions);
 	}
 
-	public async settleCompetitor(
-		competition: PublicKey,
-		competitor: PublicKey,
-		userStats: PublicKey
+	public async closeCompetitor(
+		competition: PublicKey
 	): Promise<TransactionSignature> {
 		return await this.program.methods
-			.settleCompetitor()
+			.closeCompetitor()
 			.accounts({
+				authority: this.program.provider.publicKey,
+				competitor: getCompetitorAddressSync(
+					this.program.programId,
+					competition,
+					this.program.provider.publicKey
+				),
 				competition,
-				competitor,
-				driftUserStats: userStats,
 			})
 			.rpc();
 	}
 
-	public async settleAllCompetitors(
-		competition: PublicKey,
-		roundNumber: BN,
-		chunkSize = 1,
-		claimEntryMax = 0
-	): Promise<void> {
-		const competitorProgramAccounts =
-			await this.program.account.competitor.all();
-		let instructions = [];
-
-		let claimEntryCount = 0;
-		for (const competitor of competitorProgramAccounts) {
-			if (competitor.account.competition.equals(competition)) {
+	/**
+	 * Settles the signer's own competitor once the round ends, optionally claiming the free entry first
+	 */
+	public async selfSettleCompetitor({
+		competition,
+		claimEntry = false,
+	}: {
+		competition: PublicKey;
+		claimEntry?: boolean;
+	}): Promise<TransactionSignature> {
+		const competitionAccount = await this.program.account.competition.fetch(
+			competition
+		);
+
+		const ix = await this.program.methods
+			.selfSettleCompetitor(claimEntry)
+			.accounts({
+				authority: this.program.provider.publicKey,
+				competitor: getCompetitorAddressSync(
+					this.program.programId,
+					competition,
+					this.program.provider.publicKey
+				),
+				competition,
+				driftUserStats: this.driftClient.getUserStatsAccountPublicKey(),
+				ticketIndexPage: this.getOptionalAccount(
+					this.getNextTicketIndexPage(competition, competitionAccount)
+				),
+				instructions: claimEntry
+					? anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY
+					: this.program.programId,
+			})
+			.instruction();
+
+		return await this.createAndSendTxn([ix], {
+			// claiming the entry needs to be a standalone ix in a tx
+			noComputeBudgetIx: claimEntry,
+		});
+	}
+
+	public async settleCompetitor(
+		competition: PublicKey,
+		competitor: PublicKey,
+		userStats: PublicKey,
+		competitionKeeper?: PublicKey
+	): Promise<TransactionSignature> {
+		const competitionAccount = await this.program.account.competition.fetch(
+			competition
+		);
+
+		return await this.program.methods
+			.settleCompetitor()
+			.accounts({
+				keeper: this.program.provider.publicKey,
+				competition,
+				competitor,
+				driftUserStats: userStats,
+				competitionKeeper: this.getOptionalAccount(competitionKeeper),
+				ticketIndexPage: this.getOptionalAccount(
+					this.getNextTicketIndexPage(competition, competitionAccount)
+				),
+			})
+			.rpc();
+	}
+
+	/**
+	 * Settles up to MAX_SETTLE_COMPETITORS_BATCH_SIZE (10) competitors in one ix. With the ticket index
+	 * enabled a batch can't cross a page, so split batches on TICKET_INDEX_PAGE_SIZE boundaries
+	 */
+	public async settleCompetitorsBatch(
+		competition: PublicKey,
+		competitors: { competitor: PublicKey; userStats: PublicKey }[],
+		competitionKeeper?: PublicKey
+	): Promise<TransactionSignature> {
+		const competitionAccount = await this.program.account.competition.fetch(
+			competition
+		);
+
+		const remainingAccounts: AccountMeta[] = competitors.flatMap(
+			({ competitor, userStats }) => [
+				{ pubkey: competitor, isWritable: true, isSigner: false },
+				{ pubkey: userStats, isWritable: false, isSigner: false },
+			]
+		);
+
+		const ix = await this.program.methods
+			.settleCompetitorsBatch()
+			.accounts({
+				keeper: this.program.provider.publicKey,
+				competition,
+				competitionKeeper: this.getOptionalAccount(competitionKeeper),
+				ticketIndexPage: this.getOptionalAccount(
+					this.getNextTicketIndexPage(competition, competitionAccount)
+				),
+			})
+			.remainingAccounts(remainingAccounts)
+			.instruction();
+
+		return await this.createAndSendTxn([ix], {
+			computeUnitParams: {
+				units: 80_000 * competitors.length,
+			},
+		});
+	}
+
+	public async initializeTicketIndexPage(
+		competition: PublicKey,
+		pageNumber: number
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.initializeTicketIndexPage(pageNumber)
+			.accounts({
+				ticketIndexPage: getTicketIndexPageAddressSync(
+					this.program.programId,
+					competition,
+					pageNumber
+				),
+				competition,
+				payer: this.program.provider.publicKey,
+				rent: SYSVAR_RENT_PUBKEY,
+				systemProgram: anchor.web3.SystemProgram.programId,
+			})
+			.rpc();
+	}
+
+	public async settleAllCompetitors(
+		competition: PublicKey,
+		roundNumber: BN,
+		chunkSize = 1,
+		claimEntryMax = 0,
+		competitionKeeper?: PublicKey
+	): Promise<void> {
+		const competitionAccount = await this.program.account.competition.fetch(
+			competition
+		);
+		const competitorProgramAccounts =
+			await this.program.account.competitor.all();
+		let instructions = [];
+		// with the ticket index, each settlement records to the page of its position
+		let position = competitionAccount.numberOfCompetitorsSettled.toNumber();
+
+		let claimEntryCount = 0;
+		for (const competitor of competitorProgramAccounts) {
+			if (competitor.account.competition.equals(competition)) {
 				if (
 					roundNumber &&
 					!competitor.account.competitionRoundNumber.eq(roundNumber)
@@ -397,12 +940,23 @@ export class CompetitionsClient {
 					);
 					claimEntryCount += 1;
 				}
+				const ticketIndexPage =
+					competitionAccount.ticketIndexEnabled !== 0
+						? getTicketIndexPageAddressSync(
+								this.program.programId,
+								competition,
+								Math.floor(position / TICKET_INDEX_PAGE_SIZE)
+						  )
+						: undefined;
+				position += 1;
 				const initCompetitorIx = this.program.instruction.settleCompetitor({
 					accounts: {
 						competition: competitor.account.competition,
 						competitor: competitor.publicKey,
 						driftUserStats: competitor.account.userStats,
 						keeper: this.program.provider.publicKey,
+						competitionKeeper: this.getOptionalAccount(competitionKeeper),
+						ticketIndexPage: this.getOptionalAccount(ticketIndexPage),
 					},
 				});
 				instructions.push(initCompetitorIx);
@@ -433,86 +987,447 @@ export class CompetitionsClient {
 		}
 	}
 
-	public async settleNextWinner(competition: PublicKey): Promise<void> {
+	/**
+	 * Finds the competitor holding winnerRandomness and settles them. A competitor that already
+	 * won this round is settled too, the program redraws instead of paying them twice
+	 */
+	public async settleNextWinner(
+		competition: PublicKey,
+		competitionKeeper?: PublicKey
+	): Promise<void> {
 		const competitionAccount = await this.program.account.competition.fetch(
 			competition
 		);
 
-		if (competitionAccount.winnerRandomness.gt(ZERO)) {
-			const spotMarket = await getSpotMarketPublicKey(
-				this.driftClient.program.programId,
-				QUOTE_SPOT_MARKET_INDEX
+		if (
+			!isVariant(
+				competitionAccount.status,
+				'winnerAndPrizeRandomnessComplete'
+			) ||
+			!competitionAccount.winnerRandomness.gt(ZERO)
+		) {
+			return;
+		}
+
+		let txSig: TransactionSignature;
+		let winnerAuthority: PublicKey;
+		if (competitionAccount.ticketIndexEnabled !== 0) {
+			const { pageNumber, ticketRange } = await this.findWinnerTicketIndexPage(
+				competition,
+				competitionAccount
 			);
-			const insuranceFundVault = await getInsuranceFundVaultPublicKey(
-				this.driftClient.program.programId,
-				QUOTE_SPOT_MARKET_INDEX
+			const competitor = await this.program.account.competitor.fetch(
+				ticketRange.competitor
 			);
+			winnerAuthority = competitor.authority;
+			txSig = await this.settleWinnerByIndex(
+				competition,
+				ticketRange.competitor,
+				competitor.userStats,
+				pageNumber,
+				competitionKeeper
+			);
+		} else {
+			const winner = await this.findRoundWinner(
+				competition,
+				competitionAccount
+			);
+			if (!winner) {
+				console.log(
+					`No competitor holds winnerRandomness ${competitionAccount.winnerRandomness.toString()}`
+				);
+				return;
+			}
+			winnerAuthority = winner.account.authority;
+			txSig = await this.settleWinner(
+				competition,
+				winner.publicKey,
+				winner.account.userStats,
+				competitionKeeper
+			);
+		}
 
-			const competitorProgramAccounts =
-				await this.program.account.competitor.all();
-			for (const competitor of competitorProgramAccounts) {
-				if (
-					competitor.account.competition.equals(competition) &&
-					competitor.account.minDraw.lt(competitionAccount.winnerRandomness) &&
-					competitor.account.maxDraw.gte(competitionAccount.winnerRandomness)
-				) {
-					let instructions = [];
-					const initCompetitorIx = this.program.instruction.settleWinner({
-						accounts: {
-							keeper: this.program.provider.publicKey,
-							competition,
-							competitor: competitor.publicKey,
-							driftUserStats: competitor.account.userStats,
-							spotMarket,
-							insuranceFundVault,
-						},
-					});
-					instructions.push(initCompetitorIx);
-
-					const txSig = await this.createAndSendTxn(instructions, {
-						computeUnitParams: {
-							units: 20_000,
-						},
-					});
-					console.log(
-						`Settled winner authority ${competitor.account.authority.toBase58()}:`,
-						txSig
-					);
-				}
+		console.log(
+			`Settled winner authority ${winnerAuthority.toBase58()}:`,
+			txSig
+		);
+	}
+
+	/**
+	 * Finds this round's settled competitor whose ticket range holds winnerRandomness
+	 */
+	public async findRoundWinner(
+		competition: PublicKey,
+		competitionAccount: CompetitionAccount
+	) {
+		// settled competitors are moved to the next round number
+		const settledRoundNumber = competitionAccount.roundNumber.addn(1);
+		return (await this.program.account.competitor.all()).find(
+			(competitor) =>
+				competitor.account.competition.equals(competition) &&
+				competitor.account.competitionRoundNumber.eq(settledRoundNumber) &&
+				competitionAccount.winnerRandomness.gt(competitor.account.minDraw) &&
+				competitionAccount.winnerRandomness.lte(competitor.account.maxDraw)
+		);
+	}
+
+	/**
+	 * Binary searches the round's pages for winnerRandomness, pages hold ascending ticket ranges
+	 * in settlement order (see TicketIndexPage::find_winner)
+	 */
+	public async findWinnerTicketIndexPage(
+		competition: PublicKey,
+		competitionAccount: CompetitionAccount
+	) {
+		const winnerRandomness = competitionAccount.winnerRandomness;
+
+		let low = 0;
+		let high =
+			Math.ceil(
+				competitionAccount.numberOfCompetitorsSettled.toNumber() /
+					TICKET_INDEX_PAGE_SIZE
+			) - 1;
+		while (low <= high) {
+			const pageNumber = Math.floor((low + high) / 2);
+			const page = await this.program.account.ticketIndexPage.fetch(
+				getTicketIndexPageAddressSync(
+					this.program.programId,
+					competition,
+					pageNumber
+				)
+			);
+			const entries = page.entries.slice(0, page.numberOfEntries);
+
+			if (
+				!page.roundNumber.eq(competitionAccount.roundNumber) ||
+				entries.length === 0
+			) {
+				throw new Error(
+					`Ticket index page ${pageNumber} is not indexed for round ${competitionAccount.roundNumber.toString()}`
+				);
+			}
+
+			if (winnerRandomness.lte(entries[0].minDraw)) {
+				high = pageNumber - 1;
+			} else if (winnerRandomness.gt(entries[entries.length - 1].maxDraw)) {
+				low = pageNumber + 1;
+			} else {
+				const ticketRange = entries.find(
+					(entry) =>
+						winnerRandomness.gt(entry.minDraw) &&
+						winnerRandomness.lte(entry.maxDraw)
+				);
+				return { pageNumber, ticketRange };
 			}
 		}
+
+		throw new Error(
+			`No ticket index entry holds winnerRandomness ${winnerRandomness.toString()}`
+		);
 	}
 
 	public async settleWinner(
 		competition: PublicKey,
 		competitor: PublicKey,
-		userStats: PublicKey
+		userStats: PublicKey,
+		competitionKeeper?: PublicKey
 	): Promise<TransactionSignature> {
-		const spotMarket = await getSpotMarketPublicKey(
-			this.driftClient.program.programId,
-			QUOTE_SPOT_MARKET_INDEX
-		);
-		const insuranceFundVault = await getInsuranceFundVaultPublicKey(
-			this.driftClient.program.programId,
-			QUOTE_SPOT_MARKET_INDEX
+		const { accounts, signers } = await this.getSettleWinnerAccounts(
+			competition,
+			competitor,
+			userStats,
+			competitionKeeper
 		);
+
 		const instr = await this.program.methods
 			.settleWinner()
+			.accounts(accounts)
+			.instruction();
+		return await this.createAndSendTxn([instr], {
+			computeUnitParams: {
+				units: 400_000,
+			},
+			signers,
+		});
+	}
+
+	/**
+	 * Settles the winner resolved by ticket index page pageNumber
+	 */
+	public async settleWinnerByIndex(
+		competition: PublicKey,
+		competitor: PublicKey,
+		userStats: PublicKey,
+		pageNumber: number,
+		competitionKeeper?: PublicKey
+	): Promise<TransactionSignature> {
+		const { accounts, signers } = await this.getSettleWinnerAccounts(
+			competition,
+			competitor,
+			userStats,
+			competitionKeeper
+		);
+
+		const instr = await this.program.methods
+			.settleWinnerByIndex()
 			.accounts({
-				competitor,
-				competition: competition,
-				driftUserStats: userStats,
-				spotMarket,
-				insuranceFundVault: insuranceFundVault,
+				settleWinner: accounts,
+				ticketIndexPage: getTicketIndexPageAddressSync(
+					this.program.programId,
+					competition,
+					pageNumber
+				),
 			})
 			.instruction();
 		return await this.createAndSendTxn([instr], {
 			computeUnitParams: {
 				units: 400_000,
 			},
+			signers,
 		});
 	}
 
+	async getSettleWinnerAccounts(
+		competition: PublicKey,
+		competitor: PublicKey,
+		userStats: PublicKey,
+		competitionKeeper?: PublicKey
+	) {
+		const competitionAccount = await this.program.account.competition.fetch(
+			competition
+		);
+		const spotMarket = await getSpotMarketPublicKey(
+			this.driftClient.program.programId,
+			QUOTE_SPOT_MARKET_INDEX
+		);
+		const insuranceFundVault = await getInsuranceFundVaultPublicKey(
+			this.driftClient.program.programId,
+			QUOTE_SPOT_MARKET_INDEX
+		);
+
+		const accounts = {
+			keeper: this.program.provider.publicKey,
+			competitor,
+			competition,
+			driftUserStats: userStats,
+			spotMarket,
+			insuranceFundVault,
+			competitionKeeper: this.getOptionalAccount(competitionKeeper),
+			winnerBadgeMint: this.program.programId,
+			winnerBadgeTokenAccount: this.program.programId,
+			winnerAuthority: this.program.programId,
+			competitionAuthority: this.program.programId,
+			tokenProgram: this.program.programId,
+			associatedTokenProgram: this.program.programId,
+			systemProgram: this.program.programId,
+		};
+		const signers: Signer[] = [];
+
+		if (competitionAccount.winnerBadgesEnabled !== 0) {
+			const competitorAccount = await this.program.account.competitor.fetch(
+				competitor
+			);
+			const winnerBadgeMint = Keypair.generate();
+			accounts.winnerBadgeMint = winnerBadgeMint.publicKey;
+			accounts.winnerBadgeTokenAccount =
+				await anchor.utils.token.associatedAddress({
+					mint: winnerBadgeMint.publicKey,
+					owner: competitorAccount.authority,
+				});
+			accounts.winnerAuthority = competitorAccount.authority;
+			accounts.competitionAuthority = competitionAccount.competitionAuthority;
+			accounts.tokenProgram = TOKEN_PROGRAM_ID;
+			accounts.associatedTokenProgram = ASSOCIATED_TOKEN_PROGRAM_ID;
+			accounts.systemProgram = anchor.web3.SystemProgram.programId;
+			signers.push(winnerBadgeMint);
+		}
+
+		return { accounts, signers };
+	}
+
+	/**
+	 * Redraws a winner left unsettled past the skip timeout
+	 */
+	public async skipWinner(
+		competition: PublicKey,
+		competitionKeeper?: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.skipWinner()
+			.accounts({
+				keeper: this.program.provider.publicKey,
+				competition,
+				competitionKeeper: this.getOptionalAccount(competitionKeeper),
+			})
+			.rpc();
+	}
+
+	public async expireCompetition(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.expireCompetition()
+			.accounts({
+				keeper: this.program.provider.publicKey,
+				competition,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Requests the round's randomness from the sponsor's commitment, see revealRandomness.
+	 * Pins the slot hash right after, it has to be pinned within the slot hashes window
+	 */
+	public async requestCommitRevealRandomness(
+		competition: PublicKey,
+		competitionKeeper?: PublicKey
+	): Promise<TransactionSignature> {
+		const txSig = await this.program.methods
+			.requestCommitRevealRandomness()
+			.accounts({
+				competition,
+				keeper: this.program.provider.publicKey,
+				competitionKeeper: this.getOptionalAccount(competitionKeeper),
+				...(await this.getInsuranceFundAccounts()),
+			})
+			.rpc();
+
+		// the slot after the request may not have landed yet
+		for (let attempt = 0; attempt < 5; attempt++) {
+			try {
+				await this.pinRandomnessSlotHash(competition);
+				break;
+			} catch (e) {
+				console.log('couldnt pinRandomnessSlotHash', e);
+				await sleep(1000);
+			}
+		}
+
+		return txSig;
+	}
+
+	/**
+	 * Pins the hash of the first slot after the request before the slot hashes sysvar rotates it out
+	 */
+	public async pinRandomnessSlotHash(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.pinRandomnessSlotHash()
+			.accounts({
+				competition,
+				keeper: this.program.provider.publicKey,
+				slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+			})
+			.rpc();
+	}
+
+	public async revealRandomness(
+		competition: PublicKey,
+		secret: number[]
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.revealRandomness(secret)
+			.accounts({
+				competition,
+				revealer: this.program.provider.publicKey,
+				slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+				...(await this.getInsuranceFundAccounts()),
+			})
+			.rpc();
+	}
+
+	/**
+	 * After the reveal deadline: revokes commit-reveal, re-request with SwitchboardClient.requestRandomness.
+	 * If nobody pinned the slot hash within the slot hashes window, falls back without revoking
+	 */
+	public async resolveMissedReveal(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.resolveMissedReveal()
+			.accounts({
+				competition,
+				keeper: this.program.provider.publicKey,
+				slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+			})
+			.rpc();
+	}
+
+	/**
+	 * Devnet builds only
+	 */
+	public async drawSlotHashRandomness(
+		competition: PublicKey,
+		competitionKeeper?: PublicKey
+	): Promise<TransactionSignature> {
+		return await this.program.methods
+			.drawSlotHashRandomness()
+			.accounts({
+				competition,
+				keeper: this.program.provider.publicKey,
+				competitionKeeper: this.getOptionalAccount(competitionKeeper),
+				slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+				...(await this.getInsuranceFundAccounts()),
+			})
+			.rpc();
+	}
+
+	async getInsuranceFundAccounts(): Promise<{
+		spotMarket: PublicKey;
+		insuranceFundVault: PublicKey;
+	}> {
+		return {
+			spotMarket: await getSpotMarketPublicKey(
+				this.driftClient.program.programId,
+				QUOTE_SPOT_MARKET_INDEX
+			),
+			insuranceFundVault: await getInsuranceFundVaultPublicKey(
+				this.driftClient.program.programId,
+				QUOTE_SPOT_MARKET_INDEX
+			),
+		};
+	}
+
+	public getCompetitionKeeperPublicKey(
+		competition: PublicKey,
+		keeperAuthority = this.program.provider.publicKey
+	): PublicKey {
+		return getCompetitionKeeperAddressSync(
+			this.program.programId,
+			competition,
+			keeperAuthority
+		);
+	}
+
+	/**
+	 * Page the next settlement records to, when the ticket index is enabled
+	 */
+	getNextTicketIndexPage(
+		competition: PublicKey,
+		competitionAccount: CompetitionAccount
+	): PublicKey | undefined {
+		if (competitionAccount.ticketIndexEnabled === 0) {
+			return undefined;
+		}
+
+		return getTicketIndexPageAddressSync(
+			this.program.programId,
+			competition,
+			Math.floor(
+				competitionAccount.numberOfCompetitorsSettled.toNumber() /
+					TICKET_INDEX_PAGE_SIZE
+			)
+		);
+	}
+
+	/**
+	 * Anchor reads the program id as an omitted optional account
+	 */
+	getOptionalAccount(account?: PublicKey): PublicKey {
+		return account ?? this.program.programId;
+	}
+
 	public getCompetitionPublicKey(name: string): PublicKey {
 		const encodedName = encodeName(name);
 		return getCompetitionAddressSync(this.program.programId, encodedName);
@@ -588,6 +1503,7 @@ export class CompetitionsClient {
 		txOpts?: {
 			computeUnitParams?: SetComputeUnitLimitParams;
 			noComputeBudgetIx?: boolean;
+			signers?: Signer[];
 		}
 	): Promise<TransactionSignature> {
 		const tx = new Transaction();
@@ -608,7 +1524,7 @@ export class CompetitionsClient {
 		tx.add(...ixs);
 		const { txSig } = await this.driftClient.sendTransaction(
 			tx,
-			[],
+			txOpts?.signers ?? [],
 			this.driftClient.opts
 		);
 
@@ -6,3 +6,10 @@ export { DriftCompetitionsErrors };
 export const DRIFT_COMPETITION_PROGRAM_ID = new PublicKey(
 	'DraWMeQX9LfzQQSYoeBwHAgM5JcqFkgrX7GbTfjzVMVL'
 );
+
+export const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+	'BPFLoaderUpgradeab1e11111111111111111111111'
+);
+
+// page size of the on-chain ticket index, see TicketIndexPage
+export const TICKET_INDEX_PAGE_SIZE = 32;
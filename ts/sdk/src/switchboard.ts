@@ -23,7 +23,8 @@ export class SwitchboardClient {
 
 	public async requestRandomness(
 		competition: PublicKey,
-		bounty?: BN
+		bounty?: BN,
+		competitionKeeper?: PublicKey
 	): Promise<TransactionSignature> {
 		const switchboardProgram = await SwitchboardProgram.fromProvider(
 			// @ts-ignore
@@ -57,6 +58,9 @@ export class SwitchboardClient {
 			.requestRandomness(bounty ?? null)
 			.accounts({
 				competition,
+				keeper: this.provider.publicKey,
+				competitionKeeper:
+					this.competitionsClient.getOptionalAccount(competitionKeeper),
 				switchboard: switchboardProgram.attestationProgramId,
 				switchboardState: switchboardProgram.attestationProgramState.publicKey,
 				switchboardAttestationQueue: attestationQueueAddress,
@@ -123,4 +127,66 @@ export class SwitchboardClient {
 			.signers([switchboardRequestKeypair])
 			.rpc();
 	}
+
+	/**
+	 * Closes a competition that has no competitors left, along with its switchboard function request
+	 */
+	public async closeCompetition(
+		competition: PublicKey
+	): Promise<TransactionSignature> {
+		const competitionAccount =
+			await this.competitionsClient.program.account.competition.fetch(
+				competition
+			);
+		const sponsor = this.provider.publicKey;
+
+		const switchboardAccounts = {
+			switchboard: this.competitionsClient.getOptionalAccount(),
+			switchboardState: this.competitionsClient.getOptionalAccount(),
+			switchboardFunction: this.competitionsClient.getOptionalAccount(),
+			switchboardRequest: this.competitionsClient.getOptionalAccount(),
+			switchboardRequestEscrow: this.competitionsClient.getOptionalAccount(),
+			sponsorTokenAccount: this.competitionsClient.getOptionalAccount(),
+			tokenProgram: this.competitionsClient.getOptionalAccount(),
+			systemProgram: this.competitionsClient.getOptionalAccount(),
+		};
+
+		if (
+			!competitionAccount.switchboardFunctionRequest.equals(PublicKey.default)
+		) {
+			const switchboardProgram = await SwitchboardProgram.fromProvider(
+				// @ts-ignore
+				this.provider
+			);
+
+			switchboardAccounts.switchboard = switchboardProgram.attestationProgramId;
+			switchboardAccounts.switchboardState =
+				switchboardProgram.attestationProgramState.publicKey;
+			switchboardAccounts.switchboardFunction =
+				competitionAccount.switchboardFunction;
+			switchboardAccounts.switchboardRequest =
+				competitionAccount.switchboardFunctionRequest;
+			switchboardAccounts.switchboardRequestEscrow =
+				competitionAccount.switchboardFunctionRequestEscrow;
+			switchboardAccounts.sponsorTokenAccount =
+				await anchor.utils.token.associatedAddress({
+					mint: switchboardProgram.mint.address,
+					owner: sponsor,
+				});
+			switchboardAccounts.tokenProgram = anchor.utils.token.TOKEN_PROGRAM_ID;
+			switchboardAccounts.systemProgram = anchor.web3.SystemProgram.programId;
+		}
+
+		return await this.competitionsClient.program.methods
+			.closeCompetition()
+			.accounts({
+				sponsor,
+				competition,
+				competitionAuthority: competitionAccount.competitionAuthority,
+				competitionRegistryPage:
+					this.competitionsClient.getOptionalRegistryPage(competitionAccount),
+				...switchboardAccounts,
+			})
+			.rpc();
+	}
 }
@@ -2,6 +2,161 @@ export type DriftCompetitions = {
 	version: '0.1.0';
 	name: 'drift_competitions';
 	instructions: [
+		{
+			name: 'initializeCompetitionRegistry';
+			accounts: [
+				{
+					name: 'competitionRegistry';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'program';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'programData';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'admin';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeCompetitionRegistryPage';
+			accounts: [
+				{
+					name: 'competitionRegistryPage';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competitionRegistry';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'admin';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'pageNumber';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'registerCompetition';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competitionRegistry';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competitionRegistryPage';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'admin';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'deregisterCompetition';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competitionRegistry';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'competitionRegistryPage';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'admin';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'migrateCompetition';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'program';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'programData';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'admin';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
 		{
 			name: 'initializeCompetition';
 			accounts: [
@@ -63,6 +218,52 @@ export type DriftCompetitions = {
 				}
 			];
 		},
+		{
+			name: 'updateCompetitionSchedule';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'operator';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'UpdateCompetitionScheduleParams';
+					};
+				}
+			];
+		},
+		{
+			name: 'updateRoundSchedule';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'operator';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'UpdateRoundScheduleParams';
+					};
+				}
+			];
+		},
 		{
 			name: 'updateSwitchboardFunction';
 			accounts: [
@@ -86,11 +287,13 @@ export type DriftCompetitions = {
 					name: 'switchboard';
 					isMut: false;
 					isSigner: false;
+					docs: ['CHECK: is switchboard program'];
 				},
 				{
 					name: 'switchboardState';
 					isMut: false;
 					isSigner: false;
+					docs: ['CHECK:'];
 				},
 				{
 					name: 'switchboardAttestationQueue';
@@ -105,12 +308,14 @@ export type DriftCompetitions = {
 				{
 					name: 'switchboardRequest';
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
+					docs: ['CHECK: checked din cpi'];
 				},
 				{
 					name: 'switchboardRequestEscrow';
 					isMut: true;
 					isSigner: false;
+					docs: ['CHECK: checked din cpi'];
 				},
 				{
 					name: 'switchboardMint';
@@ -144,7 +349,7 @@ export type DriftCompetitions = {
 					isSigner: false;
 				},
 				{
-					name: 'sponsor';
+					name: 'moderator';
 					isMut: false;
 					isSigner: true;
 				},
@@ -169,217 +374,259 @@ export type DriftCompetitions = {
 			];
 		},
 		{
-			name: 'initializeCompetitor';
+			name: 'pauseCompetition';
 			accounts: [
-				{
-					name: 'competitor';
-					isMut: true;
-					isSigner: false;
-				},
 				{
 					name: 'competition';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'authority';
+					name: 'operator';
 					isMut: false;
 					isSigner: true;
-				},
-				{
-					name: 'payer';
-					isMut: true;
-					isSigner: true;
-				},
-				{
-					name: 'rent';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'systemProgram';
-					isMut: false;
-					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'claimEntry';
+			name: 'resumeCompetition';
 			accounts: [
-				{
-					name: 'authority';
-					isMut: true;
-					isSigner: true;
-				},
-				{
-					name: 'competitor';
-					isMut: true;
-					isSigner: false;
-				},
 				{
 					name: 'competition';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'instructions';
+					name: 'operator';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'claimMultipleEntries';
+			name: 'closeCompetition';
 			accounts: [
 				{
-					name: 'authority';
+					name: 'sponsor';
 					isMut: true;
 					isSigner: true;
 				},
 				{
-					name: 'competitor';
+					name: 'competition';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'competition';
+					name: 'competitionAuthority';
+					isMut: false;
+					isSigner: false;
+					docs: ['CHECK'];
+				},
+				{
+					name: 'competitionRegistryPage';
 					isMut: true;
 					isSigner: false;
+					isOptional: true;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'switchboard';
 					isMut: false;
 					isSigner: false;
+					isOptional: true;
+					docs: ['CHECK: program ID checked.'];
 				},
 				{
-					name: 'driftState';
+					name: 'switchboardState';
 					isMut: false;
 					isSigner: false;
+					isOptional: true;
+					docs: ['CHECK: checked in cpi'];
 				},
 				{
-					name: 'spotMarket';
+					name: 'switchboardFunction';
 					isMut: true;
 					isSigner: false;
+					isOptional: true;
+					docs: ['CHECK: checked in cpi'];
 				},
 				{
-					name: 'spotMarketVault';
+					name: 'switchboardRequest';
 					isMut: true;
 					isSigner: false;
+					isOptional: true;
+					docs: ['CHECK: cpi checks'];
 				},
 				{
-					name: 'userTokenAccount';
+					name: 'switchboardRequestEscrow';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+					docs: ['CHECK: cpi checks'];
+				},
+				{
+					name: 'sponsorTokenAccount';
 					isMut: true;
 					isSigner: false;
+					isOptional: true;
+					docs: [
+						'CHECK: sponsor\'s wrapped SOL token account, checked in cpi'
+					];
 				},
 				{
 					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
+					isOptional: true;
 				},
 				{
-					name: 'driftProgram';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
+					isOptional: true;
 				}
 			];
-			args: [
+			args: [];
+		},
+		{
+			name: 'proposeSponsor';
+			accounts: [
 				{
-					name: 'entries';
-					type: 'u64';
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'sponsor';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'pendingSponsor';
+					type: 'publicKey';
 				}
 			];
 		},
 		{
-			name: 'claimWinnings';
+			name: 'acceptSponsor';
 			accounts: [
 				{
-					name: 'authority';
+					name: 'competition';
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'competitor';
+					name: 'pendingSponsor';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'addCompetitionKeeper';
+			accounts: [
+				{
+					name: 'competitionKeeper';
 					isMut: true;
 					isSigner: false;
 				},
 				{
 					name: 'competition';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: true;
+					name: 'keeperAuthority';
+					isMut: false;
 					isSigner: false;
+					docs: ['CHECK: keeper signer to allow'];
 				},
 				{
-					name: 'spotMarket';
+					name: 'operator';
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'insuranceFundVault';
+					name: 'rent';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'removeCompetitionKeeper';
+			accounts: [
+				{
+					name: 'competitionKeeper';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'competition';
 					isMut: false;
 					isSigner: false;
 				},
+				{
+					name: 'keeperAuthority';
+					isMut: true;
+					isSigner: false;
+					docs: ['CHECK: checked against competition_keeper'];
+				},
 				{
 					name: 'competitionAuthority';
-					isMut: false;
+					isMut: true;
 					isSigner: false;
 					docs: ['CHECK'];
 				},
 				{
-					name: 'driftTransferConfig';
+					name: 'operator';
 					isMut: true;
-					isSigner: false;
-					docs: ['CHECK in cpi'];
+					isSigner: true;
 				},
 				{
-					name: 'driftState';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
-					docs: ['CHECK in cpi'];
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'commitRandomness';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'sponsor';
+					isMut: false;
+					isSigner: true;
 				}
 			];
 			args: [
 				{
-					name: 'nShares';
+					name: 'commitment';
 					type: {
-						option: 'u64';
+						array: ['u8', 32];
 					};
 				}
 			];
 		},
 		{
-			name: 'settleCompetitor';
+			name: 'initializeCompetitor';
 			accounts: [
-				{
-					name: 'keeper';
-					isMut: true;
-					isSigner: true;
-				},
 				{
 					name: 'competitor';
 					isMut: true;
@@ -394,66 +641,104 @@ export type DriftCompetitions = {
 					name: 'driftUserStats';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'requestRandomness';
+			name: 'claimEntry';
 			accounts: [
 				{
-					name: 'competition';
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'competitor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'keeper';
+					name: 'competition';
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'competitionAuthority';
+					name: 'driftUserStats';
 					isMut: false;
 					isSigner: false;
-					docs: ['CHECK'];
 				},
 				{
-					name: 'spotMarket';
+					name: 'instructions';
 					isMut: false;
 					isSigner: false;
+					docs: ['CHECK: fixed instructions sysvar account'];
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'claimMultipleEntries';
+			accounts: [
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'insuranceFundVault';
-					isMut: false;
+					name: 'competitor';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'switchboard';
-					isMut: false;
+					name: 'competition';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'switchboardState';
+					name: 'driftUserStats';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'switchboardAttestationQueue';
+					name: 'driftState';
 					isMut: false;
 					isSigner: false;
+					docs: ['CHECK: checked in cpi'];
 				},
 				{
-					name: 'switchboardFunction';
+					name: 'spotMarket';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'switchboardRequest';
+					name: 'spotMarketVault';
 					isMut: true;
 					isSigner: false;
+					docs: ['CHECK: checked in cpi'];
 				},
 				{
-					name: 'switchboardRequestEscrow';
+					name: 'userTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
@@ -463,31 +748,44 @@ export type DriftCompetitions = {
 					isSigner: false;
 				},
 				{
-					name: 'systemProgram';
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'bounty';
-					type: {
-						option: 'u64';
-					};
+					name: 'entries';
+					type: 'u64';
 				}
 			];
 		},
 		{
-			name: 'receiveRandomness';
+			name: 'claimWinnings';
 			accounts: [
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'competitor';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'competition';
 					isMut: true;
 					isSigner: false;
 				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'spotMarket';
-					isMut: false;
+					isMut: true;
 					isSigner: false;
 				},
 				{
@@ -496,38 +794,70 @@ export type DriftCompetitions = {
 					isSigner: false;
 				},
 				{
-					name: 'switchboardFunction';
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'switchboardRequest';
+					name: 'competitionAuthority';
 					isMut: false;
 					isSigner: false;
+					docs: ['CHECK'];
 				},
 				{
-					name: 'enclaveSigner';
+					name: 'driftTransferConfig';
+					isMut: true;
+					isSigner: false;
+					docs: ['CHECK in cpi'];
+				},
+				{
+					name: 'driftState';
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
+					docs: ['CHECK in cpi'];
 				}
 			];
 			args: [
 				{
-					name: 'winnerRandomness';
-					type: 'u128';
+					name: 'nShares';
+					type: {
+						option: 'u64';
+					};
+				}
+			];
+		},
+		{
+			name: 'closeCompetitor';
+			accounts: [
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'prizeRandomness';
-					type: 'u128';
+					name: 'competitor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
 				}
 			];
+			args: [];
 		},
 		{
-			name: 'settleWinner';
+			name: 'selfSettleCompetitor';
 			accounts: [
 				{
-					name: 'keeper';
-					isMut: true;
+					name: 'authority';
+					isMut: false;
 					isSigner: true;
 				},
 				{
@@ -546,741 +876,3701 @@ export type DriftCompetitions = {
 					isSigner: false;
 				},
 				{
-					name: 'spotMarket';
+					name: 'ticketIndexPage';
 					isMut: true;
 					isSigner: false;
+					isOptional: true;
 				},
 				{
-					name: 'insuranceFundVault';
+					name: 'instructions';
 					isMut: false;
 					isSigner: false;
+					isOptional: true;
+					docs: [
+						'CHECK: fixed instructions sysvar account (required to claim the free entry)'
+					];
 				}
 			];
-			args: [];
-		}
-	];
-	accounts: [
+			args: [
+				{
+					name: 'claimEntry';
+					type: 'bool';
+				}
+			];
+		},
 		{
-			name: 'competition';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						type: {
-							array: ['u8', 32];
-						};
+			name: 'initializeTicketIndexPage';
+			accounts: [
+				{
+					name: 'ticketIndexPage';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competition';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'pageNumber';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'settleCompetitor';
+			accounts: [
+				{
+					name: 'keeper';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'competitor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'competitionKeeper';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'ticketIndexPage';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'settleCompetitorsBatch';
+			accounts: [
+				{
+					name: 'keeper';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competitionKeeper';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'ticketIndexPage';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'requestRandomness';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'keeper';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'competitionAuthority';
+					isMut: false;
+					isSigner: false;
+					docs: ['CHECK'];
+				},
+				{
+					name: 'competitionKeeper';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'spotMarket';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'switchboard';
+					isMut: false;
+					isSigner: false;
+					docs: ['CHECK: program ID checked.'];
+				},
+				{
+					name: 'switchboardState';
+					isMut: false;
+					isSigner: false;
+					docs: ['CHECK:'];
+				},
+				{
+					name: 'switchboardAttestationQueue';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'switchboardFunction';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'switchboardRequest';
+					isMut: true;
+					isSigner: false;
+					docs: ['CHECK: cpi checks'];
+				},
+				{
+					name: 'switchboardRequestEscrow';
+					isMut: true;
+					isSigner: false;
+					docs: ['CHECK: cpi checks'];
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'bounty';
+					type: {
+						option: 'u64';
+					};
+				}
+			];
+		},
+		{
+			name: 'receiveRandomness';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'spotMarket';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'switchboardFunction';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'switchboardRequest';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'enclaveSigner';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'winnerRandomnessRaw';
+					type: 'u128';
+				},
+				{
+					name: 'prizeRandomnessRaw';
+					type: 'u128';
+				}
+			];
+		},
+		{
+			name: 'requestCommitRevealRandomness';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'keeper';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'competitionKeeper';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'spotMarket';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'pinRandomnessSlotHash';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'keeper';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'slotHashes';
+					isMut: false;
+					isSigner: false;
+					docs: ['CHECK: slot hashes sysvar, read in place'];
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'revealRandomness';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'revealer';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'spotMarket';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'slotHashes';
+					isMut: false;
+					isSigner: false;
+					docs: ['CHECK: slot hashes sysvar, read in place'];
+				}
+			];
+			args: [
+				{
+					name: 'secret';
+					type: {
+						array: ['u8', 32];
+					};
+				}
+			];
+		},
+		{
+			name: 'resolveMissedReveal';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'keeper';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'slotHashes';
+					isMut: false;
+					isSigner: false;
+					docs: ['CHECK: slot hashes sysvar, read in place'];
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'drawSlotHashRandomness';
+			accounts: [
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'keeper';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'competitionKeeper';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'spotMarket';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'slotHashes';
+					isMut: false;
+					isSigner: false;
+					docs: ['CHECK: slot hashes sysvar, read in place'];
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'settleWinner';
+			accounts: [
+				{
+					name: 'keeper';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'competitor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'spotMarket';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'competitionKeeper';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'winnerBadgeMint';
+					isMut: true;
+					isSigner: true;
+					isOptional: true;
+				},
+				{
+					name: 'winnerBadgeTokenAccount';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+					docs: ['CHECK: checked in cpi'];
+				},
+				{
+					name: 'winnerAuthority';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+					docs: ['CHECK: checked against competitor authority'];
+				},
+				{
+					name: 'competitionAuthority';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+					docs: ['CHECK'];
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'associatedTokenProgram';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'settleWinnerByIndex';
+			accounts: [
+				{
+					name: 'settleWinner';
+					accounts: [
+						{
+							name: 'keeper';
+							isMut: true;
+							isSigner: true;
+						},
+						{
+							name: 'competitor';
+							isMut: true;
+							isSigner: false;
+						},
+						{
+							name: 'competition';
+							isMut: true;
+							isSigner: false;
+						},
+						{
+							name: 'driftUserStats';
+							isMut: false;
+							isSigner: false;
+						},
+						{
+							name: 'spotMarket';
+							isMut: true;
+							isSigner: false;
+						},
+						{
+							name: 'insuranceFundVault';
+							isMut: false;
+							isSigner: false;
+						},
+						{
+							name: 'competitionKeeper';
+							isMut: true;
+							isSigner: false;
+							isOptional: true;
+						},
+						{
+							name: 'winnerBadgeMint';
+							isMut: true;
+							isSigner: true;
+							isOptional: true;
+						},
+						{
+							name: 'winnerBadgeTokenAccount';
+							isMut: true;
+							isSigner: false;
+							isOptional: true;
+							docs: ['CHECK: checked in cpi'];
+						},
+						{
+							name: 'winnerAuthority';
+							isMut: false;
+							isSigner: false;
+							isOptional: true;
+							docs: ['CHECK: checked against competitor authority'];
+						},
+						{
+							name: 'competitionAuthority';
+							isMut: false;
+							isSigner: false;
+							isOptional: true;
+							docs: ['CHECK'];
+						},
+						{
+							name: 'tokenProgram';
+							isMut: false;
+							isSigner: false;
+							isOptional: true;
+						},
+						{
+							name: 'associatedTokenProgram';
+							isMut: false;
+							isSigner: false;
+							isOptional: true;
+						},
+						{
+							name: 'systemProgram';
+							isMut: false;
+							isSigner: false;
+							isOptional: true;
+						}
+					];
+				},
+				{
+					name: 'ticketIndexPage';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'skipWinner';
+			accounts: [
+				{
+					name: 'keeper';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competitionKeeper';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'expireCompetition';
+			accounts: [
+				{
+					name: 'keeper';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'competition';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'claimKeeperReward';
+			accounts: [
+				{
+					name: 'keeper';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'competitionKeeper';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'competition';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'competitionAuthority';
+					isMut: true;
+					isSigner: false;
+					docs: ['CHECK'];
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		}
+	];
+	accounts: [
+		{
+			name: 'competition';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'sponsorInfo';
+						type: {
+							defined: 'SponsorInfo';
+						};
+					},
+					{
+						name: 'switchboardFunction';
+						type: 'publicKey';
+					},
+					{
+						name: 'switchboardFunctionRequest';
+						type: 'publicKey';
+					},
+					{
+						name: 'switchboardFunctionRequestEscrow';
+						type: 'publicKey';
+					},
+					{
+						name: 'competitionAuthority';
+						type: 'publicKey';
+					},
+					{
+						name: 'numberOfCompetitors';
+						type: 'u128';
+					},
+					{
+						name: 'numberOfCompetitorsSettled';
+						type: 'u128';
+					},
+					{
+						name: 'totalScoreSettled';
+						type: 'u128';
+					},
+					{
+						name: 'maxEntriesPerCompetitor';
+						type: 'u128';
+					},
+					{
+						name: 'prizeAmount';
+						type: 'u128';
+					},
+					{
+						name: 'prizeAmountSettled';
+						type: 'u128';
+					},
+					{
+						name: 'prizeBase';
+						type: 'u128';
+					},
+					{
+						name: 'winnerRandomness';
+						type: 'u128';
+					},
+					{
+						name: 'prizeRandomness';
+						type: 'u128';
+					},
+					{
+						name: 'prizeRandomnessMax';
+						type: 'u128';
+					},
+					{
+						name: 'outstandingUnclaimedWinnings';
+						type: 'u128';
+					},
+					{
+						name: 'roundNumber';
+						type: 'u64';
+					},
+					{
+						name: 'nextRoundExpiryTs';
+						type: 'i64';
+					},
+					{
+						name: 'competitionExpiryTs';
+						type: 'i64';
+					},
+					{
+						name: 'roundDuration';
+						type: 'u64';
+					},
+					{
+						name: 'numberOfWinners';
+						type: 'u32';
+					},
+					{
+						name: 'numberOfWinnersSettled';
+						type: 'u32';
+					},
+					{
+						name: 'status';
+						type: {
+							defined: 'CompetitionRoundStatus';
+						};
+					},
+					{
+						name: 'competitionAuthorityBump';
+						type: 'u8';
+					},
+					{
+						name: 'winnerBadgesEnabled';
+						type: 'u8';
+					},
+					{
+						name: 'statusBeforePause';
+						type: {
+							defined: 'CompetitionRoundStatus';
+						};
+					},
+					{
+						name: 'keeperAllowlistEnabled';
+						type: 'u8';
+					},
+					{
+						name: 'ticketIndexEnabled';
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 2];
+						};
+					},
+					{
+						name: 'pausedTs';
+						type: 'i64';
+					},
+					{
+						name: 'pendingSponsor';
+						type: 'publicKey';
+					},
+					{
+						name: 'operator';
+						type: 'publicKey';
+					},
+					{
+						name: 'moderator';
+						type: 'publicKey';
+					},
+					{
+						name: 'keeperReward';
+						type: 'u64';
+					},
+					{
+						name: 'pendingRoundParams';
+						type: {
+							defined: 'PendingRoundParams';
+						};
+					},
+					{
+						name: 'roundSchedule';
+						type: {
+							defined: 'RoundSchedule';
+						};
+					},
+					{
+						name: 'winningScoreSettled';
+						type: 'u128';
+					},
+					{
+						name: 'winnerRedraws';
+						type: 'u32';
+					},
+					{
+						name: 'padding2';
+						type: {
+							array: ['u8', 4];
+						};
+					},
+					{
+						name: 'winnerDrawTs';
+						type: 'i64';
+					},
+					{
+						name: 'randomnessRequestedTs';
+						type: 'i64';
+					},
+					{
+						name: 'randomnessRequestedSlot';
+						type: 'u64';
+					},
+					{
+						name: 'randomnessRequestTimeout';
+						type: 'u32';
+					},
+					{
+						name: 'randomnessRequestRetries';
+						type: 'u8';
+					},
+					{
+						name: 'maxRandomnessRequestRetries';
+						type: 'u8';
+					},
+					{
+						name: 'padding3';
+						type: {
+							array: ['u8', 2];
+						};
+					},
+					{
+						name: 'numberOfCompetitorsForfeited';
+						type: 'u128';
+					},
+					{
+						name: 'settlementGracePeriod';
+						type: 'u32';
+					},
+					{
+						name: 'winnerSettlementTimeout';
+						type: 'u32';
+					},
+					{
+						name: 'randomnessProvider';
+						type: {
+							defined: 'RandomnessProvider';
+						};
+					},
+					{
+						name: 'randomnessRevealsMissed';
+						type: 'u8';
+					},
+					{
+						name: 'padding5';
+						type: {
+							array: ['u8', 6];
+						};
+					},
+					{
+						name: 'randomnessCommitment';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'randomnessCommitmentRoundNumber';
+						type: 'u64';
+					},
+					{
+						name: 'randomnessSlotHash';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'randomnessSlotHashSlot';
+						type: 'u64';
+					},
+					{
+						name: 'winnerRandomnessDelivered';
+						type: 'u128';
+					},
+					{
+						name: 'prizeRandomnessDelivered';
+						type: 'u128';
+					},
+					{
+						name: 'winnerRandomnessMax';
+						type: 'u128';
+					},
+					{
+						name: 'prizeDraw';
+						type: 'u128';
+					},
+					{
+						name: 'roundStartTs';
+						type: 'i64';
+					},
+					{
+						name: 'registryPageNumber';
+						type: 'u32';
+					},
+					{
+						name: 'registered';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'competitionKeeper';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'authority';
+						type: 'publicKey';
+					},
+					{
+						name: 'competition';
+						type: 'publicKey';
+					},
+					{
+						name: 'unclaimedReward';
+						type: 'u64';
+					},
+					{
+						name: 'totalReward';
+						type: 'u64';
+					},
+					{
+						name: 'numberOfCalls';
+						type: 'u64';
+					},
+					{
+						name: 'lastCallTs';
+						type: 'i64';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 32];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'competitionRegistry';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'admin';
+						type: 'publicKey';
+					},
+					{
+						name: 'totalCompetitionsRegistered';
+						type: 'u64';
+					},
+					{
+						name: 'numberOfPages';
+						type: 'u32';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 4];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'competitionRegistryPage';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'pageNumber';
+						type: 'u32';
+					},
+					{
+						name: 'numberOfCompetitions';
+						type: 'u32';
+					},
+					{
+						name: 'competitions';
+						type: {
+							array: ['publicKey', 64];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'competitor';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'authority';
+						type: 'publicKey';
+					},
+					{
+						name: 'competition';
+						type: 'publicKey';
+					},
+					{
+						name: 'userStats';
+						type: 'publicKey';
+					},
+					{
+						name: 'minDraw';
+						type: 'u128';
+					},
+					{
+						name: 'maxDraw';
+						type: 'u128';
+					},
+					{
+						name: 'unclaimedWinningsBase';
+						type: 'u128';
+					},
+					{
+						name: 'unclaimedWinnings';
+						type: 'u64';
+					},
+					{
+						name: 'competitionRoundNumber';
+						type: 'u64';
+					},
+					{
+						name: 'previousSnapshotScore';
+						type: 'u64';
+					},
+					{
+						name: 'latestSnapshotScore';
+						type: 'u64';
+					},
+					{
+						name: 'bonusScore';
+						type: 'u64';
+					},
+					{
+						name: 'status';
+						type: {
+							defined: 'CompetitorStatus';
+						};
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 7];
+						};
+					},
+					{
+						name: 'lastRoundWon';
+						type: 'u64';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 16];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'ticketIndexPage';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'competition';
+						type: 'publicKey';
+					},
+					{
+						name: 'roundNumber';
+						type: 'u64';
+					},
+					{
+						name: 'pageNumber';
+						type: 'u32';
+					},
+					{
+						name: 'numberOfEntries';
+						type: 'u32';
+					},
+					{
+						name: 'entries';
+						type: {
+							array: [
+								{
+									defined: 'TicketRange';
+								},
+								32
+							];
+						};
+					}
+				];
+			};
+		}
+	];
+	types: [
+		{
+			name: 'CompetitionParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'nextRoundExpiryTs';
+						type: 'i64';
+					},
+					{
+						name: 'competitionExpiryTs';
+						type: 'i64';
+					},
+					{
+						name: 'roundDuration';
+						type: 'u64';
+					},
+					{
+						name: 'maxEntriesPerCompetitor';
+						type: 'u128';
+					},
+					{
+						name: 'minSponsorAmount';
+						type: 'u64';
+					},
+					{
+						name: 'maxSponsorFraction';
+						type: 'u64';
+					},
+					{
+						name: 'numberOfWinners';
+						type: 'u32';
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateCompetitionParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'maxEntriesPerCompetitor';
+						type: {
+							option: 'u128';
+						};
+					},
+					{
+						name: 'minSponsorAmount';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'maxSponsorFraction';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'numberOfWinners';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'winnerBadgesEnabled';
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'keeperAllowlistEnabled';
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'keeperReward';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'ticketIndexEnabled';
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'randomnessProvider';
+						type: {
+							option: {
+								defined: 'RandomnessProvider';
+							};
+						};
+					},
+					{
+						name: 'operator';
+						type: {
+							option: 'publicKey';
+						};
+					},
+					{
+						name: 'moderator';
+						type: {
+							option: 'publicKey';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateCompetitionScheduleParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'nextRoundExpiryTs';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'competitionExpiryTs';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'roundDuration';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'settlementGracePeriod';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'winnerSettlementTimeout';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'randomnessRequestTimeout';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'maxRandomnessRequestRetries';
+						type: {
+							option: 'u8';
+						};
+					},
+					{
+						name: 'resetRoundState';
+						type: {
+							option: 'bool';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateRoundScheduleParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'mode';
+						type: {
+							option: {
+								defined: 'RoundScheduleMode';
+							};
+						};
+					},
+					{
+						name: 'weekdayMask';
+						type: {
+							option: 'u8';
+						};
+					},
+					{
+						name: 'hour';
+						type: {
+							option: 'u8';
+						};
+					},
+					{
+						name: 'minute';
+						type: {
+							option: 'u8';
+						};
+					},
+					{
+						name: 'clearExpiries';
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'addExpiries';
+						type: {
+							vec: 'i64';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'SponsorInfo';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sponsor';
+						type: 'publicKey';
 					},
 					{
-						name: 'sponsorInfo';
+						name: 'minSponsorAmount';
+						type: 'u64';
+					},
+					{
+						name: 'maxSponsorFraction';
+						type: 'u64';
+					}
+				];
+			};
+		},
+		{
+			name: 'PendingRoundParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'maxEntriesPerCompetitor';
+						type: 'u128';
+					},
+					{
+						name: 'roundDuration';
+						type: 'u64';
+					},
+					{
+						name: 'minSponsorAmount';
+						type: 'u64';
+					},
+					{
+						name: 'maxSponsorFraction';
+						type: 'u64';
+					},
+					{
+						name: 'numberOfWinners';
+						type: 'u32';
+					},
+					{
+						name: 'flags';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
 						type: {
-							defined: 'SponsorInfo';
+							array: ['u8', 3];
 						};
-					},
+					}
+				];
+			};
+		},
+		{
+			name: 'RoundSchedule';
+			type: {
+				kind: 'struct';
+				fields: [
 					{
-						name: 'switchboardFunction';
-						type: 'publicKey';
+						name: 'expiries';
+						type: {
+							array: ['i64', 16];
+						};
 					},
 					{
-						name: 'switchboardFunctionRequest';
-						type: 'publicKey';
+						name: 'head';
+						type: 'u8';
 					},
 					{
-						name: 'switchboardFunctionRequestEscrow';
-						type: 'publicKey';
+						name: 'len';
+						type: 'u8';
 					},
 					{
-						name: 'competitionAuthority';
-						type: 'publicKey';
+						name: 'mode';
+						type: {
+							defined: 'RoundScheduleMode';
+						};
 					},
 					{
-						name: 'numberOfCompetitors';
-						type: 'u128';
+						name: 'weekdayMask';
+						type: 'u8';
 					},
 					{
-						name: 'numberOfCompetitorsSettled';
-						type: 'u128';
+						name: 'hour';
+						type: 'u8';
 					},
 					{
-						name: 'totalScoreSettled';
-						type: 'u128';
+						name: 'minute';
+						type: 'u8';
 					},
 					{
-						name: 'maxEntriesPerCompetitor';
-						type: 'u128';
+						name: 'padding';
+						type: {
+							array: ['u8', 2];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'TicketRange';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'competitor';
+						type: 'publicKey';
 					},
 					{
-						name: 'prizeAmount';
+						name: 'minDraw';
 						type: 'u128';
 					},
 					{
-						name: 'prizeAmountSettled';
+						name: 'maxDraw';
 						type: 'u128';
+					}
+				];
+			};
+		},
+		{
+			name: 'CompetitionRoundStatus';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Active';
 					},
 					{
-						name: 'prizeBase';
-						type: 'u128';
+						name: 'WinnerAndPrizeRandomnessRequested';
 					},
 					{
-						name: 'winnerRandomness';
-						type: 'u128';
+						name: 'WinnerAndPrizeRandomnessComplete';
 					},
 					{
-						name: 'prizeRandomness';
-						type: 'u128';
+						name: 'WinnerSettlementComplete';
 					},
 					{
-						name: 'prizeRandomnessMax';
-						type: 'u128';
+						name: 'Expired';
 					},
 					{
-						name: 'outstandingUnclaimedWinnings';
-						type: 'u128';
+						name: 'Paused';
+					}
+				];
+			};
+		},
+		{
+			name: 'RandomnessProvider';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Switchboard';
 					},
 					{
-						name: 'roundNumber';
-						type: 'u64';
+						name: 'CommitReveal';
 					},
 					{
-						name: 'nextRoundExpiryTs';
-						type: 'i64';
+						name: 'SlotHash';
+					}
+				];
+			};
+		},
+		{
+			name: 'CompetitorStatus';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Active';
 					},
 					{
-						name: 'competitionExpiryTs';
-						type: 'i64';
+						name: 'Disqualified';
+					}
+				];
+			};
+		},
+		{
+			name: 'RoundScheduleMode';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Interval';
 					},
 					{
-						name: 'roundDuration';
-						type: 'u64';
+						name: 'Explicit';
 					},
 					{
-						name: 'numberOfWinners';
-						type: 'u32';
+						name: 'Weekly';
+					}
+				];
+			};
+		}
+	];
+	events: [
+		{
+			name: 'CompetitionRoundSummaryRecord';
+			fields: [
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'roundNumber';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'roundStartTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'roundEndTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'prizePlacement';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'prizeOddsNumerator';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeRandomness';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeRandomnessMax';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'maxPrizeBucketValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'prizeAmount';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'prizeBase';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'numberOfWinners';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'numberOfCompetitorsSettled';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalScoreSettled';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'insuranceVaultBalance';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'protocolIfShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalIfShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'prizeDraw';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'winnerRandomnessDelivered';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeRandomnessDelivered';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'winnerRandomnessMax';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'randomnessHash';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'CompetitionRoundWinnerRecord';
+			fields: [
+				{
+					name: 'roundNumber';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'competitor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'competitorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'minDraw';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'maxDraw';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'winnerPlacement';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'numberOfWinners';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'numberOfCompetitorsSettled';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'winnerRandomness';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalScoreSettled';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeRandomness';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeRandomnessMax';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeAmount';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeBase';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'winnerBadgeMint';
+					type: 'publicKey';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'CompetitorSettledRecord';
+			fields: [
+				{
+					name: 'roundNumber';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'competitor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'competitorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'status';
+					type: {
+						defined: 'CompetitorStatus';
+					};
+					index: false;
+				},
+				{
+					name: 'unclaimedWinnings';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'minDraw';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'maxDraw';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'bonusScoreBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'bonusScoreAfter';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'previousSnapshotScoreBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'snapshotScore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'CompetitionExpiredRecord';
+			fields: [
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'roundNumber';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'roundAbandoned';
+					type: 'bool';
+					index: false;
+				},
+				{
+					name: 'numberOfCompetitors';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'outstandingUnclaimedWinnings';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'unclaimedWinningsForfeited';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'competitionExpiryTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'claimWindowEndTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'SponsorProposedRecord';
+			fields: [
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'sponsor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'pendingSponsor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'SponsorAcceptedRecord';
+			fields: [
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'previousSponsor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'sponsor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'RoundParamsScheduledRecord';
+			fields: [
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'effectiveRoundNumber';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'maxEntriesPerCompetitor';
+					type: {
+						option: 'u128';
+					};
+					index: false;
+				},
+				{
+					name: 'roundDuration';
+					type: {
+						option: 'u64';
+					};
+					index: false;
+				},
+				{
+					name: 'minSponsorAmount';
+					type: {
+						option: 'u64';
+					};
+					index: false;
+				},
+				{
+					name: 'maxSponsorFraction';
+					type: {
+						option: 'u64';
+					};
+					index: false;
+				},
+				{
+					name: 'numberOfWinners';
+					type: {
+						option: 'u32';
+					};
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'WinnerSkippedRecord';
+			fields: [
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'roundNumber';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'winnerPlacement';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'skippedWinnerRandomness';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'winnerRandomness';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'winnerRedraws';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'RandomnessRequestedRecord';
+			fields: [
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'roundNumber';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'retry';
+					type: 'u8';
+					index: false;
+				},
+				{
+					name: 'maxRetries';
+					type: 'u8';
+					index: false;
+				},
+				{
+					name: 'winnerMax';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeMax';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'slot';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'RandomnessRevealedRecord';
+			fields: [
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'roundNumber';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'commitment';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				},
+				{
+					name: 'secret';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				},
+				{
+					name: 'slotHash';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				},
+				{
+					name: 'slot';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'revealMissed';
+					type: 'bool';
+					index: false;
+				},
+				{
+					name: 'slotHashPinMissed';
+					type: 'bool';
+					index: false;
+				},
+				{
+					name: 'revealsMissed';
+					type: 'u8';
+					index: false;
+				},
+				{
+					name: 'winnerRandomness';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeRandomness';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'WinnerDrawRecord';
+			fields: [
+				{
+					name: 'competition';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'roundNumber';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'winnerRandomnessDelivered';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeRandomnessDelivered';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'winnerRandomnessMax';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeRandomnessMax';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeRandomness';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'prizeDraw';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalScoreSettled';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'winnerPlacement';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'winnerRedraws';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'winningScoreSettled';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'winnerRandomness';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				}
+			];
+		}
+	];
+	errors: [
+		{
+			code: 6000;
+			name: 'Default';
+			msg: 'Default';
+		},
+		{
+			code: 6001;
+			name: 'DriftError';
+			msg: 'DriftError';
+		},
+		{
+			code: 6002;
+			name: 'CompetitionRoundOngoing';
+			msg: 'CompetitionRoundOngoing';
+		},
+		{
+			code: 6003;
+			name: 'CompetitionRoundInSettlementPhase';
+			msg: 'CompetitionRoundInSettlementPhase';
+		},
+		{
+			code: 6004;
+			name: 'CompetitionStatusNotActive';
+			msg: 'CompetitionStatusNotActive';
+		},
+		{
+			code: 6005;
+			name: 'CompetitionExpired';
+			msg: 'CompetitionExpired';
+		},
+		{
+			code: 6006;
+			name: 'InvalidRoundSettlementDetected';
+			msg: 'InvalidRoundSettlementDetected';
+		},
+		{
+			code: 6007;
+			name: 'CompetitionWinnerNotDetermined';
+			msg: 'CompetitionWinnerNotDetermined';
+		},
+		{
+			code: 6008;
+			name: 'CompetitorHasWrongRoundNumber';
+			msg: 'CompetitorHasWrongRoundNumber';
+		},
+		{
+			code: 6009;
+			name: 'CompetitorNotWinner';
+			msg: 'CompetitorNotWinner';
+		},
+		{
+			code: 6010;
+			name: 'InvalidStatusUpdateDetected';
+			msg: 'InvalidStatusUpdateDetected';
+		},
+		{
+			code: 6011;
+			name: 'InvalidIFRebase';
+			msg: 'InvalidIFRebase';
+		},
+		{
+			code: 6012;
+			name: 'CompetitorHasAlreadyClaimedEntry';
+			msg: 'CompetitorHasAlreadyClaimedEntry';
+		},
+		{
+			code: 6013;
+			name: 'CompetitorNeedsToRebaseInsuranceFundStake';
+			msg: 'CompetitorNeedsToRebaseInsuranceFundStake';
+		},
+		{
+			code: 6014;
+			name: 'CompetitorHasNoUnclaimedWinnings';
+			msg: 'CompetitorHasNoUnclaimedWinnings';
+		},
+		{
+			code: 6015;
+			name: 'CompetitionRoundNumberIssue';
+			msg: 'CompetitionRoundNumberIssue';
+		},
+		{
+			code: 6016;
+			name: 'CompetitorSnapshotIssue';
+			msg: 'CompetitorSnapshotIssue';
+		},
+		{
+			code: 6017;
+			name: 'CompetitorHasInvalidClaim';
+			msg: 'CompetitorHasInvalidClaim';
+		},
+		{
+			code: 6018;
+			name: 'CompetitorUpdateInvalid';
+			msg: 'CompetitorUpdateInvalid';
+		},
+		{
+			code: 6019;
+			name: 'CompetitionInvariantIssue';
+			msg: 'CompetitionInvariantIssue';
+		},
+		{
+			code: 6020;
+			name: 'CompetitorHasPendingInsuranceWithdraw';
+			msg: 'CompetitorHasPendingInsuranceWithdraw';
+		},
+		{
+			code: 6021;
+			name: 'WinnerBadgeAccountsMissing';
+			msg: 'WinnerBadgeAccountsMissing';
+		},
+		{
+			code: 6022;
+			name: 'CompetitionClaimWindowElapsed';
+			msg: 'CompetitionClaimWindowElapsed';
+		},
+		{
+			code: 6023;
+			name: 'CompetitionPaused';
+			msg: 'CompetitionPaused';
+		},
+		{
+			code: 6024;
+			name: 'CompetitorHasUnclaimedWinnings';
+			msg: 'CompetitorHasUnclaimedWinnings';
+		},
+		{
+			code: 6025;
+			name: 'CompetitionNotClosable';
+			msg: 'CompetitionNotClosable';
+		},
+		{
+			code: 6026;
+			name: 'InvalidSponsorUpdate';
+			msg: 'InvalidSponsorUpdate';
+		},
+		{
+			code: 6027;
+			name: 'KeeperNotAllowed';
+			msg: 'KeeperNotAllowed';
+		},
+		{
+			code: 6028;
+			name: 'InvalidMaxSponsorFraction';
+			msg: 'InvalidMaxSponsorFraction';
+		},
+		{
+			code: 6029;
+			name: 'InvalidNumberOfWinners';
+			msg: 'InvalidNumberOfWinners';
+		},
+		{
+			code: 6030;
+			name: 'InvalidRoundDuration';
+			msg: 'InvalidRoundDuration';
+		},
+		{
+			code: 6031;
+			name: 'InvalidCompetitionExpiryTs';
+			msg: 'InvalidCompetitionExpiryTs';
+		},
+		{
+			code: 6032;
+			name: 'InvalidRoundSchedule';
+			msg: 'InvalidRoundSchedule';
+		},
+		{
+			code: 6033;
+			name: 'InvalidCompetitionName';
+			msg: 'InvalidCompetitionName';
+		},
+		{
+			code: 6034;
+			name: 'CompetitionRegistryUpdateInvalid';
+			msg: 'CompetitionRegistryUpdateInvalid';
+		},
+		{
+			code: 6035;
+			name: 'InvalidBatchSettlementAccounts';
+			msg: 'InvalidBatchSettlementAccounts';
+		},
+		{
+			code: 6036;
+			name: 'TicketIndexPageMissing';
+			msg: 'TicketIndexPageMissing';
+		},
+		{
+			code: 6037;
+			name: 'TicketIndexPageInvalid';
+			msg: 'TicketIndexPageInvalid';
+		},
+		{
+			code: 6038;
+			name: 'TicketIndexWinnerNotFound';
+			msg: 'TicketIndexWinnerNotFound';
+		},
+		{
+			code: 6039;
+			name: 'WinnerNotSkippable';
+			msg: 'WinnerNotSkippable';
+		},
+		{
+			code: 6040;
+			name: 'RandomnessRequestNotTimedOut';
+			msg: 'RandomnessRequestNotTimedOut';
+		},
+		{
+			code: 6041;
+			name: 'RandomnessRequestRetriesExhausted';
+			msg: 'RandomnessRequestRetriesExhausted';
+		},
+		{
+			code: 6042;
+			name: 'CompetitorNotSettleable';
+			msg: 'CompetitorNotSettleable';
+		},
+		{
+			code: 6043;
+			name: 'InvalidRandomnessProvider';
+			msg: 'InvalidRandomnessProvider';
+		},
+		{
+			code: 6044;
+			name: 'InvalidRandomnessCommitment';
+			msg: 'InvalidRandomnessCommitment';
+		},
+		{
+			code: 6045;
+			name: 'InvalidRandomnessReveal';
+			msg: 'InvalidRandomnessReveal';
+		},
+		{
+			code: 6046;
+			name: 'SlotHashNotFound';
+			msg: 'SlotHashNotFound';
+		},
+		{
+			code: 6047;
+			name: 'RandomnessRevealNotTimedOut';
+			msg: 'RandomnessRevealNotTimedOut';
+		},
+		{
+			code: 6048;
+			name: 'InvalidRandomnessRequestParams';
+			msg: 'InvalidRandomnessRequestParams';
+		},
+		{
+			code: 6049;
+			name: 'InvalidWinnerBadgeAuthority';
+			msg: 'InvalidWinnerBadgeAuthority';
+		},
+		{
+			code: 6050;
+			name: 'InvalidCompetitionMigration';
+			msg: 'InvalidCompetitionMigration';
+		},
+		{
+			code: 6051;
+			name: 'InvalidRandomnessDelivered';
+			msg: 'InvalidRandomnessDelivered';
+		}
+	];
+};
+
+export const IDL: DriftCompetitions = {
+	version: '0.1.0',
+	name: 'drift_competitions',
+	instructions: [
+		{
+			name: 'initializeCompetitionRegistry',
+			accounts: [
+				{
+					name: 'competitionRegistry',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'program',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'programData',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeCompetitionRegistryPage',
+			accounts: [
+				{
+					name: 'competitionRegistryPage',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competitionRegistry',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'pageNumber',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'registerCompetition',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competitionRegistry',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competitionRegistryPage',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'deregisterCompetition',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competitionRegistry',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'competitionRegistryPage',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'migrateCompetition',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'program',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'programData',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeCompetition',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'sponsor',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'CompetitionParams',
 					},
-					{
-						name: 'numberOfWinnersSettled';
-						type: 'u32';
+				},
+			],
+		},
+		{
+			name: 'updateCompetition',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'sponsor',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateCompetitionParams',
 					},
-					{
-						name: 'status';
-						type: {
-							defined: 'CompetitionRoundStatus';
-						};
+				},
+			],
+		},
+		{
+			name: 'updateCompetitionSchedule',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'operator',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateCompetitionScheduleParams',
 					},
-					{
-						name: 'competitionAuthorityBump';
-						type: 'u8';
+				},
+			],
+		},
+		{
+			name: 'updateRoundSchedule',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'operator',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateRoundScheduleParams',
 					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u8', 30];
-						};
-					}
-				];
-			};
+				},
+			],
+		},
+		{
+			name: 'updateSwitchboardFunction',
+			accounts: [
+				{
+					name: 'sponsor',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competitionAuthority',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK'],
+				},
+				{
+					name: 'switchboard',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK: is switchboard program'],
+				},
+				{
+					name: 'switchboardState',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK:'],
+				},
+				{
+					name: 'switchboardAttestationQueue',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'switchboardFunction',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'switchboardRequest',
+					isMut: true,
+					isSigner: false,
+					docs: ['CHECK: checked din cpi'],
+				},
+				{
+					name: 'switchboardRequestEscrow',
+					isMut: true,
+					isSigner: false,
+					docs: ['CHECK: checked din cpi'],
+				},
+				{
+					name: 'switchboardMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'associatedTokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			name: 'competitor';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'authority';
-						type: 'publicKey';
-					},
-					{
-						name: 'competition';
-						type: 'publicKey';
-					},
-					{
-						name: 'userStats';
-						type: 'publicKey';
-					},
-					{
-						name: 'minDraw';
-						type: 'u128';
-					},
-					{
-						name: 'maxDraw';
-						type: 'u128';
-					},
-					{
-						name: 'unclaimedWinningsBase';
-						type: 'u128';
-					},
-					{
-						name: 'unclaimedWinnings';
-						type: 'u64';
-					},
-					{
-						name: 'competitionRoundNumber';
-						type: 'u64';
-					},
-					{
-						name: 'previousSnapshotScore';
-						type: 'u64';
-					},
-					{
-						name: 'latestSnapshotScore';
-						type: 'u64';
-					},
-					{
-						name: 'bonusScore';
-						type: 'u64';
-					},
-					{
-						name: 'status';
-						type: {
-							defined: 'CompetitorStatus';
-						};
+			name: 'updateCompetitorStatus',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'moderator',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'competitor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'newStatus',
+					type: {
+						defined: 'CompetitorStatus',
 					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u8', 31];
-						};
-					}
-				];
-			};
-		}
-	];
-	types: [
+				},
+			],
+		},
 		{
-			name: 'CompetitionParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'nextRoundExpiryTs';
-						type: 'i64';
-					},
-					{
-						name: 'competitionExpiryTs';
-						type: 'i64';
-					},
-					{
-						name: 'roundDuration';
-						type: 'u64';
-					},
-					{
-						name: 'maxEntriesPerCompetitor';
-						type: 'u128';
-					},
-					{
-						name: 'minSponsorAmount';
-						type: 'u64';
-					},
-					{
-						name: 'maxSponsorFraction';
-						type: 'u64';
-					},
-					{
-						name: 'numberOfWinners';
-						type: 'u32';
-					}
-				];
-			};
+			name: 'pauseCompetition',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'operator',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'resumeCompetition',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'operator',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'closeCompetition',
+			accounts: [
+				{
+					name: 'sponsor',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competitionAuthority',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK'],
+				},
+				{
+					name: 'competitionRegistryPage',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'switchboard',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+					docs: ['CHECK: program ID checked.'],
+				},
+				{
+					name: 'switchboardState',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+					docs: ['CHECK: checked in cpi'],
+				},
+				{
+					name: 'switchboardFunction',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+					docs: ['CHECK: checked in cpi'],
+				},
+				{
+					name: 'switchboardRequest',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+					docs: ['CHECK: cpi checks'],
+				},
+				{
+					name: 'switchboardRequestEscrow',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+					docs: ['CHECK: cpi checks'],
+				},
+				{
+					name: 'sponsorTokenAccount',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+					docs: [
+						'CHECK: sponsor\'s wrapped SOL token account, checked in cpi',
+					],
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'proposeSponsor',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'sponsor',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'pendingSponsor',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'acceptSponsor',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'pendingSponsor',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
 		},
 		{
-			name: 'UpdateCompetitionParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'nextRoundExpiryTs';
-						type: {
-							option: 'i64';
-						};
-					},
-					{
-						name: 'competitionExpiryTs';
-						type: {
-							option: 'i64';
-						};
-					},
-					{
-						name: 'roundDuration';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'maxEntriesPerCompetitor';
-						type: {
-							option: 'u128';
-						};
-					},
-					{
-						name: 'minSponsorAmount';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'maxSponsorFraction';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'numberOfWinners';
-						type: {
-							option: 'u32';
-						};
-					},
-					{
-						name: 'resetRoundState';
-						type: {
-							option: 'bool';
-						};
-					}
-				];
-			};
+			name: 'addCompetitionKeeper',
+			accounts: [
+				{
+					name: 'competitionKeeper',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competition',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'keeperAuthority',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK: keeper signer to allow'],
+				},
+				{
+					name: 'operator',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			name: 'SponsorInfo';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'sponsor';
-						type: 'publicKey';
-					},
-					{
-						name: 'minSponsorAmount';
-						type: 'u64';
-					},
-					{
-						name: 'maxSponsorFraction';
-						type: 'u64';
-					}
-				];
-			};
+			name: 'removeCompetitionKeeper',
+			accounts: [
+				{
+					name: 'competitionKeeper',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competition',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'keeperAuthority',
+					isMut: true,
+					isSigner: false,
+					docs: ['CHECK: checked against competition_keeper'],
+				},
+				{
+					name: 'competitionAuthority',
+					isMut: true,
+					isSigner: false,
+					docs: ['CHECK'],
+				},
+				{
+					name: 'operator',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			name: 'CompetitionRoundStatus';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Active';
-					},
-					{
-						name: 'WinnerAndPrizeRandomnessRequested';
-					},
-					{
-						name: 'WinnerAndPrizeRandomnessComplete';
-					},
-					{
-						name: 'WinnerSettlementComplete';
+			name: 'commitRandomness',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'sponsor',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'commitment',
+					type: {
+						array: ['u8', 32],
 					},
-					{
-						name: 'Expired';
-					}
-				];
-			};
+				},
+			],
 		},
 		{
-			name: 'CompetitorStatus';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Active';
-					},
-					{
-						name: 'Disqualified';
-					}
-				];
-			};
-		}
-	];
-	events: [
+			name: 'initializeCompetitor',
+			accounts: [
+				{
+					name: 'competitor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'claimEntry',
+			accounts: [
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'competitor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'instructions',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK: fixed instructions sysvar account'],
+				},
+			],
+			args: [],
+		},
 		{
-			name: 'CompetitionRoundSummaryRecord';
-			fields: [
+			name: 'claimMultipleEntries',
+			accounts: [
 				{
-					name: 'competition';
-					type: 'publicKey';
-					index: false;
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'roundNumber';
-					type: 'u64';
-					index: false;
+					name: 'competitor',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'roundStartTs';
-					type: 'i64';
-					index: false;
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'roundEndTs';
-					type: 'i64';
-					index: false;
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'prizePlacement';
-					type: 'u32';
-					index: false;
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK: checked in cpi'],
 				},
 				{
-					name: 'prizeOddsNumerator';
-					type: 'u128';
-					index: false;
+					name: 'spotMarket',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'prizeRandomness';
-					type: 'u128';
-					index: false;
+					name: 'spotMarketVault',
+					isMut: true,
+					isSigner: false,
+					docs: ['CHECK: checked in cpi'],
 				},
 				{
-					name: 'prizeRandomnessMax';
-					type: 'u128';
-					index: false;
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'maxPrizeBucketValue';
-					type: 'u64';
-					index: false;
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'prizeAmount';
-					type: 'u128';
-					index: false;
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
 				},
+			],
+			args: [
 				{
-					name: 'prizeValue';
-					type: 'u64';
-					index: false;
+					name: 'entries',
+					type: 'u64',
 				},
+			],
+		},
+		{
+			name: 'claimWinnings',
+			accounts: [
 				{
-					name: 'prizeBase';
-					type: 'u128';
-					index: false;
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'numberOfWinners';
-					type: 'u32';
-					index: false;
+					name: 'competitor',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'numberOfCompetitorsSettled';
-					type: 'u128';
-					index: false;
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'totalScoreSettled';
-					type: 'u128';
-					index: false;
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'insuranceVaultBalance';
-					type: 'u64';
-					index: false;
+					name: 'spotMarket',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'protocolIfShares';
-					type: 'u128';
-					index: false;
+					name: 'insuranceFundVault',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'totalIfShares';
-					type: 'u128';
-					index: false;
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
-				}
-			];
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'competitionAuthority',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK'],
+				},
+				{
+					name: 'driftTransferConfig',
+					isMut: true,
+					isSigner: false,
+					docs: ['CHECK in cpi'],
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK in cpi'],
+				},
+			],
+			args: [
+				{
+					name: 'nShares',
+					type: {
+						option: 'u64',
+					},
+				},
+			],
 		},
 		{
-			name: 'CompetitionRoundWinnerRecord';
-			fields: [
+			name: 'closeCompetitor',
+			accounts: [
 				{
-					name: 'roundNumber';
-					type: 'u64';
-					index: false;
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'competitor';
-					type: 'publicKey';
-					index: false;
+					name: 'competitor',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'competition';
-					type: 'publicKey';
-					index: false;
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'selfSettleCompetitor',
+			accounts: [
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
 				},
 				{
-					name: 'competitorAuthority';
-					type: 'publicKey';
-					index: false;
+					name: 'competitor',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'minDraw';
-					type: 'u128';
-					index: false;
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'maxDraw';
-					type: 'u128';
-					index: false;
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'ticketIndexPage',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'instructions',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+					docs: [
+						'CHECK: fixed instructions sysvar account (required to claim the free entry)',
+					],
+				},
+			],
+			args: [
+				{
+					name: 'claimEntry',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'initializeTicketIndexPage',
+			accounts: [
+				{
+					name: 'ticketIndexPage',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competition',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
 				},
+			],
+			args: [
 				{
-					name: 'winnerPlacement';
-					type: 'u32';
-					index: false;
+					name: 'pageNumber',
+					type: 'u32',
 				},
+			],
+		},
+		{
+			name: 'settleCompetitor',
+			accounts: [
 				{
-					name: 'numberOfWinners';
-					type: 'u32';
-					index: false;
+					name: 'keeper',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'numberOfCompetitorsSettled';
-					type: 'u128';
-					index: false;
+					name: 'competitor',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'winnerRandomness';
-					type: 'u128';
-					index: false;
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'totalScoreSettled';
-					type: 'u128';
-					index: false;
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'prizeRandomness';
-					type: 'u128';
-					index: false;
+					name: 'competitionKeeper',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
 				},
 				{
-					name: 'prizeRandomnessMax';
-					type: 'u128';
-					index: false;
+					name: 'ticketIndexPage',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
 				},
+			],
+			args: [],
+		},
+		{
+			name: 'settleCompetitorsBatch',
+			accounts: [
 				{
-					name: 'prizeAmount';
-					type: 'u128';
-					index: false;
+					name: 'keeper',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'prizeBase';
-					type: 'u128';
-					index: false;
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'prizeValue';
-					type: 'u64';
-					index: false;
+					name: 'competitionKeeper',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
 				},
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
-				}
-			];
+					name: 'ticketIndexPage',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+				},
+			],
+			args: [],
 		},
 		{
-			name: 'CompetitorSettledRecord';
-			fields: [
+			name: 'requestRandomness',
+			accounts: [
 				{
-					name: 'roundNumber';
-					type: 'u64';
-					index: false;
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'competitor';
-					type: 'publicKey';
-					index: false;
+					name: 'keeper',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'competition';
-					type: 'publicKey';
-					index: false;
+					name: 'competitionAuthority',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK'],
 				},
 				{
-					name: 'competitorAuthority';
-					type: 'publicKey';
-					index: false;
+					name: 'competitionKeeper',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
 				},
 				{
-					name: 'status';
-					type: {
-						defined: 'CompetitorStatus';
-					};
-					index: false;
+					name: 'spotMarket',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'unclaimedWinnings';
-					type: 'u64';
-					index: false;
+					name: 'insuranceFundVault',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'minDraw';
-					type: 'u128';
-					index: false;
+					name: 'switchboard',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK: program ID checked.'],
 				},
 				{
-					name: 'maxDraw';
-					type: 'u128';
-					index: false;
+					name: 'switchboardState',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK:'],
 				},
 				{
-					name: 'bonusScoreBefore';
-					type: 'u64';
-					index: false;
+					name: 'switchboardAttestationQueue',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'bonusScoreAfter';
-					type: 'u64';
-					index: false;
+					name: 'switchboardFunction',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'previousSnapshotScoreBefore';
-					type: 'u64';
-					index: false;
+					name: 'switchboardRequest',
+					isMut: true,
+					isSigner: false,
+					docs: ['CHECK: cpi checks'],
 				},
 				{
-					name: 'snapshotScore';
-					type: 'u64';
-					index: false;
+					name: 'switchboardRequestEscrow',
+					isMut: true,
+					isSigner: false,
+					docs: ['CHECK: cpi checks'],
 				},
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
-				}
-			];
-		}
-	];
-	errors: [
-		{
-			code: 6000;
-			name: 'Default';
-			msg: 'Default';
-		},
-		{
-			code: 6001;
-			name: 'DriftError';
-			msg: 'DriftError';
-		},
-		{
-			code: 6002;
-			name: 'CompetitionRoundOngoing';
-			msg: 'CompetitionRoundOngoing';
-		},
-		{
-			code: 6003;
-			name: 'CompetitionRoundInSettlementPhase';
-			msg: 'CompetitionRoundInSettlementPhase';
-		},
-		{
-			code: 6004;
-			name: 'CompetitionStatusNotActive';
-			msg: 'CompetitionStatusNotActive';
-		},
-		{
-			code: 6005;
-			name: 'CompetitionExpired';
-			msg: 'CompetitionExpired';
-		},
-		{
-			code: 6006;
-			name: 'InvalidRoundSettlementDetected';
-			msg: 'InvalidRoundSettlementDetected';
-		},
-		{
-			code: 6007;
-			name: 'CompetitionWinnerNotDetermined';
-			msg: 'CompetitionWinnerNotDetermined';
-		},
-		{
-			code: 6008;
-			name: 'CompetitorHasWrongRoundNumber';
-			msg: 'CompetitorHasWrongRoundNumber';
-		},
-		{
-			code: 6009;
-			name: 'CompetitorNotWinner';
-			msg: 'CompetitorNotWinner';
-		},
-		{
-			code: 6010;
-			name: 'InvalidStatusUpdateDetected';
-			msg: 'InvalidStatusUpdateDetected';
-		},
-		{
-			code: 6011;
-			name: 'InvalidIFRebase';
-			msg: 'InvalidIFRebase';
-		},
-		{
-			code: 6012;
-			name: 'CompetitorHasAlreadyClaimedEntry';
-			msg: 'CompetitorHasAlreadyClaimedEntry';
-		},
-		{
-			code: 6013;
-			name: 'CompetitorNeedsToRebaseInsuranceFundStake';
-			msg: 'CompetitorNeedsToRebaseInsuranceFundStake';
-		},
-		{
-			code: 6014;
-			name: 'CompetitorHasNoUnclaimedWinnings';
-			msg: 'CompetitorHasNoUnclaimedWinnings';
-		},
-		{
-			code: 6015;
-			name: 'CompetitionRoundNumberIssue';
-			msg: 'CompetitionRoundNumberIssue';
-		},
-		{
-			code: 6016;
-			name: 'CompetitorSnapshotIssue';
-			msg: 'CompetitorSnapshotIssue';
-		},
-		{
-			code: 6017;
-			name: 'CompetitorHasInvalidClaim';
-			msg: 'CompetitorHasInvalidClaim';
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'bounty',
+					type: {
+						option: 'u64',
+					},
+				},
+			],
 		},
 		{
-			code: 6018;
-			name: 'CompetitorUpdateInvalid';
-			msg: 'CompetitorUpdateInvalid';
+			name: 'receiveRandomness',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'spotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'switchboardFunction',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'switchboardRequest',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'enclaveSigner',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'winnerRandomnessRaw',
+					type: 'u128',
+				},
+				{
+					name: 'prizeRandomnessRaw',
+					type: 'u128',
+				},
+			],
 		},
 		{
-			code: 6019;
-			name: 'CompetitionInvariantIssue';
-			msg: 'CompetitionInvariantIssue';
+			name: 'requestCommitRevealRandomness',
+			accounts: [
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'keeper',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'competitionKeeper',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'spotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6020;
-			name: 'CompetitorHasPendingInsuranceWithdraw';
-			msg: 'CompetitorHasPendingInsuranceWithdraw';
-		}
-	];
-};
-
-export const IDL: DriftCompetitions = {
-	version: '0.1.0',
-	name: 'drift_competitions',
-	instructions: [
-		{
-			name: 'initializeCompetition',
+			name: 'pinRandomnessSlotHash',
 			accounts: [
 				{
 					name: 'competition',
@@ -1288,37 +4578,60 @@ export const IDL: DriftCompetitions = {
 					isSigner: false,
 				},
 				{
-					name: 'sponsor',
+					name: 'keeper',
 					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: 'payer',
+					name: 'slotHashes',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK: slot hashes sysvar, read in place'],
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'revealRandomness',
+			accounts: [
+				{
+					name: 'competition',
 					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'revealer',
+					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: 'rent',
+					name: 'spotMarket',
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: 'systemProgram',
+					name: 'insuranceFundVault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'slotHashes',
 					isMut: false,
 					isSigner: false,
+					docs: ['CHECK: slot hashes sysvar, read in place'],
 				},
 			],
 			args: [
 				{
-					name: 'params',
+					name: 'secret',
 					type: {
-						defined: 'CompetitionParams',
+						array: ['u8', 32],
 					},
 				},
 			],
 		},
 		{
-			name: 'updateCompetition',
+			name: 'resolveMissedReveal',
 			accounts: [
 				{
 					name: 'competition',
@@ -1326,862 +4639,1572 @@ export const IDL: DriftCompetitions = {
 					isSigner: false,
 				},
 				{
-					name: 'sponsor',
+					name: 'keeper',
 					isMut: false,
 					isSigner: true,
 				},
-			],
-			args: [
 				{
-					name: 'params',
-					type: {
-						defined: 'UpdateCompetitionParams',
-					},
+					name: 'slotHashes',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK: slot hashes sysvar, read in place'],
 				},
 			],
+			args: [],
 		},
 		{
-			name: 'updateSwitchboardFunction',
+			name: 'drawSlotHashRandomness',
 			accounts: [
 				{
-					name: 'sponsor',
+					name: 'competition',
 					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'keeper',
+					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: 'competition',
+					name: 'competitionKeeper',
 					isMut: true,
 					isSigner: false,
+					isOptional: true,
 				},
 				{
-					name: 'competitionAuthority',
+					name: 'spotMarket',
 					isMut: false,
 					isSigner: false,
-					docs: ['CHECK'],
 				},
 				{
-					name: 'switchboard',
+					name: 'insuranceFundVault',
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: 'switchboardState',
+					name: 'slotHashes',
+					isMut: false,
+					isSigner: false,
+					docs: ['CHECK: slot hashes sysvar, read in place'],
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'settleWinner',
+			accounts: [
+				{
+					name: 'keeper',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'competitor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: 'switchboardAttestationQueue',
+					name: 'spotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: 'switchboardFunction',
+					name: 'competitionKeeper',
 					isMut: true,
 					isSigner: false,
+					isOptional: true,
 				},
 				{
-					name: 'switchboardRequest',
+					name: 'winnerBadgeMint',
 					isMut: true,
 					isSigner: true,
+					isOptional: true,
 				},
 				{
-					name: 'switchboardRequestEscrow',
+					name: 'winnerBadgeTokenAccount',
 					isMut: true,
 					isSigner: false,
+					isOptional: true,
+					docs: ['CHECK: checked in cpi'],
 				},
 				{
-					name: 'switchboardMint',
+					name: 'winnerAuthority',
 					isMut: false,
 					isSigner: false,
+					isOptional: true,
+					docs: ['CHECK: checked against competitor authority'],
+				},
+				{
+					name: 'competitionAuthority',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+					docs: ['CHECK'],
 				},
 				{
 					name: 'tokenProgram',
 					isMut: false,
 					isSigner: false,
+					isOptional: true,
 				},
 				{
 					name: 'associatedTokenProgram',
 					isMut: false,
 					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'settleWinnerByIndex',
+			accounts: [
+				{
+					name: 'settleWinner',
+					accounts: [
+						{
+							name: 'keeper',
+							isMut: true,
+							isSigner: true,
+						},
+						{
+							name: 'competitor',
+							isMut: true,
+							isSigner: false,
+						},
+						{
+							name: 'competition',
+							isMut: true,
+							isSigner: false,
+						},
+						{
+							name: 'driftUserStats',
+							isMut: false,
+							isSigner: false,
+						},
+						{
+							name: 'spotMarket',
+							isMut: true,
+							isSigner: false,
+						},
+						{
+							name: 'insuranceFundVault',
+							isMut: false,
+							isSigner: false,
+						},
+						{
+							name: 'competitionKeeper',
+							isMut: true,
+							isSigner: false,
+							isOptional: true,
+						},
+						{
+							name: 'winnerBadgeMint',
+							isMut: true,
+							isSigner: true,
+							isOptional: true,
+						},
+						{
+							name: 'winnerBadgeTokenAccount',
+							isMut: true,
+							isSigner: false,
+							isOptional: true,
+							docs: ['CHECK: checked in cpi'],
+						},
+						{
+							name: 'winnerAuthority',
+							isMut: false,
+							isSigner: false,
+							isOptional: true,
+							docs: ['CHECK: checked against competitor authority'],
+						},
+						{
+							name: 'competitionAuthority',
+							isMut: false,
+							isSigner: false,
+							isOptional: true,
+							docs: ['CHECK'],
+						},
+						{
+							name: 'tokenProgram',
+							isMut: false,
+							isSigner: false,
+							isOptional: true,
+						},
+						{
+							name: 'associatedTokenProgram',
+							isMut: false,
+							isSigner: false,
+							isOptional: true,
+						},
+						{
+							name: 'systemProgram',
+							isMut: false,
+							isSigner: false,
+							isOptional: true,
+						},
+					],
+				},
+				{
+					name: 'ticketIndexPage',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'skipWinner',
+			accounts: [
+				{
+					name: 'keeper',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'competition',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'competitionKeeper',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'expireCompetition',
+			accounts: [
+				{
+					name: 'keeper',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'systemProgram',
-					isMut: false,
+					name: 'competition',
+					isMut: true,
 					isSigner: false,
 				},
 			],
 			args: [],
 		},
 		{
-			name: 'updateCompetitorStatus',
+			name: 'claimKeeperReward',
 			accounts: [
 				{
-					name: 'competition',
+					name: 'keeper',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'competitionKeeper',
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: 'sponsor',
+					name: 'competition',
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: 'competitor',
+					name: 'competitionAuthority',
 					isMut: true,
 					isSigner: false,
+					docs: ['CHECK'],
 				},
 				{
-					name: 'driftUserStats',
-					isMut: true,
+					name: 'systemProgram',
+					isMut: false,
 					isSigner: false,
 				},
 			],
-			args: [
-				{
-					name: 'newStatus',
-					type: {
-						defined: 'CompetitorStatus',
+			args: [],
+		},
+	],
+	accounts: [
+		{
+			name: 'competition',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'sponsorInfo',
+						type: {
+							defined: 'SponsorInfo',
+						},
+					},
+					{
+						name: 'switchboardFunction',
+						type: 'publicKey',
+					},
+					{
+						name: 'switchboardFunctionRequest',
+						type: 'publicKey',
+					},
+					{
+						name: 'switchboardFunctionRequestEscrow',
+						type: 'publicKey',
+					},
+					{
+						name: 'competitionAuthority',
+						type: 'publicKey',
+					},
+					{
+						name: 'numberOfCompetitors',
+						type: 'u128',
+					},
+					{
+						name: 'numberOfCompetitorsSettled',
+						type: 'u128',
+					},
+					{
+						name: 'totalScoreSettled',
+						type: 'u128',
+					},
+					{
+						name: 'maxEntriesPerCompetitor',
+						type: 'u128',
+					},
+					{
+						name: 'prizeAmount',
+						type: 'u128',
+					},
+					{
+						name: 'prizeAmountSettled',
+						type: 'u128',
+					},
+					{
+						name: 'prizeBase',
+						type: 'u128',
+					},
+					{
+						name: 'winnerRandomness',
+						type: 'u128',
+					},
+					{
+						name: 'prizeRandomness',
+						type: 'u128',
+					},
+					{
+						name: 'prizeRandomnessMax',
+						type: 'u128',
+					},
+					{
+						name: 'outstandingUnclaimedWinnings',
+						type: 'u128',
+					},
+					{
+						name: 'roundNumber',
+						type: 'u64',
+					},
+					{
+						name: 'nextRoundExpiryTs',
+						type: 'i64',
+					},
+					{
+						name: 'competitionExpiryTs',
+						type: 'i64',
+					},
+					{
+						name: 'roundDuration',
+						type: 'u64',
+					},
+					{
+						name: 'numberOfWinners',
+						type: 'u32',
+					},
+					{
+						name: 'numberOfWinnersSettled',
+						type: 'u32',
+					},
+					{
+						name: 'status',
+						type: {
+							defined: 'CompetitionRoundStatus',
+						},
+					},
+					{
+						name: 'competitionAuthorityBump',
+						type: 'u8',
+					},
+					{
+						name: 'winnerBadgesEnabled',
+						type: 'u8',
+					},
+					{
+						name: 'statusBeforePause',
+						type: {
+							defined: 'CompetitionRoundStatus',
+						},
+					},
+					{
+						name: 'keeperAllowlistEnabled',
+						type: 'u8',
+					},
+					{
+						name: 'ticketIndexEnabled',
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 2],
+						},
+					},
+					{
+						name: 'pausedTs',
+						type: 'i64',
+					},
+					{
+						name: 'pendingSponsor',
+						type: 'publicKey',
+					},
+					{
+						name: 'operator',
+						type: 'publicKey',
+					},
+					{
+						name: 'moderator',
+						type: 'publicKey',
+					},
+					{
+						name: 'keeperReward',
+						type: 'u64',
+					},
+					{
+						name: 'pendingRoundParams',
+						type: {
+							defined: 'PendingRoundParams',
+						},
+					},
+					{
+						name: 'roundSchedule',
+						type: {
+							defined: 'RoundSchedule',
+						},
+					},
+					{
+						name: 'winningScoreSettled',
+						type: 'u128',
+					},
+					{
+						name: 'winnerRedraws',
+						type: 'u32',
+					},
+					{
+						name: 'padding2',
+						type: {
+							array: ['u8', 4],
+						},
+					},
+					{
+						name: 'winnerDrawTs',
+						type: 'i64',
+					},
+					{
+						name: 'randomnessRequestedTs',
+						type: 'i64',
+					},
+					{
+						name: 'randomnessRequestedSlot',
+						type: 'u64',
+					},
+					{
+						name: 'randomnessRequestTimeout',
+						type: 'u32',
+					},
+					{
+						name: 'randomnessRequestRetries',
+						type: 'u8',
+					},
+					{
+						name: 'maxRandomnessRequestRetries',
+						type: 'u8',
+					},
+					{
+						name: 'padding3',
+						type: {
+							array: ['u8', 2],
+						},
+					},
+					{
+						name: 'numberOfCompetitorsForfeited',
+						type: 'u128',
+					},
+					{
+						name: 'settlementGracePeriod',
+						type: 'u32',
+					},
+					{
+						name: 'winnerSettlementTimeout',
+						type: 'u32',
+					},
+					{
+						name: 'randomnessProvider',
+						type: {
+							defined: 'RandomnessProvider',
+						},
+					},
+					{
+						name: 'randomnessRevealsMissed',
+						type: 'u8',
+					},
+					{
+						name: 'padding5',
+						type: {
+							array: ['u8', 6],
+						},
+					},
+					{
+						name: 'randomnessCommitment',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'randomnessCommitmentRoundNumber',
+						type: 'u64',
+					},
+					{
+						name: 'randomnessSlotHash',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'randomnessSlotHashSlot',
+						type: 'u64',
+					},
+					{
+						name: 'winnerRandomnessDelivered',
+						type: 'u128',
+					},
+					{
+						name: 'prizeRandomnessDelivered',
+						type: 'u128',
 					},
-				},
-			],
-		},
-		{
-			name: 'initializeCompetitor',
-			accounts: [
-				{
-					name: 'competitor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'competition',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'claimEntry',
-			accounts: [
-				{
-					name: 'authority',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'competitor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'competition',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'instructions',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'claimMultipleEntries',
-			accounts: [
-				{
-					name: 'authority',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'competitor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'competition',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'spotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'spotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'entries',
-					type: 'u64',
-				},
-			],
-		},
-		{
-			name: 'claimWinnings',
-			accounts: [
-				{
-					name: 'authority',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'competitor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'competition',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'spotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'competitionAuthority',
-					isMut: false,
-					isSigner: false,
-					docs: ['CHECK'],
-				},
-				{
-					name: 'driftTransferConfig',
-					isMut: true,
-					isSigner: false,
-					docs: ['CHECK in cpi'],
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-					docs: ['CHECK in cpi'],
-				},
-			],
-			args: [
-				{
-					name: 'nShares',
-					type: {
-						option: 'u64',
+					{
+						name: 'winnerRandomnessMax',
+						type: 'u128',
 					},
-				},
-			],
+					{
+						name: 'prizeDraw',
+						type: 'u128',
+					},
+					{
+						name: 'roundStartTs',
+						type: 'i64',
+					},
+					{
+						name: 'registryPageNumber',
+						type: 'u32',
+					},
+					{
+						name: 'registered',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'settleCompetitor',
-			accounts: [
-				{
-					name: 'keeper',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'competitor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'competition',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'competitionKeeper',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'authority',
+						type: 'publicKey',
+					},
+					{
+						name: 'competition',
+						type: 'publicKey',
+					},
+					{
+						name: 'unclaimedReward',
+						type: 'u64',
+					},
+					{
+						name: 'totalReward',
+						type: 'u64',
+					},
+					{
+						name: 'numberOfCalls',
+						type: 'u64',
+					},
+					{
+						name: 'lastCallTs',
+						type: 'i64',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'requestRandomness',
-			accounts: [
-				{
-					name: 'competition',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'keeper',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'competitionAuthority',
-					isMut: false,
-					isSigner: false,
-					docs: ['CHECK'],
-				},
-				{
-					name: 'spotMarket',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'switchboard',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'switchboardState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'switchboardAttestationQueue',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'switchboardFunction',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'switchboardRequest',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'switchboardRequestEscrow',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'bounty',
-					type: {
-						option: 'u64',
+			name: 'competitionRegistry',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'admin',
+						type: 'publicKey',
 					},
-				},
-			],
+					{
+						name: 'totalCompetitionsRegistered',
+						type: 'u64',
+					},
+					{
+						name: 'numberOfPages',
+						type: 'u32',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 4],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'receiveRandomness',
-			accounts: [
-				{
-					name: 'competition',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'spotMarket',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'switchboardFunction',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'switchboardRequest',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'enclaveSigner',
-					isMut: false,
-					isSigner: true,
-				},
-			],
-			args: [
-				{
-					name: 'winnerRandomness',
-					type: 'u128',
-				},
-				{
-					name: 'prizeRandomness',
-					type: 'u128',
-				},
-			],
+			name: 'competitionRegistryPage',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'pageNumber',
+						type: 'u32',
+					},
+					{
+						name: 'numberOfCompetitions',
+						type: 'u32',
+					},
+					{
+						name: 'competitions',
+						type: {
+							array: ['publicKey', 64],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'settleWinner',
-			accounts: [
-				{
-					name: 'keeper',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'competitor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'competition',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'spotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'competitor',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'authority',
+						type: 'publicKey',
+					},
+					{
+						name: 'competition',
+						type: 'publicKey',
+					},
+					{
+						name: 'userStats',
+						type: 'publicKey',
+					},
+					{
+						name: 'minDraw',
+						type: 'u128',
+					},
+					{
+						name: 'maxDraw',
+						type: 'u128',
+					},
+					{
+						name: 'unclaimedWinningsBase',
+						type: 'u128',
+					},
+					{
+						name: 'unclaimedWinnings',
+						type: 'u64',
+					},
+					{
+						name: 'competitionRoundNumber',
+						type: 'u64',
+					},
+					{
+						name: 'previousSnapshotScore',
+						type: 'u64',
+					},
+					{
+						name: 'latestSnapshotScore',
+						type: 'u64',
+					},
+					{
+						name: 'bonusScore',
+						type: 'u64',
+					},
+					{
+						name: 'status',
+						type: {
+							defined: 'CompetitorStatus',
+						},
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+					{
+						name: 'lastRoundWon',
+						type: 'u64',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 16],
+						},
+					},
+				],
+			},
 		},
-	],
-	accounts: [
 		{
-			name: 'competition',
+			name: 'ticketIndexPage',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'name',
+						name: 'competition',
+						type: 'publicKey',
+					},
+					{
+						name: 'roundNumber',
+						type: 'u64',
+					},
+					{
+						name: 'pageNumber',
+						type: 'u32',
+					},
+					{
+						name: 'numberOfEntries',
+						type: 'u32',
+					},
+					{
+						name: 'entries',
 						type: {
-							array: ['u8', 32],
+							array: [
+								{
+									defined: 'TicketRange',
+								},
+								32,
+							],
 						},
 					},
+				],
+			},
+		},
+	],
+	types: [
+		{
+			name: 'CompetitionParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'sponsorInfo',
+						name: 'name',
 						type: {
-							defined: 'SponsorInfo',
+							array: ['u8', 32],
 						},
 					},
 					{
-						name: 'switchboardFunction',
-						type: 'publicKey',
+						name: 'nextRoundExpiryTs',
+						type: 'i64',
 					},
 					{
-						name: 'switchboardFunctionRequest',
-						type: 'publicKey',
+						name: 'competitionExpiryTs',
+						type: 'i64',
 					},
 					{
-						name: 'switchboardFunctionRequestEscrow',
-						type: 'publicKey',
+						name: 'roundDuration',
+						type: 'u64',
 					},
 					{
-						name: 'competitionAuthority',
-						type: 'publicKey',
+						name: 'maxEntriesPerCompetitor',
+						type: 'u128',
 					},
 					{
-						name: 'numberOfCompetitors',
-						type: 'u128',
+						name: 'minSponsorAmount',
+						type: 'u64',
 					},
 					{
-						name: 'numberOfCompetitorsSettled',
-						type: 'u128',
+						name: 'maxSponsorFraction',
+						type: 'u64',
 					},
 					{
-						name: 'totalScoreSettled',
-						type: 'u128',
+						name: 'numberOfWinners',
+						type: 'u32',
 					},
+				],
+			},
+		},
+		{
+			name: 'UpdateCompetitionParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
 						name: 'maxEntriesPerCompetitor',
-						type: 'u128',
+						type: {
+							option: 'u128',
+						},
 					},
 					{
-						name: 'prizeAmount',
-						type: 'u128',
+						name: 'minSponsorAmount',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'prizeAmountSettled',
-						type: 'u128',
+						name: 'maxSponsorFraction',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'prizeBase',
-						type: 'u128',
+						name: 'numberOfWinners',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'winnerRandomness',
-						type: 'u128',
+						name: 'winnerBadgesEnabled',
+						type: {
+							option: 'bool',
+						},
 					},
 					{
-						name: 'prizeRandomness',
-						type: 'u128',
+						name: 'keeperAllowlistEnabled',
+						type: {
+							option: 'bool',
+						},
 					},
 					{
-						name: 'prizeRandomnessMax',
-						type: 'u128',
+						name: 'keeperReward',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'outstandingUnclaimedWinnings',
-						type: 'u128',
+						name: 'ticketIndexEnabled',
+						type: {
+							option: 'bool',
+						},
 					},
 					{
-						name: 'roundNumber',
-						type: 'u64',
+						name: 'randomnessProvider',
+						type: {
+							option: {
+								defined: 'RandomnessProvider',
+							},
+						},
+					},
+					{
+						name: 'operator',
+						type: {
+							option: 'publicKey',
+						},
+					},
+					{
+						name: 'moderator',
+						type: {
+							option: 'publicKey',
+						},
 					},
+				],
+			},
+		},
+		{
+			name: 'UpdateCompetitionScheduleParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
 						name: 'nextRoundExpiryTs',
-						type: 'i64',
+						type: {
+							option: 'i64',
+						},
 					},
 					{
 						name: 'competitionExpiryTs',
-						type: 'i64',
+						type: {
+							option: 'i64',
+						},
 					},
 					{
 						name: 'roundDuration',
-						type: 'u64',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'numberOfWinners',
-						type: 'u32',
+						name: 'settlementGracePeriod',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'numberOfWinnersSettled',
-						type: 'u32',
+						name: 'winnerSettlementTimeout',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'status',
+						name: 'randomnessRequestTimeout',
 						type: {
-							defined: 'CompetitionRoundStatus',
+							option: 'u32',
 						},
 					},
 					{
-						name: 'competitionAuthorityBump',
-						type: 'u8',
+						name: 'maxRandomnessRequestRetries',
+						type: {
+							option: 'u8',
+						},
 					},
 					{
-						name: 'padding',
+						name: 'resetRoundState',
 						type: {
-							array: ['u8', 30],
+							option: 'bool',
 						},
 					},
 				],
 			},
 		},
 		{
-			name: 'competitor',
+			name: 'UpdateRoundScheduleParams',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'authority',
-						type: 'publicKey',
+						name: 'mode',
+						type: {
+							option: {
+								defined: 'RoundScheduleMode',
+							},
+						},
 					},
 					{
-						name: 'competition',
-						type: 'publicKey',
+						name: 'weekdayMask',
+						type: {
+							option: 'u8',
+						},
 					},
 					{
-						name: 'userStats',
+						name: 'hour',
+						type: {
+							option: 'u8',
+						},
+					},
+					{
+						name: 'minute',
+						type: {
+							option: 'u8',
+						},
+					},
+					{
+						name: 'clearExpiries',
+						type: {
+							option: 'bool',
+						},
+					},
+					{
+						name: 'addExpiries',
+						type: {
+							vec: 'i64',
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'SponsorInfo',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'sponsor',
 						type: 'publicKey',
 					},
 					{
-						name: 'minDraw',
+						name: 'minSponsorAmount',
+						type: 'u64',
+					},
+					{
+						name: 'maxSponsorFraction',
+						type: 'u64',
+					},
+				],
+			},
+		},
+		{
+			name: 'PendingRoundParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'maxEntriesPerCompetitor',
 						type: 'u128',
 					},
 					{
-						name: 'maxDraw',
-						type: 'u128',
+						name: 'roundDuration',
+						type: 'u64',
+					},
+					{
+						name: 'minSponsorAmount',
+						type: 'u64',
+					},
+					{
+						name: 'maxSponsorFraction',
+						type: 'u64',
+					},
+					{
+						name: 'numberOfWinners',
+						type: 'u32',
+					},
+					{
+						name: 'flags',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
 					},
+				],
+			},
+		},
+		{
+			name: 'RoundSchedule',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'unclaimedWinningsBase',
-						type: 'u128',
+						name: 'expiries',
+						type: {
+							array: ['i64', 16],
+						},
 					},
 					{
-						name: 'unclaimedWinnings',
-						type: 'u64',
+						name: 'head',
+						type: 'u8',
 					},
 					{
-						name: 'competitionRoundNumber',
-						type: 'u64',
+						name: 'len',
+						type: 'u8',
 					},
 					{
-						name: 'previousSnapshotScore',
-						type: 'u64',
+						name: 'mode',
+						type: {
+							defined: 'RoundScheduleMode',
+						},
 					},
 					{
-						name: 'latestSnapshotScore',
-						type: 'u64',
+						name: 'weekdayMask',
+						type: 'u8',
 					},
 					{
-						name: 'bonusScore',
-						type: 'u64',
+						name: 'hour',
+						type: 'u8',
 					},
 					{
-						name: 'status',
-						type: {
-							defined: 'CompetitorStatus',
-						},
+						name: 'minute',
+						type: 'u8',
 					},
 					{
 						name: 'padding',
 						type: {
-							array: ['u8', 31],
+							array: ['u8', 2],
 						},
 					},
 				],
 			},
 		},
-	],
-	types: [
 		{
-			name: 'CompetitionParams',
+			name: 'TicketRange',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'name',
-						type: {
-							array: ['u8', 32],
-						},
+						name: 'competitor',
+						type: 'publicKey',
 					},
 					{
-						name: 'nextRoundExpiryTs',
-						type: 'i64',
+						name: 'minDraw',
+						type: 'u128',
 					},
 					{
-						name: 'competitionExpiryTs',
-						type: 'i64',
+						name: 'maxDraw',
+						type: 'u128',
 					},
+				],
+			},
+		},
+		{
+			name: 'CompetitionRoundStatus',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'roundDuration',
-						type: 'u64',
+						name: 'Active',
 					},
 					{
-						name: 'maxEntriesPerCompetitor',
-						type: 'u128',
+						name: 'WinnerAndPrizeRandomnessRequested',
 					},
 					{
-						name: 'minSponsorAmount',
-						type: 'u64',
+						name: 'WinnerAndPrizeRandomnessComplete',
 					},
 					{
-						name: 'maxSponsorFraction',
-						type: 'u64',
+						name: 'WinnerSettlementComplete',
 					},
 					{
-						name: 'numberOfWinners',
-						type: 'u32',
+						name: 'Expired',
+					},
+					{
+						name: 'Paused',
 					},
 				],
 			},
 		},
 		{
-			name: 'UpdateCompetitionParams',
+			name: 'RandomnessProvider',
 			type: {
-				kind: 'struct',
-				fields: [
+				kind: 'enum',
+				variants: [
 					{
-						name: 'nextRoundExpiryTs',
-						type: {
-							option: 'i64',
-						},
+						name: 'Switchboard',
 					},
 					{
-						name: 'competitionExpiryTs',
-						type: {
-							option: 'i64',
-						},
+						name: 'CommitReveal',
 					},
 					{
-						name: 'roundDuration',
-						type: {
-							option: 'u64',
-						},
+						name: 'SlotHash',
 					},
+				],
+			},
+		},
+		{
+			name: 'CompetitorStatus',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'maxEntriesPerCompetitor',
-						type: {
-							option: 'u128',
-						},
+						name: 'Active',
 					},
 					{
-						name: 'minSponsorAmount',
-						type: {
-							option: 'u64',
-						},
+						name: 'Disqualified',
 					},
+				],
+			},
+		},
+		{
+			name: 'RoundScheduleMode',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'maxSponsorFraction',
-						type: {
-							option: 'u64',
-						},
+						name: 'Interval',
 					},
 					{
-						name: 'numberOfWinners',
-						type: {
-							option: 'u32',
-						},
+						name: 'Explicit',
 					},
 					{
-						name: 'resetRoundState',
-						type: {
-							option: 'bool',
-						},
+						name: 'Weekly',
+					},
+				],
+			},
+		},
+	],
+	events: [
+		{
+			name: 'CompetitionRoundSummaryRecord',
+			fields: [
+				{
+					name: 'competition',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'roundNumber',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'roundStartTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'roundEndTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'prizePlacement',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'prizeOddsNumerator',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'prizeRandomness',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'prizeRandomnessMax',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'maxPrizeBucketValue',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'prizeAmount',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'prizeValue',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'prizeBase',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'numberOfWinners',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'numberOfCompetitorsSettled',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalScoreSettled',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'insuranceVaultBalance',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'protocolIfShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalIfShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'prizeDraw',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'winnerRandomnessDelivered',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'prizeRandomnessDelivered',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'winnerRandomnessMax',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'randomnessHash',
+					type: {
+						array: ['u8', 32],
 					},
-				],
-			},
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'SponsorInfo',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'sponsor',
-						type: 'publicKey',
-					},
-					{
-						name: 'minSponsorAmount',
-						type: 'u64',
-					},
-					{
-						name: 'maxSponsorFraction',
-						type: 'u64',
-					},
-				],
-			},
+			name: 'CompetitionRoundWinnerRecord',
+			fields: [
+				{
+					name: 'roundNumber',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'competitor',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'competition',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'competitorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'minDraw',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'maxDraw',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'winnerPlacement',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'numberOfWinners',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'numberOfCompetitorsSettled',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'winnerRandomness',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalScoreSettled',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'prizeRandomness',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'prizeRandomnessMax',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'prizeAmount',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'prizeBase',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'prizeValue',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'winnerBadgeMint',
+					type: 'publicKey',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'CompetitionRoundStatus',
-			type: {
-				kind: 'enum',
-				variants: [
-					{
-						name: 'Active',
-					},
-					{
-						name: 'WinnerAndPrizeRandomnessRequested',
-					},
-					{
-						name: 'WinnerAndPrizeRandomnessComplete',
-					},
-					{
-						name: 'WinnerSettlementComplete',
-					},
-					{
-						name: 'Expired',
+			name: 'CompetitorSettledRecord',
+			fields: [
+				{
+					name: 'roundNumber',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'competitor',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'competition',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'competitorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'status',
+					type: {
+						defined: 'CompetitorStatus',
 					},
-				],
-			},
+					index: false,
+				},
+				{
+					name: 'unclaimedWinnings',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'minDraw',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'maxDraw',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'bonusScoreBefore',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'bonusScoreAfter',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'previousSnapshotScoreBefore',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'snapshotScore',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'CompetitorStatus',
-			type: {
-				kind: 'enum',
-				variants: [
-					{
-						name: 'Active',
-					},
-					{
-						name: 'Disqualified',
-					},
-				],
-			},
+			name: 'CompetitionExpiredRecord',
+			fields: [
+				{
+					name: 'competition',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'roundNumber',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'roundAbandoned',
+					type: 'bool',
+					index: false,
+				},
+				{
+					name: 'numberOfCompetitors',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'outstandingUnclaimedWinnings',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'unclaimedWinningsForfeited',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'competitionExpiryTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'claimWindowEndTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+			],
 		},
-	],
-	events: [
 		{
-			name: 'CompetitionRoundSummaryRecord',
+			name: 'SponsorProposedRecord',
 			fields: [
 				{
 					name: 'competition',
@@ -2189,90 +6212,135 @@ export const IDL: DriftCompetitions = {
 					index: false,
 				},
 				{
-					name: 'roundNumber',
-					type: 'u64',
+					name: 'sponsor',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'roundStartTs',
-					type: 'i64',
+					name: 'pendingSponsor',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'roundEndTs',
+					name: 'ts',
 					type: 'i64',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'SponsorAcceptedRecord',
+			fields: [
 				{
-					name: 'prizePlacement',
-					type: 'u32',
+					name: 'competition',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'prizeOddsNumerator',
-					type: 'u128',
+					name: 'previousSponsor',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'prizeRandomness',
-					type: 'u128',
+					name: 'sponsor',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'prizeRandomnessMax',
-					type: 'u128',
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'RoundParamsScheduledRecord',
+			fields: [
+				{
+					name: 'competition',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'maxPrizeBucketValue',
+					name: 'effectiveRoundNumber',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'prizeAmount',
-					type: 'u128',
+					name: 'maxEntriesPerCompetitor',
+					type: {
+						option: 'u128',
+					},
 					index: false,
 				},
 				{
-					name: 'prizeValue',
-					type: 'u64',
+					name: 'roundDuration',
+					type: {
+						option: 'u64',
+					},
 					index: false,
 				},
 				{
-					name: 'prizeBase',
-					type: 'u128',
+					name: 'minSponsorAmount',
+					type: {
+						option: 'u64',
+					},
+					index: false,
+				},
+				{
+					name: 'maxSponsorFraction',
+					type: {
+						option: 'u64',
+					},
 					index: false,
 				},
 				{
 					name: 'numberOfWinners',
-					type: 'u32',
+					type: {
+						option: 'u32',
+					},
 					index: false,
 				},
 				{
-					name: 'numberOfCompetitorsSettled',
-					type: 'u128',
+					name: 'ts',
+					type: 'i64',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'WinnerSkippedRecord',
+			fields: [
 				{
-					name: 'totalScoreSettled',
-					type: 'u128',
+					name: 'competition',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'insuranceVaultBalance',
+					name: 'roundNumber',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'protocolIfShares',
+					name: 'winnerPlacement',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'skippedWinnerRandomness',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'totalIfShares',
+					name: 'winnerRandomness',
 					type: 'u128',
 					index: false,
 				},
+				{
+					name: 'winnerRedraws',
+					type: 'u32',
+					index: false,
+				},
 				{
 					name: 'ts',
 					type: 'i64',
@@ -2281,86 +6349,112 @@ export const IDL: DriftCompetitions = {
 			],
 		},
 		{
-			name: 'CompetitionRoundWinnerRecord',
+			name: 'RandomnessRequestedRecord',
 			fields: [
+				{
+					name: 'competition',
+					type: 'publicKey',
+					index: false,
+				},
 				{
 					name: 'roundNumber',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'competitor',
-					type: 'publicKey',
+					name: 'retry',
+					type: 'u8',
 					index: false,
 				},
 				{
-					name: 'competition',
-					type: 'publicKey',
+					name: 'maxRetries',
+					type: 'u8',
 					index: false,
 				},
 				{
-					name: 'competitorAuthority',
-					type: 'publicKey',
+					name: 'winnerMax',
+					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'minDraw',
+					name: 'prizeMax',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'maxDraw',
-					type: 'u128',
+					name: 'slot',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'winnerPlacement',
-					type: 'u32',
+					name: 'ts',
+					type: 'i64',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'RandomnessRevealedRecord',
+			fields: [
 				{
-					name: 'numberOfWinners',
-					type: 'u32',
+					name: 'competition',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'numberOfCompetitorsSettled',
-					type: 'u128',
+					name: 'roundNumber',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'winnerRandomness',
-					type: 'u128',
+					name: 'commitment',
+					type: {
+						array: ['u8', 32],
+					},
 					index: false,
 				},
 				{
-					name: 'totalScoreSettled',
-					type: 'u128',
+					name: 'secret',
+					type: {
+						array: ['u8', 32],
+					},
 					index: false,
 				},
 				{
-					name: 'prizeRandomness',
-					type: 'u128',
+					name: 'slotHash',
+					type: {
+						array: ['u8', 32],
+					},
 					index: false,
 				},
 				{
-					name: 'prizeRandomnessMax',
-					type: 'u128',
+					name: 'slot',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'prizeAmount',
-					type: 'u128',
+					name: 'revealMissed',
+					type: 'bool',
 					index: false,
 				},
 				{
-					name: 'prizeBase',
+					name: 'slotHashPinMissed',
+					type: 'bool',
+					index: false,
+				},
+				{
+					name: 'revealsMissed',
+					type: 'u8',
+					index: false,
+				},
+				{
+					name: 'winnerRandomness',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'prizeValue',
-					type: 'u64',
+					name: 'prizeRandomness',
+					type: 'u128',
 					index: false,
 				},
 				{
@@ -2371,68 +6465,71 @@ export const IDL: DriftCompetitions = {
 			],
 		},
 		{
-			name: 'CompetitorSettledRecord',
+			name: 'WinnerDrawRecord',
 			fields: [
+				{
+					name: 'competition',
+					type: 'publicKey',
+					index: false,
+				},
 				{
 					name: 'roundNumber',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'competitor',
-					type: 'publicKey',
+					name: 'winnerRandomnessDelivered',
+					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'competition',
-					type: 'publicKey',
+					name: 'prizeRandomnessDelivered',
+					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'competitorAuthority',
-					type: 'publicKey',
+					name: 'winnerRandomnessMax',
+					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'status',
-					type: {
-						defined: 'CompetitorStatus',
-					},
+					name: 'prizeRandomnessMax',
+					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'unclaimedWinnings',
-					type: 'u64',
+					name: 'prizeRandomness',
+					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'minDraw',
+					name: 'prizeDraw',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'maxDraw',
+					name: 'totalScoreSettled',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'bonusScoreBefore',
-					type: 'u64',
+					name: 'winnerPlacement',
+					type: 'u32',
 					index: false,
 				},
 				{
-					name: 'bonusScoreAfter',
-					type: 'u64',
+					name: 'winnerRedraws',
+					type: 'u32',
 					index: false,
 				},
 				{
-					name: 'previousSnapshotScoreBefore',
-					type: 'u64',
+					name: 'winningScoreSettled',
+					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'snapshotScore',
-					type: 'u64',
+					name: 'winnerRandomness',
+					type: 'u128',
 					index: false,
 				},
 				{
@@ -2549,5 +6646,160 @@ export const IDL: DriftCompetitions = {
 			name: 'CompetitorHasPendingInsuranceWithdraw',
 			msg: 'CompetitorHasPendingInsuranceWithdraw',
 		},
+		{
+			code: 6021,
+			name: 'WinnerBadgeAccountsMissing',
+			msg: 'WinnerBadgeAccountsMissing',
+		},
+		{
+			code: 6022,
+			name: 'CompetitionClaimWindowElapsed',
+			msg: 'CompetitionClaimWindowElapsed',
+		},
+		{
+			code: 6023,
+			name: 'CompetitionPaused',
+			msg: 'CompetitionPaused',
+		},
+		{
+			code: 6024,
+			name: 'CompetitorHasUnclaimedWinnings',
+			msg: 'CompetitorHasUnclaimedWinnings',
+		},
+		{
+			code: 6025,
+			name: 'CompetitionNotClosable',
+			msg: 'CompetitionNotClosable',
+		},
+		{
+			code: 6026,
+			name: 'InvalidSponsorUpdate',
+			msg: 'InvalidSponsorUpdate',
+		},
+		{
+			code: 6027,
+			name: 'KeeperNotAllowed',
+			msg: 'KeeperNotAllowed',
+		},
+		{
+			code: 6028,
+			name: 'InvalidMaxSponsorFraction',
+			msg: 'InvalidMaxSponsorFraction',
+		},
+		{
+			code: 6029,
+			name: 'InvalidNumberOfWinners',
+			msg: 'InvalidNumberOfWinners',
+		},
+		{
+			code: 6030,
+			name: 'InvalidRoundDuration',
+			msg: 'InvalidRoundDuration',
+		},
+		{
+			code: 6031,
+			name: 'InvalidCompetitionExpiryTs',
+			msg: 'InvalidCompetitionExpiryTs',
+		},
+		{
+			code: 6032,
+			name: 'InvalidRoundSchedule',
+			msg: 'InvalidRoundSchedule',
+		},
+		{
+			code: 6033,
+			name: 'InvalidCompetitionName',
+			msg: 'InvalidCompetitionName',
+		},
+		{
+			code: 6034,
+			name: 'CompetitionRegistryUpdateInvalid',
+			msg: 'CompetitionRegistryUpdateInvalid',
+		},
+		{
+			code: 6035,
+			name: 'InvalidBatchSettlementAccounts',
+			msg: 'InvalidBatchSettlementAccounts',
+		},
+		{
+			code: 6036,
+			name: 'TicketIndexPageMissing',
+			msg: 'TicketIndexPageMissing',
+		},
+		{
+			code: 6037,
+			name: 'TicketIndexPageInvalid',
+			msg: 'TicketIndexPageInvalid',
+		},
+		{
+			code: 6038,
+			name: 'TicketIndexWinnerNotFound',
+			msg: 'TicketIndexWinnerNotFound',
+		},
+		{
+			code: 6039,
+			name: 'WinnerNotSkippable',
+			msg: 'WinnerNotSkippable',
+		},
+		{
+			code: 6040,
+			name: 'RandomnessRequestNotTimedOut',
+			msg: 'RandomnessRequestNotTimedOut',
+		},
+		{
+			code: 6041,
+			name: 'RandomnessRequestRetriesExhausted',
+			msg: 'RandomnessRequestRetriesExhausted',
+		},
+		{
+			code: 6042,
+			name: 'CompetitorNotSettleable',
+			msg: 'CompetitorNotSettleable',
+		},
+		{
+			code: 6043,
+			name: 'InvalidRandomnessProvider',
+			msg: 'InvalidRandomnessProvider',
+		},
+		{
+			code: 6044,
+			name: 'InvalidRandomnessCommitment',
+			msg: 'InvalidRandomnessCommitment',
+		},
+		{
+			code: 6045,
+			name: 'InvalidRandomnessReveal',
+			msg: 'InvalidRandomnessReveal',
+		},
+		{
+			code: 6046,
+			name: 'SlotHashNotFound',
+			msg: 'SlotHashNotFound',
+		},
+		{
+			code: 6047,
+			name: 'RandomnessRevealNotTimedOut',
+			msg: 'RandomnessRevealNotTimedOut',
+		},
+		{
+			code: 6048,
+			name: 'InvalidRandomnessRequestParams',
+			msg: 'InvalidRandomnessRequestParams',
+		},
+		{
+			code: 6049,
+			name: 'InvalidWinnerBadgeAuthority',
+			msg: 'InvalidWinnerBadgeAuthority',
+		},
+		{
+			code: 6050,
+			name: 'InvalidCompetitionMigration',
+			msg: 'InvalidCompetitionMigration',
+		},
+		{
+			code: 6051,
+			name: 'InvalidRandomnessDelivered',
+			msg: 'InvalidRandomnessDelivered',
+		},
 	],
 };
@@ -34,6 +34,19 @@ export class CompetitionStatus {
 		WinnerSettlementComplete: {},
 	};
 	static readonly EXPIRED = { Expired: {} };
+	static readonly PAUSED = { paused: {} };
+}
+
+export class RandomnessProvider {
+	static readonly SWITCHBOARD = { switchboard: {} };
+	static readonly COMMIT_REVEAL = { commitReveal: {} };
+	static readonly SLOT_HASH = { slotHash: {} };
+}
+
+export class RoundScheduleMode {
+	static readonly INTERVAL = { interval: {} };
+	static readonly EXPLICIT = { explicit: {} };
+	static readonly WEEKLY = { weekly: {} };
 }
 
 export class CompetitorStatus {
@@ -81,6 +94,7 @@ export type Competition = {
 	totalScoreSettled: BN;
 	maxEntriesPerCompetitor: BN;
 	prizeAmount: BN;
+	prizeAmountSettled: BN;
 	prizeBase: BN;
 	winnerRandomness: BN;
 	prizeRandomness: BN;
@@ -90,8 +104,64 @@ export type Competition = {
 	nextRoundExpiryTs: BN;
 	competitionExpiryTs: BN;
 	roundDuration: BN;
+	numberOfWinners: number;
+	numberOfWinnersSettled: number;
 	status: CompetitionStatus;
 	competitionAuthorityBump: number;
+	winnerBadgesEnabled: number;
+	statusBeforePause: CompetitionStatus;
+	keeperAllowlistEnabled: number;
+	ticketIndexEnabled: number;
+	pausedTs: BN;
+	pendingSponsor: PublicKey;
+	operator: PublicKey;
+	moderator: PublicKey;
+	keeperReward: BN;
+	pendingRoundParams: PendingRoundParams;
+	roundSchedule: RoundSchedule;
+	winningScoreSettled: BN;
+	winnerRedraws: number;
+	winnerDrawTs: BN;
+	randomnessRequestedTs: BN;
+	randomnessRequestedSlot: BN;
+	randomnessRequestTimeout: number;
+	randomnessRequestRetries: number;
+	maxRandomnessRequestRetries: number;
+	numberOfCompetitorsForfeited: BN;
+	settlementGracePeriod: number;
+	winnerSettlementTimeout: number;
+	randomnessProvider: RandomnessProvider;
+	randomnessRevealsMissed: number;
+	randomnessCommitment: number[];
+	randomnessCommitmentRoundNumber: BN;
+	randomnessSlotHash: number[];
+	randomnessSlotHashSlot: BN;
+	winnerRandomnessDelivered: BN;
+	prizeRandomnessDelivered: BN;
+	winnerRandomnessMax: BN;
+	prizeDraw: BN;
+	roundStartTs: BN;
+	registryPageNumber: number;
+	registered: number;
+};
+
+export type PendingRoundParams = {
+	maxEntriesPerCompetitor: BN;
+	roundDuration: BN;
+	minSponsorAmount: BN;
+	maxSponsorFraction: BN;
+	numberOfWinners: number;
+	flags: number;
+};
+
+export type RoundSchedule = {
+	expiries: BN[];
+	head: number;
+	len: number;
+	mode: RoundScheduleMode;
+	weekdayMask: number;
+	hour: number;
+	minute: number;
 };
 
 export type Competitor = {
@@ -107,6 +177,42 @@ export type Competitor = {
 	latestSnapshotScore: BN;
 	bonusScore: BN;
 	status: CompetitorStatus;
+	lastRoundWon: BN; // round_number + 1 of the last round won
+};
+
+export type CompetitionKeeper = {
+	authority: PublicKey;
+	competition: PublicKey;
+	unclaimedReward: BN;
+	totalReward: BN;
+	numberOfCalls: BN;
+	lastCallTs: BN;
+};
+
+export type TicketRange = {
+	competitor: PublicKey;
+	minDraw: BN;
+	maxDraw: BN;
+};
+
+export type TicketIndexPage = {
+	competition: PublicKey;
+	roundNumber: BN;
+	pageNumber: number;
+	numberOfEntries: number;
+	entries: TicketRange[];
+};
+
+export type CompetitionRegistry = {
+	admin: PublicKey;
+	totalCompetitionsRegistered: BN;
+	numberOfPages: number;
+};
+
+export type CompetitionRegistryPage = {
+	pageNumber: number;
+	numberOfCompetitions: number;
+	competitions: PublicKey[];
 };
 
 /** Events */
@@ -146,6 +252,11 @@ export type CompetitionRoundSummaryRecord = {
 	protocolIfShares: BN;
 	totalIfShares: BN;
 	ts: BN;
+	prizeDraw: BN; // prizeRandomness re-ranged to the buckets if they shrank since the request
+	winnerRandomnessDelivered: BN; // raw winner value delivered to the program, ranged on-chain to [1, winnerRandomnessMax]
+	prizeRandomnessDelivered: BN; // raw prize value delivered to the program, ranged on-chain to [0, prizeRandomnessMax]
+	winnerRandomnessMax: BN; // totalScoreSettled at request
+	randomnessHash: number[]; // raw winner || prize bytes: the sha256 for on-chain providers, the function's draws for Switchboard
 };
 
 export type CompetitionRoundWinnerRecord = {
@@ -166,6 +277,7 @@ export type CompetitionRoundWinnerRecord = {
 	prizeBase: BN;
 	prizeValue: BN; // USDC Value of the prizeAmount (if shares)
 	ts: BN;
+	winnerBadgeMint: PublicKey; // mint of the badge token given for this placement (default if none)
 };
 
 export type CompetitorSettledRecord = {
@@ -184,10 +296,67 @@ export type CompetitorSettledRecord = {
 	ts: BN;
 };
 
+export type WinnerSkippedRecord = {
+	competition: PublicKey;
+	roundNumber: BN;
+	winnerPlacement: number;
+	skippedWinnerRandomness: BN; // draw that timed out unsettled
+	winnerRandomness: BN; // redraw
+	winnerRedraws: number;
+	ts: BN;
+};
+
+export type RandomnessRequestedRecord = {
+	competition: PublicKey;
+	roundNumber: BN;
+	retry: number; // 0 for the first request
+	maxRetries: number;
+	winnerMax: BN;
+	prizeMax: BN;
+	slot: BN;
+	ts: BN;
+};
+
+export type RandomnessRevealedRecord = {
+	competition: PublicKey;
+	roundNumber: BN;
+	commitment: number[];
+	secret: number[]; // zeroed when the reveal was missed
+	slotHash: number[];
+	slot: BN; // slot slotHash was pinned from
+	revealMissed: boolean; // commit-reveal revoked, the draw is re-requested from switchboard
+	slotHashPinMissed: boolean; // nobody pinned the slot hash in time, re-requested without revoking
+	revealsMissed: number;
+	winnerRandomness: BN; // 0 when the reveal was missed
+	prizeRandomness: BN; // 0 when the reveal was missed
+	ts: BN;
+};
+
+export type WinnerDrawRecord = {
+	competition: PublicKey;
+	roundNumber: BN;
+	winnerRandomnessDelivered: BN; // raw value the round's draws derive from
+	prizeRandomnessDelivered: BN;
+	winnerRandomnessMax: BN;
+	prizeRandomnessMax: BN;
+	prizeRandomness: BN;
+	prizeDraw: BN;
+	totalScoreSettled: BN;
+	winnerPlacement: number;
+	winnerRedraws: number;
+	winningScoreSettled: BN; // tickets already won this round, draws landing on them are redone
+	winnerRandomness: BN; // drawn ticket
+	ts: BN;
+};
+
 export type CompetitionsEventMap = {
 	CompetitionRoundWinnerRecord: Event<CompetitionRoundWinnerRecord>;
 	CompetitionRoundSummaryRecord: Event<CompetitionRoundSummaryRecord>;
 	CompetitorSettledRecord: Event<CompetitorSettledRecord>;
+	WinnerSkippedRecord: Event<WinnerSkippedRecord>;
+	RandomnessRequestedRecord: Event<RandomnessRequestedRecord>;
+	RandomnessRevealedRecord: Event<RandomnessRevealedRecord>;
+	WinnerDrawRecord: Event<WinnerDrawRecord>;
 };
 
 export type EventType = keyof CompetitionsEventMap;
@@ -200,7 +369,11 @@ export type WrappedEvents = WrappedEvent<EventType>[];
 export type DriftSweepstakesEvent =
 	| CompetitionsEventMap['CompetitionRoundSummaryRecord']
 	| CompetitionsEventMap['CompetitionRoundWinnerRecord']
-	| CompetitionsEventMap['CompetitorSettledRecord'];
+	| CompetitionsEventMap['CompetitorSettledRecord']
+	| CompetitionsEventMap['WinnerSkippedRecord']
+	| CompetitionsEventMap['RandomnessRequestedRecord']
+	| CompetitionsEventMap['RandomnessRevealedRecord']
+	| CompetitionsEventMap['WinnerDrawRecord'];
 
 export interface EventSubscriberEvents {
 	newEvent: (event: WrappedEvent<EventType>) => void;